@@ -36,6 +36,66 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArg
 /// // {"userName": "Alice", "age": "30"}  // camelCase + string number
 /// ```
 ///
+/// # Custom Field Coercion
+///
+/// Use `#[llm(deserialize_with = "path::to::fn")]` to coerce a field with your own
+/// function, or `#[llm(with = "path::to::module")]` to call `module::deserialize`.
+/// The function receives the raw value and the coercion context, so it can record
+/// transformations and constraints like the built-in impls do. For `Option<T>`
+/// fields the function returns `T`.
+///
+/// ```ignore
+/// use tryparse::{deserializer::CoercionContext, error::Result, value::FlexValue};
+///
+/// fn parse_rating(value: &FlexValue, ctx: &mut CoercionContext) -> Result<u8> {
+///     // "3 of 5 stars" -> 3
+///     # unimplemented!()
+/// }
+///
+/// #[derive(LlmDeserialize)]
+/// struct Review {
+///     #[llm(deserialize_with = "parse_rating")]
+///     rating: u8,
+///     #[llm(with = "phone")]
+///     contact: Option<String>,
+/// }
+/// ```
+///
+/// Custom-coerced fields are only tried in the lenient pass.
+///
+/// # Skipping Bad List Items
+///
+/// `#[llm(skip_bad_items)]` drops items of a `Vec<T>` field (including lists nested
+/// inside it) that don't coerce, instead of failing the field. Each dropped item is
+/// recorded as an `ArrayItemParseError`. `CoercionContext::with_skip_bad_items`
+/// turns this on everywhere.
+///
+/// ```ignore
+/// #[derive(LlmDeserialize)]
+/// struct Order {
+///     #[llm(skip_bad_items)]
+///     quantities: Vec<i64>, // [1, "lots", 3] -> [1, 3]
+/// }
+/// ```
+///
+/// # Maybe Fields
+///
+/// A `tryparse::deserializer::Maybe<T>` field is `Absent` when its key is missing.
+/// Write the full path, or mark an imported `Maybe<T>` with `#[llm(maybe)]`; any
+/// other type named `Maybe` is an ordinary field. A value that is present but
+/// doesn't coerce fails the struct instead of reading as `Absent`.
+///
+/// ```ignore
+/// use tryparse::deserializer::Maybe;
+///
+/// #[derive(LlmDeserialize)]
+/// struct UserPatch {
+///     #[llm(maybe)]
+///     nickname: Maybe<String>,
+///     age: tryparse::deserializer::Maybe<u32>,
+/// }
+/// ```
+///
 /// # Union Types
 ///
 /// ```ignore
@@ -108,16 +168,33 @@ fn generate_struct_deserialize(
                 .map(|f| f.ident.as_ref().unwrap().to_string())
                 .collect();
 
-            // Check if each field is Option<T>
+            // Parse #[llm(...)] field attributes
+            let field_attrs: Vec<_> =
+                match fields.named.iter().map(FieldAttrs::from_field).collect() {
+                    Ok(attrs) => attrs,
+                    Err(err) => return err.to_compile_error(),
+                };
+
+            // Check if each field is Option<T> or Maybe<T>
             let is_optional: Vec<_> = field_types.iter().map(|ty| is_option_type(ty)).collect();
+            let is_maybe: Vec<_> = match fields
+                .named
+                .iter()
+                .zip(&field_attrs)
+                .map(|(field, attrs)| is_maybe_field(&field.ty, attrs))
+                .collect()
+            {
+                Ok(is_maybe) => is_maybe,
+                Err(err) => return err.to_compile_error(),
+            };
 
-            // Extract inner type for Option<T> fields
+            // Extract inner type for Option<T> and Maybe<T> fields
             let inner_types: Vec<_> = field_types
                 .iter()
-                .zip(&is_optional)
-                .map(|(ty, opt)| {
-                    if *opt {
-                        extract_option_inner(ty)
+                .zip(is_optional.iter().zip(&is_maybe))
+                .map(|(ty, (opt, maybe))| {
+                    if *opt || *maybe {
+                        extract_generic_inner(ty)
                     } else {
                         (*ty).clone()
                     }
@@ -126,70 +203,182 @@ fn generate_struct_deserialize(
 
             let name_str = name.to_string();
 
+            // Generate per-field dispatch for try_deserialize (strict mode only)
+            let strict_arms: Vec<_> = field_name_strs
+                .iter()
+                .zip(&field_types)
+                .zip(&field_attrs)
+                .map(|((field_name_str, field_ty), attrs)| {
+                    if attrs.deserialize_with.is_some() {
+                        // Custom functions are coercions - leave them to the lenient pass
+                        quote! {
+                            #field_name_str => None,
+                        }
+                    } else {
+                        quote! {
+                            #field_name_str => {
+                                // Try strict deserialization
+                                <#field_ty as ::tryparse::deserializer::LlmDeserialize>::try_deserialize(field_value, field_ctx)
+                                    .map(|v| Box::new(v) as Box<dyn Any>)
+                            }
+                        }
+                    }
+                })
+                .collect();
+
+            // Generate per-field dispatch for deserialize
+            let lenient_arms: Vec<_> = field_name_strs
+                .iter()
+                .zip(inner_types.iter().zip(&field_types))
+                .zip(&field_attrs)
+                .map(|((field_name_str, (inner_ty, field_ty)), attrs)| {
+                    // #[llm(skip_bad_items)] turns the mode on for this field only
+                    let (set_skip, restore_skip) = if attrs.skip_bad_items {
+                        (
+                            quote! { let skip_was = field_ctx.set_skip_bad_items(true); },
+                            quote! { field_ctx.set_skip_bad_items(skip_was); },
+                        )
+                    } else {
+                        (quote! {}, quote! {})
+                    };
+                    if let Some(path) = &attrs.deserialize_with {
+                        quote! {
+                            #field_name_str => {
+                                if strict {
+                                    Err(::tryparse::error::ParseError::DeserializeFailed(
+                                        ::tryparse::error::DeserializeError::type_mismatch(
+                                            stringify!(#inner_ty),
+                                            "value"
+                                        )
+                                    ))
+                                } else {
+                                    // Custom coercion function
+                                    #set_skip
+                                    let v: ::tryparse::error::Result<#inner_ty> = #path(field_value, field_ctx);
+                                    #restore_skip
+                                    Ok(Box::new(v?) as Box<dyn Any>)
+                                }
+                            }
+                        }
+                    } else {
+                        quote! {
+                            #field_name_str => {
+                                if strict {
+                                    // Try strict deserialization
+                                    if let Some(v) = <#field_ty as ::tryparse::deserializer::LlmDeserialize>::try_deserialize(field_value, field_ctx) {
+                                        Ok(Box::new(v) as Box<dyn Any>)
+                                    } else {
+                                        Err(::tryparse::error::ParseError::DeserializeFailed(
+                                            ::tryparse::error::DeserializeError::type_mismatch(
+                                                stringify!(#field_ty),
+                                                "value"
+                                            )
+                                        ))
+                                    }
+                                } else {
+                                    // Lenient deserialization
+                                    #set_skip
+                                    let v = <#field_ty as ::tryparse::deserializer::LlmDeserialize>::deserialize(field_value, field_ctx);
+                                    #restore_skip
+                                    Ok(Box::new(v?) as Box<dyn Any>)
+                                }
+                            }
+                        }
+                    }
+                })
+                .collect();
+
             // Generate field descriptor setup (collect to Vec for reuse)
             let field_descriptors: Vec<_> = field_name_strs
                 .iter()
                 .zip(&field_types)
-                .zip(&is_optional)
-                .map(|((name, ty), opt)| {
+                .zip(is_optional.iter().zip(&is_maybe))
+                .map(|((name, ty), (opt, maybe))| {
                     let type_name = quote!(stringify!(#ty)).to_string();
+                    let opt = *opt || *maybe;
+                    // A Maybe value that doesn't parse isn't "absent"
+                    let fail_if_unparseable = if *maybe {
+                        quote! { .fail_if_unparseable() }
+                    } else {
+                        quote! {}
+                    };
                     quote! {
                         .field(::tryparse::deserializer::FieldDescriptor::new(
                             #name,
                             #type_name,
                             #opt
-                        ))
+                        )#fail_if_unparseable)
                     }
                 })
                 .collect();
 
-            // Generate field extraction for try_deserialize (returns Option)
-            let field_extractions_strict: Vec<_> = field_names
+            // Generate field extraction (Box<dyn Any> → field value). Fields with a
+            // custom function store the inner type; the rest store the full field type.
+            let field_extractions: Vec<_> = field_names
                 .iter()
-                .zip(&inner_types)
-                .zip(&is_optional)
-                .map(|((field_name, inner_ty), opt)| {
+                .zip(inner_types.iter().zip(&field_types))
+                .zip(is_optional.iter().zip(&is_maybe).zip(&field_attrs))
+                .map(|((field_name, (inner_ty, field_ty)), ((opt, maybe), attrs))| {
                     let field_name_str = field_name.as_ref().unwrap().to_string();
-                    if *opt {
-                        // Optional field
-                        quote! {
+                    let custom = attrs.deserialize_with.is_some();
+                    match (*opt, *maybe, custom) {
+                        // Optional field with a custom function
+                        (true, _, true) => quote! {
                             let #field_name = fields.get(#field_name_str)
                                 .and_then(|v| v.downcast_ref::<#inner_ty>())
                                 .cloned();
-                        }
-                    } else {
-                        // Required field - return None if missing
-                        quote! {
+                        },
+                        // Optional field - missing or unparseable is None
+                        (true, _, false) => quote! {
+                            let #field_name = fields.get(#field_name_str)
+                                .and_then(|v| v.downcast_ref::<#field_ty>())
+                                .cloned()
+                                .flatten();
+                        },
+                        // Maybe field with a custom function
+                        (_, true, true) => quote! {
                             let #field_name = fields.get(#field_name_str)
                                 .and_then(|v| v.downcast_ref::<#inner_ty>())
-                                .cloned()?;
-                        }
+                                .cloned()
+                                .map_or(::tryparse::deserializer::Maybe::Absent, ::tryparse::deserializer::Maybe::Value);
+                        },
+                        // Maybe field - missing is Absent
+                        (_, true, false) => quote! {
+                            let #field_name = fields.get(#field_name_str)
+                                .and_then(|v| v.downcast_ref::<#field_ty>())
+                                .cloned()
+                                .unwrap_or_default();
+                        },
+                        // Required field
+                        _ => quote! {
+                            let #field_name = fields.get(#field_name_str)
+                                .and_then(|v| v.downcast_ref::<#inner_ty>())
+                                .cloned();
+                        },
                     }
                 })
                 .collect();
 
-            // Generate field extraction for deserialize (returns Result)
-            let field_extractions_lenient: Vec<_> = field_names.iter().zip(&inner_types).zip(&is_optional).map(|((field_name, inner_ty), opt)| {
-                let field_name_str = field_name.as_ref().unwrap().to_string();
-                if *opt {
-                    // Optional field
-                    quote! {
-                        let #field_name = fields.get(#field_name_str)
-                            .and_then(|v| v.downcast_ref::<#inner_ty>())
-                            .cloned();
-                    }
-                } else {
-                    // Required field
+            // Required fields are missing if the extraction above came back empty
+            let required_strict: Vec<_> = field_names
+                .iter()
+                .zip(is_optional.iter().zip(&is_maybe))
+                .filter(|(_, (opt, maybe))| !**opt && !**maybe)
+                .map(|(field_name, _)| quote! { let #field_name = #field_name?; })
+                .collect();
+            let required_lenient: Vec<_> = field_names
+                .iter()
+                .zip(is_optional.iter().zip(&is_maybe))
+                .filter(|(_, (opt, maybe))| !**opt && !**maybe)
+                .map(|(field_name, _)| {
+                    let field_name_str = field_name.as_ref().unwrap().to_string();
                     quote! {
-                        let #field_name = fields.get(#field_name_str)
-                            .and_then(|v| v.downcast_ref::<#inner_ty>())
-                            .cloned()
-                            .ok_or_else(|| ::tryparse::error::ParseError::DeserializeFailed(
-                                ::tryparse::error::DeserializeError::missing_field(#field_name_str)
-                            ))?;
+                        let #field_name = #field_name.ok_or_else(|| ::tryparse::error::ParseError::DeserializeFailed(
+                            ::tryparse::error::DeserializeError::missing_field(#field_name_str)
+                        ))?;
                     }
-                }
-            }).collect();
+                })
+                .collect();
 
             quote! {
                 fn try_deserialize(
@@ -208,20 +397,15 @@ fn generate_struct_deserialize(
                         |field_name, field_value, field_ctx| {
                             // Dispatch to the appropriate field type's LlmDeserialize impl (strict mode only)
                             match field_name {
-                                #(
-                                    #field_name_strs => {
-                                        // Try strict deserialization
-                                        <#inner_types as ::tryparse::deserializer::LlmDeserialize>::try_deserialize(field_value, field_ctx)
-                                            .map(|v| Box::new(v) as Box<dyn Any>)
-                                    }
-                                )*
+                                #(#strict_arms)*
                                 _ => None
                             }
                         }
                     ).ok()?;
 
                     // Extract fields from Box<dyn Any> (strict mode - return None on failure)
-                    #(#field_extractions_strict)*
+                    #(#field_extractions)*
+                    #(#required_strict)*
 
                     Some(Self {
                         #(#field_names),*
@@ -244,27 +428,7 @@ fn generate_struct_deserialize(
                         |field_name, field_value, field_ctx, strict| {
                             // Dispatch to the appropriate field type's LlmDeserialize impl
                             match field_name {
-                                #(
-                                    #field_name_strs => {
-                                        if strict {
-                                            // Try strict deserialization
-                                            if let Some(v) = <#inner_types as ::tryparse::deserializer::LlmDeserialize>::try_deserialize(field_value, field_ctx) {
-                                                Ok(Box::new(v) as Box<dyn Any>)
-                                            } else {
-                                                Err(::tryparse::error::ParseError::DeserializeFailed(
-                                                    ::tryparse::error::DeserializeError::type_mismatch(
-                                                        stringify!(#inner_types),
-                                                        "value"
-                                                    )
-                                                ))
-                                            }
-                                        } else {
-                                            // Lenient deserialization
-                                            let v = <#inner_types as ::tryparse::deserializer::LlmDeserialize>::deserialize(field_value, field_ctx)?;
-                                            Ok(Box::new(v) as Box<dyn Any>)
-                                        }
-                                    }
-                                )*
+                                #(#lenient_arms)*
                                 _ => Err(::tryparse::error::ParseError::DeserializeFailed(
                                     ::tryparse::error::DeserializeError::Custom(
                                         format!("Unknown field: {}", field_name)
@@ -275,7 +439,8 @@ fn generate_struct_deserialize(
                     )?;
 
                     // Extract fields from Box<dyn Any> (lenient mode - return error on failure)
-                    #(#field_extractions_lenient)*
+                    #(#field_extractions)*
+                    #(#required_lenient)*
 
                     Ok(Self {
                         #(#field_names),*
@@ -296,6 +461,63 @@ fn generate_struct_deserialize(
     }
 }
 
+/// Field-level `#[llm(...)]` attributes.
+#[derive(Default)]
+struct FieldAttrs {
+    /// Function used instead of the field type's `LlmDeserialize` impl.
+    ///
+    /// Set by `#[llm(deserialize_with = "path::to::fn")]`, or by
+    /// `#[llm(with = "path::to::module")]` which resolves to `module::deserialize`.
+    deserialize_with: Option<syn::Path>,
+    /// Set by `#[llm(skip_bad_items)]`: drop list items that don't coerce.
+    skip_bad_items: bool,
+    /// Set by `#[llm(maybe)]`: the field is a `Maybe<T>` imported under that name.
+    maybe: bool,
+}
+
+impl FieldAttrs {
+    fn from_field(field: &syn::Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("llm")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("with") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    let mut path: syn::Path = lit.parse()?;
+                    path.segments
+                        .push(syn::Ident::new("deserialize", lit.span()).into());
+                    attrs.set_deserialize_with(&meta, path)
+                } else if meta.path.is_ident("deserialize_with") {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    attrs.set_deserialize_with(&meta, lit.parse()?)
+                } else if meta.path.is_ident("skip_bad_items") {
+                    attrs.skip_bad_items = true;
+                    Ok(())
+                } else if meta.path.is_ident("maybe") {
+                    attrs.maybe = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported llm field attribute"))
+                }
+            })?;
+        }
+
+        Ok(attrs)
+    }
+
+    fn set_deserialize_with(
+        &mut self,
+        meta: &syn::meta::ParseNestedMeta,
+        path: syn::Path,
+    ) -> syn::Result<()> {
+        if self.deserialize_with.is_some() {
+            return Err(meta.error("duplicate `with` / `deserialize_with` attribute"));
+        }
+        self.deserialize_with = Some(path);
+        Ok(())
+    }
+}
+
 /// Check if a type is Option<T>
 fn is_option_type(ty: &Type) -> bool {
     if let Type::Path(type_path) = ty {
@@ -306,11 +528,50 @@ fn is_option_type(ty: &Type) -> bool {
     false
 }
 
-/// Extract the inner type T from Option<T>
-fn extract_option_inner(ty: &Type) -> Type {
+/// Check if a field is `tryparse::deserializer::Maybe<T>`, written out in full
+/// or marked with `#[llm(maybe)]`.
+///
+/// A bare `Maybe<T>` could be any type named `Maybe`, so it needs the attribute.
+fn is_maybe_field(ty: &Type, attrs: &FieldAttrs) -> syn::Result<bool> {
+    let segment = match ty {
+        Type::Path(type_path) => type_path.path.segments.last(),
+        _ => None,
+    };
+    let is_maybe_generic = segment.is_some_and(|segment| {
+        segment.ident == "Maybe"
+            && matches!(
+                &segment.arguments,
+                PathArguments::AngleBracketed(args) if args.args.len() == 1
+            )
+    });
+    let full_path = match ty {
+        Type::Path(type_path) => {
+            let idents: Vec<_> = type_path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            idents == ["tryparse", "deserializer", "Maybe"]
+        }
+        _ => false,
+    };
+
+    match (is_maybe_generic, full_path || attrs.maybe) {
+        (true, is_maybe) => Ok(is_maybe),
+        (false, _) if attrs.maybe => Err(syn::Error::new_spanned(
+            ty,
+            "#[llm(maybe)] needs a `Maybe<T>` field",
+        )),
+        (false, _) => Ok(false),
+    }
+}
+
+/// Extract the inner type T from Option<T> or Maybe<T>
+fn extract_generic_inner(ty: &Type) -> Type {
     if let Type::Path(type_path) = ty {
         if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Option" {
+            if segment.ident == "Option" || segment.ident == "Maybe" {
                 if let PathArguments::AngleBracketed(args) = &segment.arguments {
                     if let Some(GenericArgument::Type(inner)) = args.args.first() {
                         return inner.clone();
@@ -489,18 +750,22 @@ fn generate_union_deserialize(
 
             let mut matches = Vec::new();
 
-            // Try variant 1 with separate FlexValue to track transformations
-            let value1 = value.clone();
-            if let Ok(_) = <#variant1_type as LlmDeserialize>::deserialize(&value1, ctx) {
-                let score: u32 = value1.transformations().iter().map(|t| t.penalty()).sum();
-                matches.push(MatchResult { variant: 1, score });
+            // Try variant 1 on a scratch context to score its transformations;
+            // drop it if the coercion policy forbids any of them
+            let mut ctx1 = ctx.clone();
+            if <#variant1_type as LlmDeserialize>::deserialize(value, &mut ctx1).is_ok()
+                && ctx1.check_policy().is_ok()
+            {
+                matches.push(MatchResult { variant: 1, score: ctx1.score() });
             }
 
-            // Try variant 2 with separate FlexValue to track transformations
-            let value2 = value.clone();
-            if let Ok(_) = <#variant2_type as LlmDeserialize>::deserialize(&value2, ctx) {
-                let score: u32 = value2.transformations().iter().map(|t| t.penalty()).sum();
-                matches.push(MatchResult { variant: 2, score });
+            // Try variant 2 on a scratch context to score its transformations;
+            // drop it if the coercion policy forbids any of them
+            let mut ctx2 = ctx.clone();
+            if <#variant2_type as LlmDeserialize>::deserialize(value, &mut ctx2).is_ok()
+                && ctx2.check_policy().is_ok()
+            {
+                matches.push(MatchResult { variant: 2, score: ctx2.score() });
             }
 
             if matches.is_empty() {
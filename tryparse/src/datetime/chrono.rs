@@ -0,0 +1,265 @@
+//! `LlmDeserialize` impls and serde helpers for `chrono` types.
+//!
+//! Supported: [`NaiveDate`], [`NaiveTime`], [`NaiveDateTime`], [`DateTime<Utc>`],
+//! [`DateTime<FixedOffset>`] and [`FixedOffset`].
+//!
+//! Missing components are filled in conservatively: a date without a time is
+//! midnight, and a datetime without an offset is taken as UTC.
+//!
+//! # Serde
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Event {
+//!     #[serde(with = "tryparse::datetime::chrono::naive_date")]
+//!     day: NaiveDate,
+//! }
+//!
+//! let event: Event = tryparse::parse(r#"{"day": "March 3rd, 2024"}"#).unwrap();
+//! assert_eq!(event.day, NaiveDate::from_ymd_opt(2024, 3, 3).unwrap());
+//! ```
+
+use ::chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+use super::{impl_llm_deserialize, parse::DateTimeParts, serde_helper, Shape};
+use crate::{
+    deserializer::{CoercionContext, LlmDeserialize},
+    error::{DeserializeError, ParseError, Result},
+    value::FlexValue,
+};
+
+fn to_naive_date(parts: &DateTimeParts) -> Option<NaiveDate> {
+    let date = parts.date?;
+    NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+}
+
+fn to_naive_time(parts: &DateTimeParts) -> Option<NaiveTime> {
+    let time = parts.time?;
+    NaiveTime::from_hms_nano_opt(time.hour, time.minute, time.second, time.nanosecond)
+}
+
+fn to_naive_datetime(parts: &DateTimeParts) -> Option<NaiveDateTime> {
+    let time = match parts.time {
+        Some(_) => to_naive_time(parts)?,
+        None => NaiveTime::from_hms_opt(0, 0, 0)?,
+    };
+    Some(to_naive_date(parts)?.and_time(time))
+}
+
+fn to_fixed_datetime(parts: &DateTimeParts) -> Option<DateTime<FixedOffset>> {
+    let offset = FixedOffset::east_opt(parts.offset.unwrap_or(0))?;
+    offset
+        .from_local_datetime(&to_naive_datetime(parts)?)
+        .single()
+}
+
+fn to_utc_datetime(parts: &DateTimeParts) -> Option<DateTime<Utc>> {
+    Some(to_fixed_datetime(parts)?.with_timezone(&Utc))
+}
+
+impl_llm_deserialize!(NaiveDate, "NaiveDate", Shape::Date, to_naive_date);
+impl_llm_deserialize!(NaiveTime, "NaiveTime", Shape::Time, to_naive_time);
+impl_llm_deserialize!(
+    NaiveDateTime,
+    "NaiveDateTime",
+    Shape::LocalDateTime,
+    to_naive_datetime
+);
+impl_llm_deserialize!(
+    DateTime<Utc>,
+    "DateTime<Utc>",
+    Shape::OffsetDateTime,
+    to_utc_datetime
+);
+impl_llm_deserialize!(
+    DateTime<FixedOffset>,
+    "DateTime<FixedOffset>",
+    Shape::OffsetDateTime,
+    to_fixed_datetime
+);
+
+impl LlmDeserialize for FixedOffset {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        FixedOffset::east_opt(super::try_coerce_offset(value)?)
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        let seconds = super::coerce_offset(value, ctx)?;
+        FixedOffset::east_opt(seconds).ok_or_else(|| {
+            ParseError::DeserializeFailed(DeserializeError::invalid_value(format!(
+                "UTC offset of {} seconds is out of range",
+                seconds
+            )))
+        })
+    }
+
+    fn type_name() -> &'static str {
+        "FixedOffset"
+    }
+}
+
+serde_helper!(
+    /// Serde helper for [`NaiveDate`](::chrono::NaiveDate).
+    naive_date,
+    ::chrono::NaiveDate
+);
+serde_helper!(
+    /// Serde helper for [`NaiveTime`](::chrono::NaiveTime).
+    naive_time,
+    ::chrono::NaiveTime
+);
+serde_helper!(
+    /// Serde helper for [`NaiveDateTime`](::chrono::NaiveDateTime).
+    naive_datetime,
+    ::chrono::NaiveDateTime
+);
+serde_helper!(
+    /// Serde helper for [`DateTime<Utc>`](::chrono::DateTime).
+    datetime_utc,
+    ::chrono::DateTime<::chrono::Utc>
+);
+serde_helper!(
+    /// Serde helper for [`DateTime<FixedOffset>`](::chrono::DateTime).
+    datetime_fixed_offset,
+    ::chrono::DateTime<::chrono::FixedOffset>
+);
+serde_helper!(
+    /// Serde helper for [`FixedOffset`](::chrono::FixedOffset).
+    fixed_offset,
+    ::chrono::FixedOffset
+);
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{datetime::DateOrder, value::Source, value::Transformation};
+
+    /// 2024-03-10T12:00:00Z
+    const REFERENCE: i64 = 1_710_072_000;
+
+    fn flex(value: serde_json::Value) -> FlexValue {
+        FlexValue::new(value, Source::Direct)
+    }
+
+    fn ctx() -> CoercionContext {
+        CoercionContext::new().with_reference_time(REFERENCE)
+    }
+
+    #[test]
+    fn test_naive_date_strict_and_lenient() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 3).unwrap();
+        let mut ctx = ctx();
+
+        assert_eq!(
+            NaiveDate::try_deserialize(&flex(json!("2024-03-03")), &mut ctx),
+            Some(expected)
+        );
+        assert_eq!(
+            NaiveDate::try_deserialize(&flex(json!("March 3rd, 2024")), &mut ctx),
+            None
+        );
+        assert!(ctx.transformations().is_empty());
+
+        let date = NaiveDate::deserialize(&flex(json!("March 3rd, 2024")), &mut ctx).unwrap();
+        assert_eq!(date, expected);
+        assert!(matches!(
+            &ctx.transformations()[0],
+            Transformation::DateTimeParsed { original, format }
+                if original == "March 3rd, 2024" && format == "Month D, YYYY"
+        ));
+    }
+
+    #[test]
+    fn test_date_order_policy() {
+        let value = flex(json!("03/04/2024"));
+
+        let mut month_first = ctx();
+        assert_eq!(
+            NaiveDate::deserialize(&value, &mut month_first).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()
+        );
+
+        let mut day_first = ctx().with_date_order(DateOrder::DayFirst);
+        assert_eq!(
+            NaiveDate::deserialize(&value, &mut day_first).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 3).unwrap()
+        );
+
+        let mut strict = ctx().with_date_order(DateOrder::Unambiguous);
+        assert!(NaiveDate::deserialize(&value, &mut strict).is_err());
+    }
+
+    #[test]
+    fn test_relative_date() {
+        let mut ctx = ctx();
+        let date = NaiveDate::deserialize(&flex(json!("yesterday")), &mut ctx).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 9).unwrap());
+    }
+
+    #[test]
+    fn test_datetimes() {
+        let mut ctx = ctx();
+
+        let utc =
+            DateTime::<Utc>::deserialize(&flex(json!("2024-03-03T10:00Z")), &mut ctx).unwrap();
+        assert_eq!(utc.to_rfc3339(), "2024-03-03T10:00:00+00:00");
+        assert!(ctx.transformations().is_empty());
+
+        let fixed = DateTime::<FixedOffset>::deserialize(
+            &flex(json!("March 3, 2024 3:15 pm EST")),
+            &mut ctx,
+        )
+        .unwrap();
+        assert_eq!(fixed.to_rfc3339(), "2024-03-03T15:15:00-05:00");
+
+        let from_timestamp =
+            DateTime::<Utc>::deserialize(&flex(json!(REFERENCE)), &mut ctx).unwrap();
+        assert_eq!(from_timestamp.timestamp(), REFERENCE);
+
+        let naive = NaiveDateTime::deserialize(&flex(json!("03/03/2024 14:30")), &mut ctx).unwrap();
+        assert_eq!(naive.to_string(), "2024-03-03 14:30:00");
+
+        let time = NaiveTime::deserialize(&flex(json!("10am")), &mut ctx).unwrap();
+        assert_eq!(time, NaiveTime::from_hms_opt(10, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_fixed_offset() {
+        let mut ctx = ctx();
+
+        let strict = FixedOffset::try_deserialize(&flex(json!("+05:30")), &mut ctx).unwrap();
+        assert_eq!(strict.local_minus_utc(), 19_800);
+
+        let lenient = FixedOffset::deserialize(&flex(json!("UTC-8")), &mut ctx).unwrap();
+        assert_eq!(lenient.local_minus_utc(), -28_800);
+        assert_eq!(ctx.transformations().len(), 1);
+
+        assert!(FixedOffset::deserialize(&flex(json!("somewhere")), &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_serde_helpers() {
+        #[derive(Deserialize)]
+        struct Event {
+            #[serde(with = "naive_date")]
+            day: NaiveDate,
+            #[serde(deserialize_with = "naive_date::deserialize_day_first")]
+            other: NaiveDate,
+            #[serde(default, with = "naive_date::option")]
+            maybe: Option<NaiveDate>,
+        }
+
+        let event: Event =
+            crate::parse(r#"{"day": "3 March 2024", "other": "03/04/2024", "maybe": null}"#)
+                .unwrap();
+        assert_eq!(event.day, NaiveDate::from_ymd_opt(2024, 3, 3).unwrap());
+        assert_eq!(event.other, NaiveDate::from_ymd_opt(2024, 4, 3).unwrap());
+        assert_eq!(event.maybe, None);
+    }
+}
@@ -0,0 +1,95 @@
+//! Proleptic Gregorian calendar arithmetic.
+//!
+//! Shared by the date parser behind the `chrono` and `time` features and by
+//! the JavaScript strategy's `new Date(...)` conversion, so it needs no
+//! feature.
+
+/// A calendar date (proleptic Gregorian).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CivilDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// Returns the date, or `None` if the month or day is out of range.
+pub(crate) fn civil_date(year: i32, month: u32, day: u32) -> Option<CivilDate> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(CivilDate { year, month, day })
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`).
+///
+/// Every `i32` year fits, so this can't overflow.
+pub(crate) fn days_from_civil(date: CivilDate) -> i64 {
+    let y = i64::from(date.year) - i64::from(date.month <= 2);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (i64::from(date.month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(date.day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of [`days_from_civil`], or `None` if the year doesn't fit in an
+/// `i32`.
+pub(crate) fn civil_from_days(days: i64) -> Option<CivilDate> {
+    let z = days.checked_add(719_468)?;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = era
+        .checked_mul(400)?
+        .checked_add(yoe + i64::from(month <= 2))?;
+
+    Some(CivilDate {
+        year: i32::try_from(year).ok()?,
+        month,
+        day,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_roundtrip() {
+        for days in [-719_468, -1, 0, 1, 19_792, 2_932_896] {
+            assert_eq!(days_from_civil(civil_from_days(days).unwrap()), days);
+        }
+        assert_eq!(
+            civil_from_days(0),
+            Some(CivilDate {
+                year: 1970,
+                month: 1,
+                day: 1
+            })
+        );
+        assert_eq!(civil_from_days(i64::MAX), None);
+        assert_eq!(civil_from_days(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert!(civil_date(2024, 2, 29).is_some());
+        assert!(civil_date(2023, 2, 29).is_none());
+        assert!(civil_date(2024, 13, 1).is_none());
+        assert!(civil_date(2024, 4, 0).is_none());
+    }
+}
@@ -0,0 +1,657 @@
+//! `LlmDeserialize` impl and serde helper for [`std::time::Duration`].
+//!
+//! Accepted forms:
+//! - ISO 8601: `"PT2H"`, `"P1DT12H"`, `"PT0.5S"`
+//! - Go-style compact: `"1h30m"`, `"300ms"`, `"2h45m30.5s"`
+//! - English phrases: `"5 minutes"`, `"1 hour and 30 minutes"`, `"an hour"`, `"half a day"`
+//! - Clock notation: `"01:30:00"`, `"1:30"` (hours and minutes)
+//! - Bare numbers, read in the context's [`DurationUnit`] (seconds by default)
+//! - Serde's own representation: `{"secs": 5, "nanos": 0}`
+//!
+//! Approximate markers (`"about 2 weeks"`, `"~5 min"`, `"an hour or so"`) are
+//! stripped and recorded as [`Transformation::ApproximateValue`]. Years and
+//! months are taken as 365 and 30 days.
+//!
+//! # Serde
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Task {
+//!     #[serde(with = "tryparse::datetime::duration")]
+//!     estimate: Duration,
+//! }
+//!
+//! let task: Task = tryparse::parse(r#"{"estimate": "about 1h30m"}"#).unwrap();
+//! assert_eq!(task.estimate, Duration::from_secs(5400));
+//! ```
+//!
+//! [`Transformation::ApproximateValue`]: crate::value::Transformation::ApproximateValue
+
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserializer;
+use serde_json::Value;
+
+use crate::{
+    deserializer::{
+        natural_numbers::strip_approximate, primitives::value_type_name, CoercionContext,
+        LlmDeserialize,
+    },
+    error::{DeserializeError, ParseError, Result},
+    value::{FlexValue, Source, Transformation},
+};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Unit for bare numbers like `90` or `"1.5"` when deserializing a [`Duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DurationUnit {
+    /// Nanoseconds.
+    Nanoseconds,
+    /// Microseconds.
+    Microseconds,
+    /// Milliseconds.
+    Milliseconds,
+    /// Seconds.
+    #[default]
+    Seconds,
+    /// Minutes.
+    Minutes,
+    /// Hours.
+    Hours,
+    /// Days (24 hours).
+    Days,
+}
+
+impl DurationUnit {
+    const fn nanos(self) -> u128 {
+        match self {
+            Self::Nanoseconds => 1,
+            Self::Microseconds => 1_000,
+            Self::Milliseconds => 1_000_000,
+            Self::Seconds => NANOS_PER_SECOND,
+            Self::Minutes => 60 * NANOS_PER_SECOND,
+            Self::Hours => 3_600 * NANOS_PER_SECOND,
+            Self::Days => 86_400 * NANOS_PER_SECOND,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Nanoseconds => "nanoseconds",
+            Self::Microseconds => "microseconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Seconds => "seconds",
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+        }
+    }
+}
+
+impl LlmDeserialize for Duration {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        match &value.value {
+            Value::String(s) => match parse_iso(s.trim())? {
+                (duration, false) => Some(duration),
+                (_, true) => None,
+            },
+            Value::Object(map) => from_serde_repr(map),
+            _ => None,
+        }
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        if let Some(duration) = Self::try_deserialize(value, ctx) {
+            return Ok(duration);
+        }
+
+        let unit = ctx.duration_unit();
+        let (duration, format, marker) = match &value.value {
+            Value::Number(n) => {
+                let number = n.to_string();
+                let nanos = nanos_from_number(&number, unit.nanos())
+                    .ok_or_else(|| negative_or_invalid(&number))?;
+                (nanos, format!("number ({})", unit.name()), None)
+            }
+            Value::String(s) => parse_text(s, unit)?,
+            // Single-element arrays unwrap, as for the primitive types
+            Value::Array(items) if items.len() == 1 => {
+                let inner = FlexValue::new(items[0].clone(), value.source.clone());
+                ctx.add_transformation(Transformation::SingleToArray);
+                return Self::deserialize(&inner, ctx);
+            }
+            other => {
+                return Err(ParseError::DeserializeFailed(
+                    DeserializeError::TypeMismatch {
+                        expected: "duration",
+                        found: value_type_name(other),
+                    },
+                ))
+            }
+        };
+
+        let original = match &value.value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        if let Some(marker) = marker {
+            ctx.add_transformation(Transformation::ApproximateValue {
+                original: original.clone(),
+                marker,
+            });
+        }
+        ctx.add_transformation(Transformation::DurationParsed { original, format });
+
+        Ok(duration)
+    }
+
+    fn type_name() -> &'static str {
+        "Duration"
+    }
+}
+
+/// Serde helper: bare numbers are read as seconds.
+pub fn deserialize<'de, D>(deserializer: D) -> std::result::Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_with(deserializer, DurationUnit::Seconds)
+}
+
+/// Serde helper: bare numbers are read as milliseconds.
+pub fn deserialize_millis<'de, D>(deserializer: D) -> std::result::Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_with(deserializer, DurationUnit::Milliseconds)
+}
+
+/// Same as the parent module, for `Option<Duration>` fields. `null` becomes `None`.
+pub mod option {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer};
+
+    use super::DurationUnit;
+
+    /// Serde helper: bare numbers are read as seconds.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, DurationUnit::Seconds)
+    }
+
+    /// Serde helper: bare numbers are read as milliseconds.
+    pub fn deserialize_millis<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, DurationUnit::Milliseconds)
+    }
+
+    fn deserialize_option<'de, D>(
+        deserializer: D,
+        unit: DurationUnit,
+    ) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => super::deserialize_with(value, unit)
+                .map(Some)
+                .map_err(<D::Error as serde::de::Error>::custom),
+        }
+    }
+}
+
+fn deserialize_with<'de, D>(
+    deserializer: D,
+    unit: DurationUnit,
+) -> std::result::Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+    let mut ctx = CoercionContext::new().with_duration_unit(unit);
+
+    <Duration as LlmDeserialize>::deserialize(&FlexValue::new(value, Source::Direct), &mut ctx)
+        .map_err(serde::de::Error::custom)
+}
+
+// ================================================================================================
+// Parsing
+// ================================================================================================
+
+static ISO_DURATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^P(?:([\d.]+)Y)?(?:([\d.]+)M)?(?:([\d.]+)W)?(?:([\d.]+)D)?(?:T(?:([\d.]+)H)?(?:([\d.]+)M)?(?:([\d.]+)S)?)?$",
+    )
+    .unwrap()
+});
+
+static COMPACT_DURATION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:\d+(?:\.\d+)?(?:ns|us|µs|μs|ms|s|m|h|d|w))+$").unwrap());
+
+static COMPACT_COMPONENT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d+(?:\.\d+)?)(ns|us|µs|μs|ms|s|m|h|d|w)").unwrap());
+
+static CLOCK_DURATION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d+):([0-5]\d)(?::([0-5]\d)(\.\d+)?)?$").unwrap());
+
+/// Parses an ISO 8601 duration. The flag is set when calendar units (years,
+/// months) had to be approximated.
+fn parse_iso(s: &str) -> Option<(Duration, bool)> {
+    let caps = ISO_DURATION.captures(s)?;
+    // "P" and "PT" alone are not durations
+    if caps.iter().skip(1).all(|m| m.is_none()) || s.to_ascii_uppercase().ends_with('T') {
+        return None;
+    }
+
+    const UNITS: [u128; 7] = [
+        365 * 86_400 * NANOS_PER_SECOND,
+        30 * 86_400 * NANOS_PER_SECOND,
+        7 * 86_400 * NANOS_PER_SECOND,
+        86_400 * NANOS_PER_SECOND,
+        3_600 * NANOS_PER_SECOND,
+        60 * NANOS_PER_SECOND,
+        NANOS_PER_SECOND,
+    ];
+
+    let mut total: u128 = 0;
+    for (index, unit) in UNITS.iter().enumerate() {
+        if let Some(m) = caps.get(index + 1) {
+            total = total.checked_add(nanos_from_decimal(m.as_str(), *unit)?)?;
+        }
+    }
+    let calendar = caps.get(1).is_some() || caps.get(2).is_some();
+    Some((duration_from_nanos(total)?, calendar))
+}
+
+/// Parses `{"secs": u64, "nanos": u32}`, which is how serde serializes `Duration`.
+fn from_serde_repr(map: &serde_json::Map<String, Value>) -> Option<Duration> {
+    if map.len() != 2 {
+        return None;
+    }
+    let secs = map.get("secs")?.as_u64()?;
+    let nanos = u32::try_from(map.get("nanos")?.as_u64()?).ok()?;
+    if u128::from(nanos) >= NANOS_PER_SECOND {
+        return None;
+    }
+    Some(Duration::new(secs, nanos))
+}
+
+/// Lenient string parsing: returns the duration, a format name and the approximate marker if any.
+fn parse_text(raw: &str, unit: DurationUnit) -> Result<(Duration, String, Option<String>)> {
+    let (text, marker) = strip_approximate(raw.trim());
+    let text = text.trim_end_matches(['.', ',']).trim();
+    let invalid = || negative_or_invalid(raw);
+    if text.starts_with('-') {
+        return Err(invalid());
+    }
+
+    if let Some((duration, calendar)) = parse_iso(text) {
+        let format = if calendar {
+            "ISO 8601 (calendar units approximated)"
+        } else {
+            "ISO 8601"
+        };
+        return Ok((duration, format.to_string(), marker));
+    }
+
+    if let Some(nanos) = nanos_from_number(text, unit.nanos()) {
+        return Ok((nanos, format!("number ({})", unit.name()), marker));
+    }
+
+    let lower = text.to_lowercase();
+    if COMPACT_DURATION.is_match(&lower) {
+        let mut total: u128 = 0;
+        for caps in COMPACT_COMPONENT.captures_iter(&lower) {
+            let unit = unit_nanos(&caps[2]).ok_or_else(invalid)?;
+            let nanos = nanos_from_decimal(&caps[1], unit).ok_or_else(invalid)?;
+            total = total.checked_add(nanos).ok_or_else(invalid)?;
+        }
+        let duration = duration_from_nanos(total).ok_or_else(invalid)?;
+        return Ok((duration, "compact (1h30m)".to_string(), marker));
+    }
+
+    if let Some(caps) = CLOCK_DURATION.captures(&lower) {
+        // "1:30" reads as hours and minutes, "01:30:00" adds seconds
+        let with_seconds = caps.get(3).is_some();
+        let seconds = format!(
+            "{}{}",
+            caps.get(3).map_or("0", |m| m.as_str()),
+            caps.get(4).map_or("", |m| m.as_str())
+        );
+        let total = nanos_from_decimal(&caps[1], DurationUnit::Hours.nanos())
+            .zip(nanos_from_decimal(&caps[2], DurationUnit::Minutes.nanos()))
+            .zip(nanos_from_decimal(&seconds, NANOS_PER_SECOND))
+            .map(|((h, m), s)| h + m + s)
+            .ok_or_else(invalid)?;
+        let format = if with_seconds { "HH:MM:SS" } else { "H:MM" };
+        let duration = duration_from_nanos(total).ok_or_else(invalid)?;
+        return Ok((duration, format.to_string(), marker));
+    }
+
+    let duration = parse_phrase(&lower).ok_or_else(invalid)?;
+    Ok((duration, "English phrase".to_string(), marker))
+}
+
+/// Parses unit phrases like "1 hour and 30 minutes", "2 hrs, 15 mins" or "half an hour".
+fn parse_phrase(text: &str) -> Option<Duration> {
+    let normalized = text.replace([',', '+'], " ").replace('-', " ");
+    let tokens: Vec<&str> = normalized
+        .split_whitespace()
+        .filter(|t| *t != "and")
+        .collect();
+
+    let mut total: u128 = 0;
+    let mut amount: Option<String> = None;
+    let mut half = false;
+    let mut matched = false;
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+
+        // "1.5h" or "90min" written as one token inside a phrase
+        if let Some(caps) = COMPACT_COMPONENT.captures(token) {
+            if caps[0].len() == token.len() {
+                let unit = unit_nanos(&caps[2])?;
+                total = total.checked_add(nanos_from_decimal(&caps[1], unit)?)?;
+                matched = true;
+                i += 1;
+                continue;
+            }
+        }
+
+        if let Some(unit) = unit_nanos(token) {
+            let nanos = match (amount.take(), half) {
+                (Some(n), _) => nanos_from_decimal(&n, unit)?,
+                (None, true) => unit / 2,
+                (None, false) => return None,
+            };
+            total = total.checked_add(nanos)?;
+            half = false;
+            matched = true;
+        } else if token == "half" {
+            // "half an hour" / "half a day"; "an hour and a half" adds half the last unit
+            if matches!(tokens.get(i + 1), Some(&"a" | &"an")) {
+                half = true;
+                i += 1;
+            } else {
+                return None;
+            }
+        } else if token == "a" || token == "an" {
+            if tokens.get(i + 1) == Some(&"half") && matched {
+                let last = last_unit(&tokens[..i])?;
+                total = total.checked_add(last / 2)?;
+                i += 2;
+                continue;
+            }
+            amount = Some("1".to_string());
+        } else if is_decimal(token) && amount.is_none() {
+            amount = Some(token.to_string());
+        } else {
+            return None;
+        }
+        i += 1;
+    }
+
+    if !matched || amount.is_some() || half {
+        return None;
+    }
+    duration_from_nanos(total)
+}
+
+/// Finds the unit of the last unit word before "and a half".
+fn last_unit(tokens: &[&str]) -> Option<u128> {
+    tokens.iter().rev().find_map(|t| unit_nanos(t))
+}
+
+fn unit_nanos(word: &str) -> Option<u128> {
+    let nanos = match word {
+        "ns" | "nanosecond" | "nanoseconds" => 1,
+        "us" | "µs" | "μs" | "microsecond" | "microseconds" => 1_000,
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => 1_000_000,
+        "s" | "sec" | "secs" | "second" | "seconds" => NANOS_PER_SECOND,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60 * NANOS_PER_SECOND,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600 * NANOS_PER_SECOND,
+        "d" | "day" | "days" => 86_400 * NANOS_PER_SECOND,
+        "w" | "wk" | "wks" | "week" | "weeks" => 7 * 86_400 * NANOS_PER_SECOND,
+        "month" | "months" => 30 * 86_400 * NANOS_PER_SECOND,
+        "y" | "yr" | "yrs" | "year" | "years" => 365 * 86_400 * NANOS_PER_SECOND,
+        _ => return None,
+    };
+    Some(nanos)
+}
+
+fn is_decimal(s: &str) -> bool {
+    let mut parts = s.splitn(2, '.');
+    let int = parts.next().unwrap_or("");
+    let frac = parts.next();
+    !int.is_empty()
+        && int.bytes().all(|b| b.is_ascii_digit())
+        && frac.map_or(true, |f| {
+            !f.is_empty() && f.bytes().all(|b| b.is_ascii_digit())
+        })
+}
+
+/// Converts a non-negative decimal string times `unit` nanoseconds without going through `f64`
+/// for the integer part.
+fn nanos_from_decimal(s: &str, unit: u128) -> Option<u128> {
+    if !is_decimal(s) {
+        return None;
+    }
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    let whole = int.parse::<u128>().ok()?.checked_mul(unit)?;
+    let fraction = if frac.is_empty() {
+        0
+    } else {
+        let frac: f64 = format!("0.{frac}").parse().ok()?;
+        (frac * unit as f64).round() as u128
+    };
+    whole.checked_add(fraction)
+}
+
+fn nanos_from_number(s: &str, unit: u128) -> Option<Duration> {
+    duration_from_nanos(nanos_from_decimal(s, unit)?)
+}
+
+fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / NANOS_PER_SECOND).ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SECOND) as u32))
+}
+
+fn negative_or_invalid(raw: &str) -> ParseError {
+    let message = if raw.trim_start().starts_with('-') {
+        format!("negative duration '{}'", raw)
+    } else {
+        format!("'{}' is not a recognizable duration", raw)
+    };
+    ParseError::DeserializeFailed(DeserializeError::invalid_value(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    fn flex(value: Value) -> FlexValue {
+        FlexValue::new(value, Source::Direct)
+    }
+
+    fn parse(value: Value) -> Result<Duration> {
+        <Duration as LlmDeserialize>::deserialize(&flex(value), &mut CoercionContext::new())
+    }
+
+    #[test]
+    fn test_strict_forms() {
+        let mut ctx = CoercionContext::new();
+        assert_eq!(
+            Duration::try_deserialize(&flex(json!("PT2H30M")), &mut ctx),
+            Some(Duration::from_secs(9_000))
+        );
+        assert_eq!(
+            Duration::try_deserialize(&flex(json!({"secs": 5, "nanos": 500})), &mut ctx),
+            Some(Duration::new(5, 500))
+        );
+        assert_eq!(
+            Duration::try_deserialize(&flex(json!("5 minutes")), &mut ctx),
+            None
+        );
+        assert_eq!(Duration::try_deserialize(&flex(json!(5)), &mut ctx), None);
+        assert_eq!(
+            Duration::try_deserialize(&flex(json!("PT")), &mut ctx),
+            None
+        );
+    }
+
+    #[test]
+    fn test_iso() {
+        assert_eq!(
+            parse(json!("P1DT12H")).unwrap(),
+            Duration::from_secs(129_600)
+        );
+        assert_eq!(parse(json!("PT0.5S")).unwrap(), Duration::from_millis(500));
+        assert_eq!(parse(json!("P2W")).unwrap(), Duration::from_secs(1_209_600));
+        assert_eq!(
+            parse(json!("P1M")).unwrap(),
+            Duration::from_secs(30 * 86_400)
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        assert_eq!(parse(json!("1h30m")).unwrap(), Duration::from_secs(5_400));
+        assert_eq!(parse(json!("300ms")).unwrap(), Duration::from_millis(300));
+        assert_eq!(
+            parse(json!("2h45m30.5s")).unwrap(),
+            Duration::from_millis(9_930_500)
+        );
+        assert_eq!(parse(json!("1.5h")).unwrap(), Duration::from_secs(5_400));
+        assert_eq!(parse(json!("10µs")).unwrap(), Duration::from_micros(10));
+    }
+
+    #[test]
+    fn test_phrases() {
+        assert_eq!(parse(json!("5 minutes")).unwrap(), Duration::from_secs(300));
+        assert_eq!(
+            parse(json!("1 hour and 30 minutes")).unwrap(),
+            Duration::from_secs(5_400)
+        );
+        assert_eq!(
+            parse(json!("2 hrs, 15 mins")).unwrap(),
+            Duration::from_secs(8_100)
+        );
+        assert_eq!(parse(json!("an hour")).unwrap(), Duration::from_secs(3_600));
+        assert_eq!(
+            parse(json!("half an hour")).unwrap(),
+            Duration::from_secs(1_800)
+        );
+        assert_eq!(
+            parse(json!("an hour and a half")).unwrap(),
+            Duration::from_secs(5_400)
+        );
+        assert_eq!(
+            parse(json!("3 days.")).unwrap(),
+            Duration::from_secs(259_200)
+        );
+        assert_eq!(
+            parse(json!("1 h 30 m")).unwrap(),
+            Duration::from_secs(5_400)
+        );
+    }
+
+    #[test]
+    fn test_clock() {
+        assert_eq!(
+            parse(json!("01:30:00")).unwrap(),
+            Duration::from_secs(5_400)
+        );
+        assert_eq!(parse(json!("1:30")).unwrap(), Duration::from_secs(5_400));
+        assert_eq!(
+            parse(json!("00:00:01.25")).unwrap(),
+            Duration::from_millis(1_250)
+        );
+    }
+
+    #[test]
+    fn test_default_unit() {
+        assert_eq!(parse(json!(90)).unwrap(), Duration::from_secs(90));
+        assert_eq!(parse(json!("1.5")).unwrap(), Duration::from_millis(1_500));
+
+        let mut ctx = CoercionContext::new().with_duration_unit(DurationUnit::Minutes);
+        let duration = <Duration as LlmDeserialize>::deserialize(&flex(json!(90)), &mut ctx);
+        assert_eq!(duration.unwrap(), Duration::from_secs(5_400));
+        assert!(matches!(
+            &ctx.transformations()[0],
+            Transformation::DurationParsed { format, .. } if format == "number (minutes)"
+        ));
+    }
+
+    #[test]
+    fn test_approximate() {
+        let mut ctx = CoercionContext::new();
+        let duration =
+            <Duration as LlmDeserialize>::deserialize(&flex(json!("about 2 weeks")), &mut ctx)
+                .unwrap();
+        assert_eq!(duration, Duration::from_secs(14 * 86_400));
+
+        let transformations = ctx.transformations();
+        assert_eq!(transformations.len(), 2);
+        assert!(matches!(
+            &transformations[0],
+            Transformation::ApproximateValue { original, marker }
+                if original == "about 2 weeks" && marker == "about"
+        ));
+        assert!(matches!(
+            &transformations[1],
+            Transformation::DurationParsed { format, .. } if format == "English phrase"
+        ));
+
+        assert_eq!(parse(json!("~5 min")).unwrap(), Duration::from_secs(300));
+        assert_eq!(
+            parse(json!("an hour or so")).unwrap(),
+            Duration::from_secs(3_600)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse(json!("-5 minutes")).is_err());
+        assert!(parse(json!(-5)).is_err());
+        assert!(parse(json!("soon")).is_err());
+        assert!(parse(json!("5 parsecs")).is_err());
+        assert!(parse(json!("minutes")).is_err());
+        assert!(parse(json!(true)).is_err());
+    }
+
+    #[test]
+    fn test_serde_helpers() {
+        #[derive(Deserialize)]
+        struct Task {
+            #[serde(with = "crate::datetime::duration")]
+            estimate: Duration,
+            #[serde(deserialize_with = "crate::datetime::duration::deserialize_millis")]
+            timeout: Duration,
+            #[serde(default, with = "crate::datetime::duration::option")]
+            deadline: Option<Duration>,
+        }
+
+        let task: Task =
+            crate::parse(r#"{"estimate": "PT2H", "timeout": 250, "deadline": null}"#).unwrap();
+        assert_eq!(task.estimate, Duration::from_secs(7_200));
+        assert_eq!(task.timeout, Duration::from_millis(250));
+        assert_eq!(task.deadline, None);
+    }
+}
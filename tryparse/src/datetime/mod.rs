@@ -0,0 +1,310 @@
+//! Date, time and duration coercion.
+//!
+//! LLMs write dates every way imaginable: `"March 3rd, 2024"`, `"2024-03-03T10:00Z"`,
+//! `"03/03/2024"`, `"yesterday"` or a unix timestamp. The optional `chrono` and
+//! `time` features implement [`LlmDeserialize`] for those crates' date, time,
+//! datetime and offset types on top of one shared parser, and provide serde
+//! helpers for `#[serde(deserialize_with = "...")]`.
+//!
+//! Canonical ISO 8601 input matches strictly. Anything else is parsed leniently
+//! and records [`Transformation::DateTimeParsed`] naming the format that matched.
+//! Numeric dates like `03/04/2024` are read according to the context's [`DateOrder`].
+//!
+//! [`std::time::Duration`] needs no feature; see [`duration`].
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! use chrono::NaiveDate;
+//! use serde_json::json;
+//! use tryparse::datetime::DateOrder;
+//! use tryparse::deserializer::{CoercionContext, LlmDeserialize};
+//! use tryparse::value::{FlexValue, Source};
+//!
+//! let value = FlexValue::new(json!("03/04/2024"), Source::Direct);
+//! let mut ctx = CoercionContext::new().with_date_order(DateOrder::DayFirst);
+//!
+//! let date = NaiveDate::deserialize(&value, &mut ctx).unwrap();
+//! assert_eq!(date, NaiveDate::from_ymd_opt(2024, 4, 3).unwrap());
+//! # }
+//! ```
+//!
+//! [`LlmDeserialize`]: crate::deserializer::LlmDeserialize
+//! [`Transformation::DateTimeParsed`]: crate::value::Transformation::DateTimeParsed
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+pub(crate) mod civil;
+pub mod duration;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod parse;
+#[cfg(feature = "time")]
+pub mod time;
+
+pub use self::duration::DurationUnit;
+
+/// How to read numeric dates where both numbers could be the month, like `03/04/2024`.
+///
+/// Dates that are unambiguous (`25/12/2024`, `2024-03-04`) are read correctly
+/// under every policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DateOrder {
+    /// US style: `03/04/2024` is March 4th.
+    #[default]
+    MonthFirst,
+    /// Day first, as in most of the world: `03/04/2024` is April 3rd.
+    DayFirst,
+    /// Refuse to guess: ambiguous numeric dates fail to deserialize.
+    Unambiguous,
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) use self::coerce::*;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod coerce {
+    use serde_json::Value;
+
+    use super::{
+        parse::{self, DateOptions, DateTimeParts},
+        DateOrder,
+    };
+    use crate::{
+        deserializer::{primitives::value_type_name, CoercionContext, LlmDeserialize},
+        error::{DeserializeError, ParseError, Result},
+        value::{FlexValue, Source, Transformation},
+    };
+
+    /// Which components a strict (ISO 8601) match must have for a given target type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Shape {
+        Date,
+        Time,
+        LocalDateTime,
+        OffsetDateTime,
+    }
+
+    impl Shape {
+        fn matches(self, parts: &DateTimeParts) -> bool {
+            let has = (
+                parts.date.is_some(),
+                parts.time.is_some(),
+                parts.offset.is_some(),
+            );
+            match self {
+                Self::Date => has == (true, false, false),
+                Self::Time => has == (false, true, false),
+                Self::LocalDateTime => has == (true, true, false),
+                Self::OffsetDateTime => has == (true, true, true),
+            }
+        }
+    }
+
+    /// Strict path: canonical ISO 8601 strings with exactly the components `shape` needs.
+    pub(crate) fn try_coerce<T>(
+        value: &FlexValue,
+        shape: Shape,
+        convert: impl Fn(&DateTimeParts) -> Option<T>,
+    ) -> Option<T> {
+        let parts = parse::parse_iso(value.value.as_str()?)?;
+        if !shape.matches(&parts) {
+            return None;
+        }
+        convert(&parts)
+    }
+
+    /// Lenient path: any supported format, recording which one matched.
+    pub(crate) fn coerce<T>(
+        value: &FlexValue,
+        ctx: &mut CoercionContext,
+        shape: Shape,
+        expected: &'static str,
+        convert: impl Fn(&DateTimeParts) -> Option<T>,
+    ) -> Result<T> {
+        if let Some(result) = try_coerce(value, shape, &convert) {
+            return Ok(result);
+        }
+
+        let options = DateOptions {
+            order: ctx.date_order(),
+            reference_time: ctx.reference_time(),
+        };
+        let parts = parse::parse_datetime(&value.value, &options)?;
+        let result = convert(&parts).ok_or_else(|| {
+            ParseError::DeserializeFailed(DeserializeError::TypeMismatch {
+                expected,
+                found: format!("{} ({})", value.value, parts.format),
+            })
+        })?;
+
+        ctx.add_transformation(Transformation::DateTimeParsed {
+            original: original_text(&value.value),
+            format: parts.format,
+        });
+        Ok(result)
+    }
+
+    /// Strict path for UTC offsets: only `±HH:MM`.
+    pub(crate) fn try_coerce_offset(value: &FlexValue) -> Option<i32> {
+        match parse::parse_offset(value.value.as_str()?)? {
+            (seconds, "±HH:MM") => Some(seconds),
+            _ => None,
+        }
+    }
+
+    /// Lenient path for UTC offsets: `+0530`, `UTC-8`, `Z`, `EST`, ...
+    pub(crate) fn coerce_offset(value: &FlexValue, ctx: &mut CoercionContext) -> Result<i32> {
+        if let Some(seconds) = try_coerce_offset(value) {
+            return Ok(seconds);
+        }
+
+        let (seconds, format) = value
+            .value
+            .as_str()
+            .and_then(parse::parse_offset)
+            .ok_or_else(|| {
+                ParseError::DeserializeFailed(DeserializeError::TypeMismatch {
+                    expected: "UTC offset",
+                    found: match &value.value {
+                        Value::String(s) => format!("string: {}", s),
+                        other => value_type_name(other),
+                    },
+                })
+            })?;
+
+        ctx.add_transformation(Transformation::DateTimeParsed {
+            original: original_text(&value.value),
+            format: format.to_string(),
+        });
+        Ok(seconds)
+    }
+
+    /// Backs the serde helper modules: coerces any JSON value through `T`'s `LlmDeserialize` impl.
+    pub(crate) fn deserialize_with<'de, D, T>(
+        deserializer: D,
+        order: DateOrder,
+    ) -> std::result::Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: LlmDeserialize,
+    {
+        let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+        let mut ctx = CoercionContext::new().with_date_order(order);
+
+        T::deserialize(&FlexValue::new(value, Source::Direct), &mut ctx)
+            .map_err(serde::de::Error::custom)
+    }
+
+    fn original_text(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Implements `LlmDeserialize` for a date/time type given its strict shape and a converter.
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! impl_llm_deserialize {
+    ($ty:ty, $name:literal, $shape:expr, $convert:expr) => {
+        impl $crate::deserializer::LlmDeserialize for $ty {
+            fn try_deserialize(
+                value: &$crate::value::FlexValue,
+                _ctx: &mut $crate::deserializer::CoercionContext,
+            ) -> Option<Self> {
+                $crate::datetime::try_coerce(value, $shape, $convert)
+            }
+
+            fn deserialize(
+                value: &$crate::value::FlexValue,
+                ctx: &mut $crate::deserializer::CoercionContext,
+            ) -> $crate::error::Result<Self> {
+                $crate::datetime::coerce(value, ctx, $shape, $name, $convert)
+            }
+
+            fn type_name() -> &'static str {
+                $name
+            }
+        }
+    };
+}
+
+/// Generates a serde helper module for use with `#[serde(with = "...")]`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! serde_helper {
+    ($(#[$meta:meta])* $module:ident, $ty:ty) => {
+        $(#[$meta])*
+        ///
+        /// `deserialize` reads ambiguous numeric dates month-first and
+        /// `deserialize_day_first` reads them day-first. Use the `option`
+        /// submodule for `Option` fields.
+        pub mod $module {
+            use serde::Deserializer;
+
+            use $crate::datetime::DateOrder;
+
+            /// Deserializes leniently, reading ambiguous numeric dates month-first.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $crate::datetime::deserialize_with(deserializer, DateOrder::MonthFirst)
+            }
+
+            /// Deserializes leniently, reading ambiguous numeric dates day-first.
+            pub fn deserialize_day_first<'de, D>(deserializer: D) -> Result<$ty, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $crate::datetime::deserialize_with(deserializer, DateOrder::DayFirst)
+            }
+
+            /// Same as the parent module, for `Option` fields. `null` becomes `None`.
+            pub mod option {
+                use serde::{Deserialize, Deserializer};
+
+                use $crate::datetime::DateOrder;
+
+                /// Deserializes leniently, reading ambiguous numeric dates month-first.
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<$ty>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserialize_option(deserializer, DateOrder::MonthFirst)
+                }
+
+                /// Deserializes leniently, reading ambiguous numeric dates day-first.
+                pub fn deserialize_day_first<'de, D>(
+                    deserializer: D,
+                ) -> Result<Option<$ty>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserialize_option(deserializer, DateOrder::DayFirst)
+                }
+
+                fn deserialize_option<'de, D>(
+                    deserializer: D,
+                    order: DateOrder,
+                ) -> Result<Option<$ty>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    match Option::<serde_json::Value>::deserialize(deserializer)? {
+                        None | Some(serde_json::Value::Null) => Ok(None),
+                        Some(value) => {
+                            $crate::datetime::deserialize_with(value, order).map(Some).map_err(
+                                <D::Error as serde::de::Error>::custom,
+                            )
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) use {impl_llm_deserialize, serde_helper};
@@ -0,0 +1,783 @@
+//! Library-agnostic date/time parsing.
+//!
+//! Turns the many ways LLMs write dates into plain calendar components, which
+//! the `chrono` and `time` impls then convert into their own types. Every
+//! successful parse reports a human-readable name for the format it matched.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+
+use super::{
+    civil::{civil_date, civil_from_days, days_from_civil, CivilDate},
+    DateOrder,
+};
+use crate::error::{DeserializeError, ParseError, Result};
+
+/// Format name reported for canonical ISO 8601 / RFC 3339 input.
+pub(crate) const ISO_8601: &str = "ISO 8601";
+
+/// A wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct CivilTime {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub nanosecond: u32,
+}
+
+/// Components recovered from a date/time value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DateTimeParts {
+    pub date: Option<CivilDate>,
+    pub time: Option<CivilTime>,
+    /// Offset from UTC in seconds, if the input named one.
+    pub offset: Option<i32>,
+    /// Name of the format that matched, e.g. `"MM/DD/YYYY"`.
+    pub format: String,
+}
+
+/// Settings that affect how ambiguous input is read.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DateOptions {
+    pub order: DateOrder,
+    /// "Now" as unix seconds, used for relative words and missing years.
+    pub reference_time: i64,
+}
+
+static ISO_DATETIME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(\d{4})-(\d{2})-(\d{2})(?:[t ](\d{2}):(\d{2})(?::(\d{2})(?:[.,](\d{1,9}))?)?)?\s*(z|[+-]\d{2}(?::?\d{2})?)?$",
+    )
+    .expect("Invalid ISO datetime regex pattern")
+});
+
+static ISO_TIME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(\d{2}):(\d{2})(?::(\d{2})(?:[.,](\d{1,9}))?)?\s*(z|[+-]\d{2}(?::?\d{2})?)?$")
+        .expect("Invalid ISO time regex pattern")
+});
+
+static NUMERIC_DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{1,4})([/.\-])(\d{1,2})[/.\-](\d{1,4})$").expect("Invalid numeric date regex")
+});
+
+static CLOCK_TIME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d{1,2}):(\d{2})(?::(\d{2})(?:[.,](\d{1,9}))?)?([ap]\.?m\.?)?(z)?$")
+        .expect("Invalid clock time regex")
+});
+
+static HOUR_MERIDIEM: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d{1,2})([ap]\.?m\.?)$").expect("Invalid hour regex"));
+
+static NUMERIC_OFFSET: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:utc|gmt)?([+-])(\d{1,2})(?::?(\d{2}))?$").expect("Invalid offset regex")
+});
+
+static DAY_NUMBER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d{1,4})(?:st|nd|rd|th)?$").expect("Invalid day number regex"));
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Words that carry no date information ("Sunday, the 3rd of March at 10am").
+const FILLER_WORDS: [&str; 4] = ["at", "on", "of", "the"];
+
+/// Common timezone abbreviations and their UTC offsets in hours.
+const ZONE_ABBREVIATIONS: [(&str, i32); 12] = [
+    ("est", -5),
+    ("edt", -4),
+    ("cst", -6),
+    ("cdt", -5),
+    ("mst", -7),
+    ("mdt", -6),
+    ("pst", -8),
+    ("pdt", -7),
+    ("bst", 1),
+    ("cet", 1),
+    ("cest", 2),
+    ("jst", 9),
+];
+
+/// Strictly parses canonical ISO 8601 input (`2024-03-03`, `2024-03-03T10:00:00Z`, `10:00:00`).
+pub(crate) fn parse_iso(s: &str) -> Option<DateTimeParts> {
+    if let Some(caps) = ISO_DATETIME.captures(s) {
+        let date = civil_date(
+            caps[1].parse().ok()?,
+            caps[2].parse().ok()?,
+            caps[3].parse().ok()?,
+        )?;
+        let time = match caps.get(4) {
+            Some(hour) => Some(civil_time(
+                hour.as_str().parse().ok()?,
+                caps[5].parse().ok()?,
+                caps.get(6).map_or(Some(0), |m| m.as_str().parse().ok())?,
+                caps.get(7)
+                    .map_or(Some(0), |m| parse_fraction(m.as_str()))?,
+            )?),
+            None => None,
+        };
+        let offset = match caps.get(8) {
+            Some(m) => Some(parse_offset_token(m.as_str())?),
+            None => None,
+        };
+
+        return Some(DateTimeParts {
+            date: Some(date),
+            time,
+            offset,
+            format: ISO_8601.to_string(),
+        });
+    }
+
+    let caps = ISO_TIME.captures(s)?;
+    let time = civil_time(
+        caps[1].parse().ok()?,
+        caps[2].parse().ok()?,
+        caps.get(3).map_or(Some(0), |m| m.as_str().parse().ok())?,
+        caps.get(4)
+            .map_or(Some(0), |m| parse_fraction(m.as_str()))?,
+    )?;
+    let offset = match caps.get(5) {
+        Some(m) => Some(parse_offset_token(m.as_str())?),
+        None => None,
+    };
+
+    Some(DateTimeParts {
+        date: None,
+        time: Some(time),
+        offset,
+        format: ISO_8601.to_string(),
+    })
+}
+
+/// Leniently parses any supported date/time representation.
+///
+/// Tries, in order: ISO 8601, unix timestamps, relative words ("yesterday"),
+/// then free-form dates with month names, numeric dates and clock times.
+pub(crate) fn parse_datetime(value: &Value, options: &DateOptions) -> Result<DateTimeParts> {
+    match value {
+        Value::Number(n) => {
+            let secs = n
+                .as_f64()
+                .ok_or_else(|| invalid(format!("invalid timestamp {}", n)))?;
+            from_timestamp(secs).ok_or_else(|| invalid(format!("timestamp {} out of range", n)))
+        }
+        Value::String(s) => parse_datetime_str(s, options),
+        other => Err(ParseError::DeserializeFailed(
+            DeserializeError::type_mismatch(
+                "date/time",
+                crate::deserializer::primitives::value_type_name(other),
+            ),
+        )),
+    }
+}
+
+fn parse_datetime_str(s: &str, options: &DateOptions) -> Result<DateTimeParts> {
+    let trimmed = s.trim().trim_end_matches('.');
+    let not_a_date = || invalid(format!("unrecognized date/time '{}'", s));
+
+    if let Some(parts) = parse_iso(trimmed) {
+        return Ok(parts);
+    }
+
+    // Unix timestamps written as strings
+    let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+    if (9..=13).contains(&digits.len()) && digits.bytes().all(|b| b.is_ascii_digit()) {
+        let secs: f64 = trimmed.parse().map_err(|_| not_a_date())?;
+        return from_timestamp(secs).ok_or_else(not_a_date);
+    }
+
+    let lower = trimmed.to_lowercase();
+    if let Some(parts) = parse_relative(&lower, options.reference_time) {
+        return Ok(parts);
+    }
+
+    parse_free_form(&lower, options)?.ok_or_else(not_a_date)
+}
+
+/// Parses a UTC offset on its own: `+05:30`, `-0800`, `UTC+2`, `Z`, `EST`.
+///
+/// Returns the offset in seconds and the name of the matched format.
+pub(crate) fn parse_offset(s: &str) -> Option<(i32, &'static str)> {
+    let lower = s.trim().to_lowercase();
+    let compact: String = lower.chars().filter(|c| !c.is_whitespace()).collect();
+
+    if compact.len() == 6
+        && (compact.starts_with('+') || compact.starts_with('-'))
+        && compact.as_bytes()[3] == b':'
+    {
+        return parse_offset_token(&compact).map(|o| (o, "±HH:MM"));
+    }
+    if let Some(&(_, hours)) = ZONE_ABBREVIATIONS.iter().find(|(name, _)| *name == compact) {
+        return Some((hours * 3600, "timezone abbreviation"));
+    }
+    parse_offset_token(&compact).map(|o| (o, "UTC offset"))
+}
+
+/// Parses `z`, `utc`, `gmt`, `+05`, `+0530`, `+05:30` and `utc+5` into seconds east of UTC.
+fn parse_offset_token(token: &str) -> Option<i32> {
+    let token = token.to_ascii_lowercase();
+    if matches!(token.as_str(), "z" | "utc" | "gmt") {
+        return Some(0);
+    }
+
+    let caps = NUMERIC_OFFSET.captures(&token)?;
+    let hours: i32 = caps[2].parse().ok()?;
+    let minutes: i32 = caps.get(3).map_or(Some(0), |m| m.as_str().parse().ok())?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    let sign = if &caps[1] == "-" { -1 } else { 1 };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Converts unix seconds (or milliseconds, for values too large to be seconds) into UTC parts.
+fn from_timestamp(value: f64) -> Option<DateTimeParts> {
+    if !value.is_finite() {
+        return None;
+    }
+
+    // Seconds past 1e11 would be beyond the year 5000 - treat them as milliseconds
+    let (secs, format) = if value.abs() >= 1e11 {
+        (value / 1000.0, "unix timestamp (milliseconds)")
+    } else {
+        (value, "unix timestamp (seconds)")
+    };
+
+    let whole = secs.floor();
+    let nanosecond = ((secs - whole) * 1e9).round().min(999_999_999.0) as u32;
+    let whole = whole as i64;
+
+    let (date, mut time) = split_unix_seconds(whole)?;
+    time.nanosecond = nanosecond;
+
+    Some(DateTimeParts {
+        date: Some(date),
+        time: Some(time),
+        offset: Some(0),
+        format: format.to_string(),
+    })
+}
+
+fn parse_relative(lower: &str, reference_time: i64) -> Option<DateTimeParts> {
+    let day_offset = match lower {
+        "now" | "right now" => {
+            let mut parts = from_timestamp(reference_time as f64)?;
+            parts.format = "relative (now)".to_string();
+            return Some(parts);
+        }
+        "today" => 0,
+        "yesterday" => -1,
+        "tomorrow" => 1,
+        "day before yesterday" | "the day before yesterday" => -2,
+        "day after tomorrow" | "the day after tomorrow" => 2,
+        _ => return None,
+    };
+
+    let (today, _) = split_unix_seconds(reference_time)?;
+    let days = days_from_civil(today) + day_offset;
+
+    Some(DateTimeParts {
+        date: Some(civil_from_days(days)?),
+        time: None,
+        offset: None,
+        format: format!("relative ({})", lower.trim_start_matches("the ")),
+    })
+}
+
+/// Token-based parser for things like "March 3rd, 2024 at 10am EST" or "03/03/2024 14:30".
+///
+/// Returns `Ok(None)` if the input isn't recognizably a date, and an error for
+/// dates that are recognized but invalid or ambiguous under the configured policy.
+fn parse_free_form(lower: &str, options: &DateOptions) -> Result<Option<DateTimeParts>> {
+    let cleaned = lower.replace(',', " ");
+
+    let mut date: Option<CivilDate> = None;
+    let mut date_format: Option<String> = None;
+    let mut time: Option<CivilTime> = None;
+    let mut time_format = "";
+    let mut offset: Option<i32> = None;
+    let mut offset_format = String::new();
+    let mut month: Option<(u32, usize)> = None;
+    let mut numbers: Vec<(u32, usize, usize)> = Vec::new(); // (value, digit count, token index)
+
+    for (index, token) in cleaned.split_whitespace().enumerate() {
+        let word = token.trim_end_matches('.');
+
+        if FILLER_WORDS.contains(&word) || is_weekday(word) {
+            continue;
+        }
+
+        if let Some(meridiem) = parse_meridiem(token) {
+            if !time.as_mut().is_some_and(|t| apply_meridiem(t, meridiem)) {
+                return Ok(None);
+            }
+            time_format = "h:MM am/pm";
+            continue;
+        }
+
+        if let Some(caps) = CLOCK_TIME.captures(token) {
+            let mut parsed = match civil_time(
+                caps[1].parse().unwrap_or(99),
+                caps[2].parse().unwrap_or(99),
+                caps.get(3).map_or(0, |m| m.as_str().parse().unwrap_or(99)),
+                caps.get(4)
+                    .map_or(Some(0), |m| parse_fraction(m.as_str()))
+                    .unwrap_or(0),
+            ) {
+                Some(t) => t,
+                None => return Ok(None),
+            };
+            time_format = if caps.get(3).is_some() {
+                "HH:MM:SS"
+            } else {
+                "HH:MM"
+            };
+            if let Some(m) = caps.get(5) {
+                if !apply_meridiem(&mut parsed, m.as_str().starts_with('p')) {
+                    return Ok(None);
+                }
+                time_format = "h:MM am/pm";
+            }
+            if caps.get(6).is_some() {
+                offset = Some(0);
+                offset_format = "Z".to_string();
+            }
+            time = Some(parsed);
+            continue;
+        }
+
+        if let Some(caps) = HOUR_MERIDIEM.captures(token) {
+            let mut parsed = match civil_time(caps[1].parse().unwrap_or(99), 0, 0, 0) {
+                Some(t) => t,
+                None => return Ok(None),
+            };
+            if !apply_meridiem(&mut parsed, caps[2].starts_with('p')) {
+                return Ok(None);
+            }
+            time = Some(parsed);
+            time_format = "h am/pm";
+            continue;
+        }
+
+        if let Some(o) = parse_offset_token(token) {
+            offset = Some(o);
+            offset_format = token.to_uppercase();
+            continue;
+        }
+        if let Some(&(name, hours)) = ZONE_ABBREVIATIONS.iter().find(|(name, _)| *name == word) {
+            offset = Some(hours * 3600);
+            offset_format = name.to_uppercase();
+            continue;
+        }
+
+        if let Some(caps) = NUMERIC_DATE.captures(token) {
+            if date.is_some() {
+                return Ok(None);
+            }
+            let (parsed, format) =
+                resolve_numeric_date(&caps[1], &caps[2], &caps[3], &caps[4], options.order)?;
+            date = Some(parsed);
+            date_format = Some(format);
+            continue;
+        }
+
+        if let Some(m) = month_from_name(word) {
+            if month.is_some() {
+                return Ok(None);
+            }
+            month = Some((m, index));
+            continue;
+        }
+
+        if let Some(caps) = DAY_NUMBER.captures(token) {
+            numbers.push((caps[1].parse().unwrap_or(0), caps[1].len(), index));
+            continue;
+        }
+
+        // Unknown word - this isn't a date we understand
+        return Ok(None);
+    }
+
+    if let Some((month, month_index)) = month {
+        if date.is_some() {
+            return Ok(None);
+        }
+        let (parsed, format) = resolve_named_month(month, month_index, &numbers, options)?;
+        date = Some(parsed);
+        date_format = Some(format);
+    } else if !numbers.is_empty() {
+        // Bare numbers without a month name aren't a date
+        return Ok(None);
+    }
+
+    if date.is_none() && time.is_none() {
+        return Ok(None);
+    }
+
+    let mut format: Vec<String> = Vec::new();
+    format.extend(date_format);
+    if time.is_some() {
+        format.push(time_format.to_string());
+    }
+    if offset.is_some() {
+        format.push(offset_format);
+    }
+
+    Ok(Some(DateTimeParts {
+        date,
+        time,
+        offset,
+        format: format.join(" "),
+    }))
+}
+
+/// Resolves `a/b/c` style dates using the configured day/month order.
+fn resolve_numeric_date(
+    a: &str,
+    separator: &str,
+    b: &str,
+    c: &str,
+    order: DateOrder,
+) -> Result<(CivilDate, String)> {
+    let sep = separator;
+    let n = |s: &str| s.parse::<u32>().unwrap_or(0);
+
+    let (year, month, day, format) = if a.len() == 4 {
+        (n(a) as i32, n(b), n(c), format!("YYYY{sep}MM{sep}DD"))
+    } else {
+        let year = expand_year(c);
+        let (first, second) = (n(a), n(b));
+        let day_first = if first > 12 && second <= 12 {
+            true
+        } else if (second > 12 && first <= 12) || first == second {
+            false
+        } else {
+            match order {
+                DateOrder::MonthFirst => false,
+                DateOrder::DayFirst => true,
+                DateOrder::Unambiguous => {
+                    return Err(invalid(format!(
+                        "ambiguous date '{a}{sep}{b}{sep}{c}': day and month could be swapped"
+                    )))
+                }
+            }
+        };
+
+        if day_first {
+            (year, second, first, format!("DD{sep}MM{sep}YYYY"))
+        } else {
+            (year, first, second, format!("MM{sep}DD{sep}YYYY"))
+        }
+    };
+
+    let date = civil_date(year, month, day)
+        .ok_or_else(|| invalid(format!("invalid date '{a}{sep}{b}{sep}{c}'")))?;
+    Ok((date, format))
+}
+
+/// Resolves "March 3rd, 2024" / "3 March 2024" / "March 3" given the month and the numbers around it.
+fn resolve_named_month(
+    month: u32,
+    month_index: usize,
+    numbers: &[(u32, usize, usize)],
+    options: &DateOptions,
+) -> Result<(CivilDate, String)> {
+    let (day, year) = match numbers {
+        [(day, day_len, _)] if *day_len <= 2 => (*day, None),
+        [(first, first_len, _), (second, second_len, _)] => {
+            if *first_len <= 2 && (*second_len == 4 || *second > 31) {
+                (*first, Some(*second as i32))
+            } else if *second_len <= 2 && (*first_len == 4 || *first > 31) {
+                (*second, Some(*first as i32))
+            } else {
+                return Err(invalid("could not tell day from year"));
+            }
+        }
+        _ => {
+            return Err(invalid(
+                "expected a day and optional year next to the month name",
+            ))
+        }
+    };
+
+    let day_index = numbers
+        .iter()
+        .find(|(value, _, _)| *value == day)
+        .map_or(0, |(_, _, index)| *index);
+    let mut format = if month_index < day_index {
+        "Month D, YYYY".to_string()
+    } else {
+        "D Month YYYY".to_string()
+    };
+
+    let year = match year {
+        Some(year) => year,
+        None => {
+            format = format.replace(", YYYY", "").replace(" YYYY", "") + " (current year)";
+            split_unix_seconds(options.reference_time).map_or(1970, |(d, _)| d.year)
+        }
+    };
+
+    let date = civil_date(year, month, day).ok_or_else(|| {
+        invalid(format!(
+            "invalid date: day {} of month {} in {}",
+            day, month, year
+        ))
+    })?;
+    Ok((date, format))
+}
+
+fn month_from_name(word: &str) -> Option<u32> {
+    if word == "sept" {
+        return Some(9);
+    }
+    MONTHS
+        .iter()
+        .position(|m| *m == word || (word.len() == 3 && m.starts_with(word)))
+        .map(|i| i as u32 + 1)
+}
+
+fn is_weekday(word: &str) -> bool {
+    WEEKDAYS
+        .iter()
+        .any(|d| *d == word || (word.len() >= 3 && d.starts_with(word)))
+}
+
+/// Returns `Some(true)` for pm, `Some(false)` for am.
+fn parse_meridiem(token: &str) -> Option<bool> {
+    match token {
+        "am" | "a.m." | "a.m" => Some(false),
+        "pm" | "p.m." | "p.m" => Some(true),
+        _ => None,
+    }
+}
+
+/// Converts a 12-hour clock reading to 24-hour time. Returns false if the hour isn't 1-12.
+fn apply_meridiem(time: &mut CivilTime, pm: bool) -> bool {
+    if time.hour == 0 || time.hour > 12 {
+        return false;
+    }
+    time.hour = match (time.hour, pm) {
+        (12, false) => 0,
+        (12, true) => 12,
+        (h, true) => h + 12,
+        (h, false) => h,
+    };
+    true
+}
+
+/// Expands two-digit years: 00-69 → 2000s, 70-99 → 1900s.
+fn expand_year(s: &str) -> i32 {
+    let year: i32 = s.parse().unwrap_or(0);
+    match s.len() {
+        1 | 2 if year < 70 => 2000 + year,
+        1 | 2 => 1900 + year,
+        _ => year,
+    }
+}
+
+/// Parses a fractional-seconds digit string into nanoseconds.
+fn parse_fraction(digits: &str) -> Option<u32> {
+    let padded = format!("{:0<9}", digits);
+    padded.get(..9)?.parse().ok()
+}
+
+fn civil_time(hour: u32, minute: u32, second: u32, nanosecond: u32) -> Option<CivilTime> {
+    if hour > 23 || minute > 59 || second > 59 || nanosecond > 999_999_999 {
+        return None;
+    }
+    Some(CivilTime {
+        hour,
+        minute,
+        second,
+        nanosecond,
+    })
+}
+
+fn split_unix_seconds(secs: i64) -> Option<(CivilDate, CivilTime)> {
+    let days = secs.div_euclid(86_400);
+    let rem = secs.rem_euclid(86_400) as u32;
+
+    // Keep the year comfortably inside i32
+    if days.abs() > 365 * 1_000_000 {
+        return None;
+    }
+    let date = civil_from_days(days)?;
+
+    Some((
+        date,
+        CivilTime {
+            hour: rem / 3600,
+            minute: rem % 3600 / 60,
+            second: rem % 60,
+            nanosecond: 0,
+        },
+    ))
+}
+
+fn invalid(message: impl Into<String>) -> ParseError {
+    ParseError::DeserializeFailed(DeserializeError::invalid_value(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// 2024-03-10T12:00:00Z
+    const REFERENCE: i64 = 1_710_072_000;
+
+    fn options(order: DateOrder) -> DateOptions {
+        DateOptions {
+            order,
+            reference_time: REFERENCE,
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> Option<CivilDate> {
+        Some(CivilDate { year, month, day })
+    }
+
+    fn parse(input: &str) -> DateTimeParts {
+        parse_datetime(&json!(input), &options(DateOrder::MonthFirst)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_iso() {
+        let parts = parse_iso("2024-03-03T10:00:30.5+05:30").unwrap();
+        assert_eq!(parts.date, date(2024, 3, 3));
+        assert_eq!(
+            parts.time,
+            Some(CivilTime {
+                hour: 10,
+                minute: 0,
+                second: 30,
+                nanosecond: 500_000_000
+            })
+        );
+        assert_eq!(parts.offset, Some(19_800));
+        assert_eq!(parts.format, ISO_8601);
+
+        assert_eq!(parse_iso("2024-03-03T10:00Z").unwrap().offset, Some(0));
+        assert!(parse_iso("2024-02-30").is_none());
+        assert!(parse_iso("March 3, 2024").is_none());
+    }
+
+    #[test]
+    fn test_month_names() {
+        for (input, format) in [
+            ("March 3rd, 2024", "Month D, YYYY"),
+            ("Sunday, March 3, 2024", "Month D, YYYY"),
+            ("3 March 2024", "D Month YYYY"),
+            ("the 3rd of Mar 2024", "D Month YYYY"),
+        ] {
+            let parts = parse(input);
+            assert_eq!(parts.date, date(2024, 3, 3), "input: {}", input);
+            assert_eq!(parts.format, format, "input: {}", input);
+        }
+
+        let parts = parse("March 3");
+        assert_eq!(parts.date, date(2024, 3, 3));
+        assert_eq!(parts.format, "Month D (current year)");
+    }
+
+    #[test]
+    fn test_numeric_dates_and_order() {
+        let parts = parse("03/04/2024");
+        assert_eq!(parts.date, date(2024, 3, 4));
+        assert_eq!(parts.format, "MM/DD/YYYY");
+
+        let day_first =
+            parse_datetime(&json!("03/04/2024"), &options(DateOrder::DayFirst)).unwrap();
+        assert_eq!(day_first.date, date(2024, 4, 3));
+        assert_eq!(day_first.format, "DD/MM/YYYY");
+
+        // Unambiguous regardless of policy
+        assert_eq!(parse("25.12.2024").date, date(2024, 12, 25));
+        assert_eq!(parse("2024/03/04").date, date(2024, 3, 4));
+
+        assert!(parse_datetime(&json!("03/04/24"), &options(DateOrder::Unambiguous)).is_err());
+        assert_eq!(
+            parse_datetime(&json!("13/04/24"), &options(DateOrder::Unambiguous))
+                .unwrap()
+                .date,
+            date(2024, 4, 13)
+        );
+    }
+
+    #[test]
+    fn test_times_and_offsets() {
+        let parts = parse("March 3rd, 2024 at 3:15 PM EST");
+        assert_eq!(parts.date, date(2024, 3, 3));
+        assert_eq!(parts.time.unwrap().hour, 15);
+        assert_eq!(parts.offset, Some(-5 * 3600));
+        assert_eq!(parts.format, "Month D, YYYY h:MM am/pm EST");
+
+        let parts = parse("Sun, 03 Mar 2024 10:00:00 +0000");
+        assert_eq!(parts.time.unwrap().hour, 10);
+        assert_eq!(parts.offset, Some(0));
+
+        let parts = parse("10am");
+        assert_eq!(parts.date, None);
+        assert_eq!(parts.time.unwrap().hour, 10);
+
+        assert_eq!(parse_offset("+05:30"), Some((19_800, "±HH:MM")));
+        assert_eq!(parse_offset("UTC-8"), Some((-28_800, "UTC offset")));
+        assert_eq!(
+            parse_offset("PST"),
+            Some((-28_800, "timezone abbreviation"))
+        );
+    }
+
+    #[test]
+    fn test_relative_and_timestamps() {
+        let parts = parse("yesterday");
+        assert_eq!(parts.date, date(2024, 3, 9));
+        assert_eq!(parts.format, "relative (yesterday)");
+
+        let parts = parse_datetime(&json!(REFERENCE), &options(DateOrder::MonthFirst)).unwrap();
+        assert_eq!(parts.date, date(2024, 3, 10));
+        assert_eq!(parts.time.unwrap().hour, 12);
+        assert_eq!(parts.format, "unix timestamp (seconds)");
+
+        let parts = parse("1710072000000");
+        assert_eq!(parts.date, date(2024, 3, 10));
+        assert_eq!(parts.format, "unix timestamp (milliseconds)");
+    }
+
+    #[test]
+    fn test_rejects_non_dates() {
+        for input in ["hello world", "about 5", "March 32, 2024", "13:99", ""] {
+            assert!(
+                parse_datetime(&json!(input), &options(DateOrder::MonthFirst)).is_err(),
+                "input: {}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! `LlmDeserialize` impls and serde helpers for `time` types.
+//!
+//! Supported: [`Date`], [`Time`], [`PrimitiveDateTime`], [`OffsetDateTime`] and
+//! [`UtcOffset`].
+//!
+//! Missing components are filled in conservatively: a date without a time is
+//! midnight, and a datetime without an offset is taken as UTC.
+
+use ::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use super::{impl_llm_deserialize, parse::DateTimeParts, serde_helper, Shape};
+use crate::{
+    deserializer::{CoercionContext, LlmDeserialize},
+    error::{DeserializeError, ParseError, Result},
+    value::FlexValue,
+};
+
+fn to_date(parts: &DateTimeParts) -> Option<Date> {
+    let date = parts.date?;
+    let month = Month::try_from(u8::try_from(date.month).ok()?).ok()?;
+    Date::from_calendar_date(date.year, month, u8::try_from(date.day).ok()?).ok()
+}
+
+fn to_time(parts: &DateTimeParts) -> Option<Time> {
+    let time = parts.time?;
+    Time::from_hms_nano(
+        u8::try_from(time.hour).ok()?,
+        u8::try_from(time.minute).ok()?,
+        u8::try_from(time.second).ok()?,
+        time.nanosecond,
+    )
+    .ok()
+}
+
+fn to_primitive_datetime(parts: &DateTimeParts) -> Option<PrimitiveDateTime> {
+    let time = match parts.time {
+        Some(_) => to_time(parts)?,
+        None => Time::MIDNIGHT,
+    };
+    Some(PrimitiveDateTime::new(to_date(parts)?, time))
+}
+
+fn to_offset_datetime(parts: &DateTimeParts) -> Option<OffsetDateTime> {
+    let offset = UtcOffset::from_whole_seconds(parts.offset.unwrap_or(0)).ok()?;
+    Some(to_primitive_datetime(parts)?.assume_offset(offset))
+}
+
+impl_llm_deserialize!(Date, "Date", Shape::Date, to_date);
+impl_llm_deserialize!(Time, "Time", Shape::Time, to_time);
+impl_llm_deserialize!(
+    PrimitiveDateTime,
+    "PrimitiveDateTime",
+    Shape::LocalDateTime,
+    to_primitive_datetime
+);
+impl_llm_deserialize!(
+    OffsetDateTime,
+    "OffsetDateTime",
+    Shape::OffsetDateTime,
+    to_offset_datetime
+);
+
+impl LlmDeserialize for UtcOffset {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        UtcOffset::from_whole_seconds(super::try_coerce_offset(value)?).ok()
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        let seconds = super::coerce_offset(value, ctx)?;
+        UtcOffset::from_whole_seconds(seconds).map_err(|e| {
+            ParseError::DeserializeFailed(DeserializeError::invalid_value(e.to_string()))
+        })
+    }
+
+    fn type_name() -> &'static str {
+        "UtcOffset"
+    }
+}
+
+serde_helper!(
+    /// Serde helper for [`Date`](::time::Date).
+    date,
+    ::time::Date
+);
+serde_helper!(
+    /// Serde helper for [`Time`](::time::Time).
+    time,
+    ::time::Time
+);
+serde_helper!(
+    /// Serde helper for [`PrimitiveDateTime`](::time::PrimitiveDateTime).
+    primitive_datetime,
+    ::time::PrimitiveDateTime
+);
+serde_helper!(
+    /// Serde helper for [`OffsetDateTime`](::time::OffsetDateTime).
+    offset_datetime,
+    ::time::OffsetDateTime
+);
+serde_helper!(
+    /// Serde helper for [`UtcOffset`](::time::UtcOffset).
+    utc_offset,
+    ::time::UtcOffset
+);
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::value::{Source, Transformation};
+
+    /// 2024-03-10T12:00:00Z
+    const REFERENCE: i64 = 1_710_072_000;
+
+    fn flex(value: serde_json::Value) -> FlexValue {
+        FlexValue::new(value, Source::Direct)
+    }
+
+    fn ctx() -> CoercionContext {
+        CoercionContext::new().with_reference_time(REFERENCE)
+    }
+
+    #[test]
+    fn test_date() {
+        let expected = Date::from_calendar_date(2024, Month::March, 3).unwrap();
+        let mut ctx = ctx();
+
+        assert_eq!(
+            Date::try_deserialize(&flex(json!("2024-03-03")), &mut ctx),
+            Some(expected)
+        );
+
+        let date = Date::deserialize(&flex(json!("Sun, 3 Mar 2024")), &mut ctx).unwrap();
+        assert_eq!(date, expected);
+        assert!(matches!(
+            &ctx.transformations()[0],
+            Transformation::DateTimeParsed { format, .. } if format == "D Month YYYY"
+        ));
+    }
+
+    #[test]
+    fn test_offset_datetime() {
+        let mut ctx = ctx();
+
+        let dt = OffsetDateTime::deserialize(&flex(json!("2024-03-03T10:00:00+02:00")), &mut ctx)
+            .unwrap();
+        assert_eq!(dt.offset(), UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert!(ctx.transformations().is_empty());
+
+        let dt = OffsetDateTime::deserialize(&flex(json!("1710072000")), &mut ctx).unwrap();
+        assert_eq!(dt.unix_timestamp(), REFERENCE);
+        assert_eq!(ctx.transformations().len(), 1);
+    }
+
+    #[test]
+    fn test_time_and_offset() {
+        let mut ctx = ctx();
+
+        let time = Time::deserialize(&flex(json!("3:15 p.m.")), &mut ctx).unwrap();
+        assert_eq!(time, Time::from_hms(15, 15, 0).unwrap());
+
+        let offset = UtcOffset::deserialize(&flex(json!("GMT+1")), &mut ctx).unwrap();
+        assert_eq!(offset.whole_seconds(), 3600);
+    }
+
+    #[test]
+    fn test_serde_helper() {
+        #[derive(Deserialize)]
+        struct Event {
+            #[serde(with = "primitive_datetime")]
+            at: PrimitiveDateTime,
+        }
+
+        let event: Event = crate::parse(r#"{"at": "March 3rd, 2024 at 9:30am"}"#).unwrap();
+        assert_eq!(event.at.hour(), 9);
+        assert_eq!(event.at.minute(), 30);
+    }
+}
@@ -220,7 +220,7 @@ impl EnumMatcher {
         // If we found matches where variants appear in input, use those
         if !all_matches.is_empty() {
             // Sort by length (longest first) to prefer exact matches
-            all_matches.sort_by(|a, b| b.2.cmp(&a.2));
+            all_matches.sort_by_key(|m| std::cmp::Reverse(m.2));
 
             // Return the variant with the longest match
             return Some(all_matches[0].3);
@@ -240,7 +240,7 @@ impl EnumMatcher {
 
         if !reverse_matches.is_empty() {
             // Sort by match string length (shortest first) to prefer closest match
-            reverse_matches.sort_by(|a, b| a.1.cmp(&b.1));
+            reverse_matches.sort_by_key(|m| m.1);
             return Some(reverse_matches[0].0);
         }
 
@@ -309,8 +309,8 @@ pub fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     for (i, row) in matrix.iter_mut().enumerate().take(len1 + 1) {
         row[0] = i;
     }
-    for j in 0..=len2 {
-        matrix[0][j] = j;
+    for (j, cell) in matrix[0].iter_mut().enumerate().take(len2 + 1) {
+        *cell = j;
     }
 
     let s1_chars: Vec<char> = s1.chars().collect();
@@ -0,0 +1,501 @@
+//! `LlmDeserialize` impls for types from other crates.
+//!
+//! `serde_json` types are always available. Everything else sits behind a
+//! cargo feature named after the crate:
+//! - `uuid`: `uuid::Uuid` (braced, URN and simple forms)
+//! - `url`: `url::Url` (adds `https://` to bare hosts, `host:port` and `user@host`)
+//! - `rust_decimal`: `rust_decimal::Decimal` (currency symbols, thousands separators)
+//! - `indexmap`: `indexmap::IndexMap` (same coercions as `HashMap`)
+
+use serde_json::{Map, Value};
+
+#[cfg(any(feature = "uuid", feature = "url", feature = "rust_decimal"))]
+use crate::value::Transformation;
+use crate::{
+    deserializer::{
+        primitives::value_type_name,
+        traits::{CoercionContext, LlmDeserialize},
+    },
+    error::{DeserializeError, ParseError, Result},
+    value::FlexValue,
+};
+
+// ================================================================================================
+// serde_json::Value / serde_json::Map
+// ================================================================================================
+
+impl LlmDeserialize for Value {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        Some(value.value.clone())
+    }
+
+    fn deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Result<Self> {
+        Ok(value.value.clone())
+    }
+
+    fn type_name() -> &'static str {
+        "Value"
+    }
+}
+
+impl LlmDeserialize for Map<String, Value> {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        value.value.as_object().cloned()
+    }
+
+    fn deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Result<Self> {
+        value.value.as_object().cloned().ok_or_else(|| {
+            ParseError::DeserializeFailed(DeserializeError::TypeMismatch {
+                expected: "object",
+                found: value_type_name(&value.value),
+            })
+        })
+    }
+
+    fn type_name() -> &'static str {
+        "Map<String, Value>"
+    }
+}
+
+/// Strips whitespace and wrapping quotes/angle brackets LLMs like to add around identifiers.
+#[cfg(any(feature = "uuid", feature = "url"))]
+fn trim_wrapping(s: &str) -> &str {
+    s.trim()
+        .trim_matches(|c| c == '"' || c == '\'' || c == '`')
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .trim()
+}
+
+// ================================================================================================
+// uuid::Uuid
+// ================================================================================================
+
+#[cfg(feature = "uuid")]
+impl LlmDeserialize for uuid::Uuid {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        // Strict: only the canonical hyphenated form
+        match &value.value {
+            Value::String(s) if s.len() == 36 => uuid::Uuid::try_parse(s).ok(),
+            _ => None,
+        }
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        if let Some(id) = Self::try_deserialize(value, ctx) {
+            return Ok(id);
+        }
+
+        match &value.value {
+            Value::String(s) => {
+                // Accepts braced "{...}", "urn:uuid:..." and simple (no hyphens) forms
+                let id = uuid::Uuid::try_parse(trim_wrapping(s)).map_err(|e| {
+                    ParseError::DeserializeFailed(DeserializeError::invalid_value(format!(
+                        "invalid uuid '{}': {}",
+                        s, e
+                    )))
+                })?;
+
+                ctx.add_transformation(Transformation::StringNormalized {
+                    original: s.clone(),
+                    normalized: id.to_string(),
+                });
+                Ok(id)
+            }
+            _ => Err(ParseError::DeserializeFailed(
+                DeserializeError::TypeMismatch {
+                    expected: "uuid",
+                    found: value_type_name(&value.value),
+                },
+            )),
+        }
+    }
+
+    fn type_name() -> &'static str {
+        "Uuid"
+    }
+}
+
+// ================================================================================================
+// url::Url
+// ================================================================================================
+
+#[cfg(feature = "url")]
+impl LlmDeserialize for url::Url {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        match &value.value {
+            Value::String(s) if !is_schemeless_authority(s) => url::Url::parse(s).ok(),
+            _ => None,
+        }
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        if let Some(url) = Self::try_deserialize(value, ctx) {
+            return Ok(url);
+        }
+
+        match &value.value {
+            Value::String(s) => {
+                let trimmed = trim_wrapping(s);
+
+                let with_https = || url::Url::parse(&format!("https://{}", trimmed));
+                let parsed = if is_schemeless_authority(trimmed) {
+                    // "localhost:8080" would parse with "localhost" as the scheme
+                    with_https()
+                } else {
+                    match url::Url::parse(trimmed) {
+                        // "example.com/path" - assume https for things that look like a host
+                        Err(url::ParseError::RelativeUrlWithoutBase)
+                            if looks_like_host(trimmed) =>
+                        {
+                            with_https()
+                        }
+                        other => other,
+                    }
+                };
+                let url = parsed.map_err(|e| {
+                    ParseError::DeserializeFailed(DeserializeError::invalid_value(format!(
+                        "invalid url '{}': {}",
+                        s, e
+                    )))
+                })?;
+
+                ctx.add_transformation(Transformation::StringNormalized {
+                    original: s.clone(),
+                    normalized: url.to_string(),
+                });
+                Ok(url)
+            }
+            _ => Err(ParseError::DeserializeFailed(
+                DeserializeError::TypeMismatch {
+                    expected: "url",
+                    found: value_type_name(&value.value),
+                },
+            )),
+        }
+    }
+
+    fn type_name() -> &'static str {
+        "Url"
+    }
+}
+
+/// Returns true if `s` starts with something that looks like a hostname.
+#[cfg(feature = "url")]
+fn looks_like_host(s: &str) -> bool {
+    let host = s.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+
+    !host.is_empty()
+        && !s.contains(char::is_whitespace)
+        && (host.contains('.') || host.eq_ignore_ascii_case("localhost"))
+        && host
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '.' || c == '-')
+}
+
+/// Returns true if `s` is a host with a port or a user but no scheme, like
+/// `localhost:8080` or `admin@example.com`, which `Url::parse` reads as a
+/// URL whose scheme is the host.
+#[cfg(feature = "url")]
+fn is_schemeless_authority(s: &str) -> bool {
+    if s.contains("://") || s.contains(char::is_whitespace) {
+        return false;
+    }
+
+    let authority = s.split(['/', '?', '#']).next().unwrap_or_default();
+    let (user, host_port) = match authority.rsplit_once('@') {
+        // "mailto:a@example.com" has a scheme, not a user
+        Some((user, _)) if user.is_empty() || user.contains(':') => return false,
+        Some((user, host_port)) => (Some(user), host_port),
+        None => (None, authority),
+    };
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (host_port, None),
+    };
+    if port.is_some_and(|port| port.is_empty() || !port.bytes().all(|b| b.is_ascii_digit())) {
+        return false;
+    }
+
+    (user.is_some() || port.is_some()) && looks_like_host(host)
+}
+
+// ================================================================================================
+// rust_decimal::Decimal
+// ================================================================================================
+
+#[cfg(feature = "rust_decimal")]
+impl LlmDeserialize for rust_decimal::Decimal {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        // Fast path: only succeed if already a number
+        match &value.value {
+            Value::Number(n) => parse_decimal(&n.to_string()),
+            _ => None,
+        }
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        match &value.value {
+            Value::Number(n) => parse_decimal(&n.to_string()).ok_or_else(|| {
+                ParseError::DeserializeFailed(DeserializeError::invalid_value(format!(
+                    "number {} is out of range for decimal",
+                    n
+                )))
+            }),
+
+            // String to decimal without going through f64, so no precision is lost
+            Value::String(s) => {
+                let trimmed = s.trim().trim_end_matches(',');
+
+                // "1.234" is a whole number under comma-decimal locales
+                let plain =
+                    if trimmed.contains('.') && !ctx.number_locale().reads_point_as_decimal() {
+                        None
+                    } else {
+                        parse_decimal(trimmed)
+                    };
+
+                let decimal = plain
+                    .or_else(|| {
+                        // "$1,234.56" → 1234.56, "1.234,56 €" → 1234.56
+                        super::primitives::localized_digits(trimmed, ctx)
+                            .and_then(|n| parse_decimal(&n))
+                    })
+                    .ok_or_else(|| {
+                        ParseError::DeserializeFailed(DeserializeError::TypeMismatch {
+                            expected: "decimal",
+                            found: format!("string: {}", s),
+                        })
+                    })?;
+
+                ctx.add_transformation(Transformation::StringToNumber {
+                    original: s.clone(),
+                });
+                Ok(decimal)
+            }
+
+            // Array unwrapping [42] → 42
+            Value::Array(items) if items.len() == 1 => {
+                ctx.add_transformation(Transformation::SingleToArray);
+
+                let inner = FlexValue::new(items[0].clone(), value.source.clone());
+                // Decimal has an inherent `deserialize([u8; 16])`
+                <Self as LlmDeserialize>::deserialize(&inner, ctx)
+            }
+
+            _ => Err(ParseError::DeserializeFailed(
+                DeserializeError::TypeMismatch {
+                    expected: "decimal",
+                    found: value_type_name(&value.value),
+                },
+            )),
+        }
+    }
+
+    fn type_name() -> &'static str {
+        "Decimal"
+    }
+}
+
+/// Parses plain or scientific notation ("1.5", "1e-7") into a decimal.
+#[cfg(feature = "rust_decimal")]
+fn parse_decimal(s: &str) -> Option<rust_decimal::Decimal> {
+    use std::str::FromStr;
+
+    rust_decimal::Decimal::from_str(s)
+        .or_else(|_| rust_decimal::Decimal::from_scientific(s))
+        .ok()
+}
+
+// ================================================================================================
+// indexmap::IndexMap<K, V>
+// ================================================================================================
+
+/// Entries are inserted in the order the underlying JSON object yields them.
+#[cfg(feature = "indexmap")]
+impl<K, V> LlmDeserialize for indexmap::IndexMap<K, V>
+where
+    K: LlmDeserialize + Eq + std::hash::Hash,
+    V: LlmDeserialize,
+{
+    fn try_deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Option<Self> {
+        super::primitives::try_deserialize_object(value, ctx)
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        super::primitives::deserialize_object(value, ctx)
+    }
+
+    fn type_name() -> &'static str {
+        "IndexMap<K, V>"
+    }
+}
+
+// ================================================================================================
+// Tests
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::value::Source;
+
+    fn flex(value: Value) -> FlexValue {
+        FlexValue::new(value, Source::Direct)
+    }
+
+    #[test]
+    fn test_json_value_passthrough() {
+        let mut ctx = CoercionContext::new();
+        let value = flex(json!({"a": [1, 2], "b": null}));
+
+        let result = Value::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(result, json!({"a": [1, 2], "b": null}));
+        assert!(ctx.transformations().is_empty());
+    }
+
+    #[test]
+    fn test_json_map() {
+        let mut ctx = CoercionContext::new();
+
+        let map = Map::deserialize(&flex(json!({"a": 1})), &mut ctx).unwrap();
+        assert_eq!(map.get("a"), Some(&json!(1)));
+
+        assert!(Map::deserialize(&flex(json!([1, 2])), &mut ctx).is_err());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid() {
+        let mut ctx = CoercionContext::new();
+        let expected = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        let strict = flex(json!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert_eq!(
+            uuid::Uuid::try_deserialize(&strict, &mut ctx),
+            Some(expected)
+        );
+
+        for input in [
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            " urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8 ",
+            "67e5504410b1426f9247bb680e5fe0c8",
+        ] {
+            let value = flex(json!(input));
+            assert_eq!(uuid::Uuid::try_deserialize(&value, &mut ctx), None);
+            assert_eq!(uuid::Uuid::deserialize(&value, &mut ctx).unwrap(), expected);
+        }
+        assert!(matches!(
+            ctx.transformations()[0],
+            Transformation::StringNormalized { .. }
+        ));
+
+        assert!(uuid::Uuid::deserialize(&flex(json!("not-a-uuid")), &mut ctx).is_err());
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_url() {
+        let mut ctx = CoercionContext::new();
+
+        let strict = flex(json!("https://example.com/a?b=1"));
+        let url = url::Url::try_deserialize(&strict, &mut ctx).unwrap();
+        assert_eq!(url.as_str(), "https://example.com/a?b=1");
+
+        let bare = flex(json!("<www.example.com/docs>"));
+        let url = url::Url::deserialize(&bare, &mut ctx).unwrap();
+        assert_eq!(url.as_str(), "https://www.example.com/docs");
+        assert_eq!(ctx.transformations().len(), 1);
+
+        assert!(url::Url::deserialize(&flex(json!("not a url")), &mut ctx).is_err());
+        assert!(url::Url::deserialize(&flex(json!(42)), &mut ctx).is_err());
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_url_host_with_port_or_user() {
+        for (input, expected) in [
+            ("localhost:8080", "https://localhost:8080/"),
+            ("example.com:8443/api", "https://example.com:8443/api"),
+            ("admin@example.com", "https://admin@example.com/"),
+        ] {
+            let value = flex(json!(input));
+            assert!(url::Url::try_deserialize(&value, &mut CoercionContext::new()).is_none());
+
+            let mut ctx = CoercionContext::new();
+            let url = url::Url::deserialize(&value, &mut ctx).unwrap();
+            assert_eq!(url.as_str(), expected);
+            assert!(matches!(
+                ctx.transformations(),
+                [Transformation::StringNormalized { .. }]
+            ));
+        }
+
+        // Real schemes are left alone
+        let mut ctx = CoercionContext::new();
+        let mail = flex(json!("mailto:ada@example.com"));
+        let url = url::Url::try_deserialize(&mail, &mut ctx).unwrap();
+        assert_eq!(url.scheme(), "mailto");
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal() {
+        use std::str::FromStr;
+
+        use rust_decimal::Decimal;
+
+        let mut ctx = CoercionContext::new();
+
+        let value = flex(json!(12.5));
+        assert_eq!(
+            Decimal::try_deserialize(&value, &mut ctx),
+            Some(Decimal::from_str("12.5").unwrap())
+        );
+
+        let cases = [
+            ("0.1", "0.1"),
+            ("$1,234.56", "1234.56"),
+            ("€ 99.99", "99.99"),
+            (
+                "12345678901234567890.123456789",
+                "12345678901234567890.123456789",
+            ),
+            ("1.5e3", "1500"),
+        ];
+        for (input, expected) in cases {
+            let value = flex(json!(input));
+            assert_eq!(
+                <Decimal as LlmDeserialize>::deserialize(&value, &mut ctx).unwrap(),
+                Decimal::from_str(expected).unwrap(),
+                "input: {}",
+                input
+            );
+        }
+        assert!(ctx
+            .transformations()
+            .iter()
+            .all(|t| matches!(t, Transformation::StringToNumber { .. })));
+
+        assert!(
+            <Decimal as LlmDeserialize>::deserialize(&flex(json!("about 5 or 6")), &mut ctx)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn test_indexmap() {
+        let mut ctx = CoercionContext::new();
+        let value = flex(json!({"a": 1, "b": "2", "c": "x"}));
+
+        let map: indexmap::IndexMap<String, i64> =
+            LlmDeserialize::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(map.get("b"), Some(&2));
+    }
+}
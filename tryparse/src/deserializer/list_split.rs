@@ -0,0 +1,248 @@
+//! Splitting list-shaped text into items.
+//!
+//! Asked for a list, models often write it as prose: `"red, green, and blue"`,
+//! `"a; b; c"`, or a markdown list inside a string. `Vec<T>` uses
+//! [`split_list`] for scalar `T`, on both the `LlmDeserialize` and the serde
+//! path, before falling back to wrapping the whole string as one item.
+//!
+//! Only text that clearly reads as a list is split, since a single answer
+//! often holds a comma or an "and": `"Paris, France"`, `"Smith, John"` and
+//! `"Tom and Jerry"` are one item each.
+
+/// How a string was split into items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListSeparator {
+    /// `"- a\n- b"`, `"* a\n* b"`, `"• a\n• b"`
+    Bullet,
+    /// `"1. a\n2. b"`, `"1) a\n2) b"`
+    Numbered,
+    /// `"a; b"`
+    Semicolon,
+    /// `"a, b, and c"`
+    Comma,
+    /// `"a and b"`, `"a or b"`
+    Conjunction,
+}
+
+impl ListSeparator {
+    /// Name used in [`Transformation::SplitToArray`](crate::value::Transformation::SplitToArray).
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Bullet => "bullet",
+            Self::Numbered => "numbered",
+            Self::Semicolon => "semicolon",
+            Self::Comma => "comma",
+            Self::Conjunction => "conjunction",
+        }
+    }
+}
+
+/// Splits `s` into items, or returns `None` if it doesn't clearly read as a list.
+///
+/// Bullet or numbered lines need at least two items; inline separators need
+/// at least three, as in `"a, b, and c"` or `"a; b; c"`. Markdown lists win
+/// over inline separators, semicolons over commas, and commas over a bare
+/// "and"/"or". A comma between two digits (`"1,234"`) is part of a number,
+/// not a separator.
+pub(crate) fn split_list(s: &str) -> Option<(Vec<String>, ListSeparator)> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+
+    let (items, separator) = if s.contains('\n') {
+        split_markdown_list(s)?
+    } else if s.contains(';') {
+        let items = split_on(s, |_, _| true, ';');
+        (split_last_conjunction(items), ListSeparator::Semicolon)
+    } else if has_list_comma(s) {
+        let items = split_on(s, is_list_comma, ',');
+        (split_last_conjunction(items), ListSeparator::Comma)
+    } else {
+        (split_conjunction(s)?, ListSeparator::Conjunction)
+    };
+
+    let items: Vec<String> = items
+        .iter()
+        .map(|item| clean_item(item))
+        .filter(|item| !item.is_empty())
+        .collect();
+
+    let min_items = match separator {
+        ListSeparator::Bullet | ListSeparator::Numbered => 2,
+        ListSeparator::Semicolon | ListSeparator::Comma | ListSeparator::Conjunction => 3,
+    };
+    (items.len() >= min_items).then_some((items, separator))
+}
+
+/// Every non-empty line must be a bullet, or every one numbered.
+fn split_markdown_list(s: &str) -> Option<(Vec<String>, ListSeparator)> {
+    let lines: Vec<&str> = s.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+
+    if let Some(items) = lines.iter().map(|l| strip_bullet(l)).collect() {
+        return Some((items, ListSeparator::Bullet));
+    }
+    if let Some(items) = lines.iter().map(|l| strip_number(l)).collect() {
+        return Some((items, ListSeparator::Numbered));
+    }
+    None
+}
+
+fn strip_bullet(line: &str) -> Option<String> {
+    let rest = line.strip_prefix(['-', '*', '+', '•'])?;
+    rest.starts_with(char::is_whitespace)
+        .then(|| rest.trim().to_string())
+}
+
+fn strip_number(line: &str) -> Option<String> {
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = line[digits..].strip_prefix(['.', ')'])?;
+    rest.starts_with(char::is_whitespace)
+        .then(|| rest.trim().to_string())
+}
+
+fn has_list_comma(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+    (0..chars.len()).any(|i| chars[i] == ',' && is_list_comma(&chars, i))
+}
+
+/// A comma separates items unless it sits between two digits.
+fn is_list_comma(chars: &[char], i: usize) -> bool {
+    let before = i.checked_sub(1).map(|j| chars[j]);
+    let after = chars.get(i + 1);
+    !(before.is_some_and(|c| c.is_ascii_digit()) && after.is_some_and(char::is_ascii_digit))
+}
+
+fn split_on(s: &str, is_separator: fn(&[char], usize) -> bool, separator: char) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut items = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == separator && is_separator(&chars, i) {
+            items.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    items.push(current);
+    items
+}
+
+/// `"a and b"`, `"a or b"`, `"a & b"`.
+fn split_conjunction(s: &str) -> Option<Vec<String>> {
+    let words: Vec<&str> = s.split_whitespace().collect();
+    let mut items = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for word in words {
+        if is_conjunction(word) && !current.is_empty() {
+            items.push(current.join(" "));
+            current.clear();
+        } else {
+            current.push(word);
+        }
+    }
+    if items.is_empty() {
+        return None;
+    }
+    items.push(current.join(" "));
+    Some(items)
+}
+
+/// `["a", "b and c"]` → `["a", "b", "c"]`
+fn split_last_conjunction(mut items: Vec<String>) -> Vec<String> {
+    if let Some(last) = items.pop() {
+        match split_conjunction(&last) {
+            Some(rest) => items.extend(rest),
+            None => items.push(last),
+        }
+    }
+    items
+}
+
+fn is_conjunction(word: &str) -> bool {
+    word.eq_ignore_ascii_case("and") || word.eq_ignore_ascii_case("or") || word == "&"
+}
+
+/// Trims whitespace, a leading "and"/"or" (the Oxford comma's last item),
+/// a trailing period and matching quotes.
+fn clean_item(item: &str) -> String {
+    let mut item = item.trim();
+    if let Some((first, rest)) = item.split_once(char::is_whitespace) {
+        if is_conjunction(first) {
+            item = rest.trim_start();
+        }
+    }
+    if item.ends_with('.') && !item.ends_with("..") {
+        item = &item[..item.len() - 1];
+    }
+    for quote in ['"', '\'', '`'] {
+        if item.len() >= 2 && item.starts_with(quote) && item.ends_with(quote) {
+            item = &item[1..item.len() - 1];
+            break;
+        }
+    }
+    item.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(s: &str) -> Option<(Vec<String>, ListSeparator)> {
+        split_list(s)
+    }
+
+    fn items(s: &str) -> Vec<String> {
+        split(s).map(|(items, _)| items).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_inline_separators() {
+        assert_eq!(items("red, green, and blue"), ["red", "green", "blue"]);
+        assert_eq!(items("red; green; blue"), ["red", "green", "blue"]);
+        assert_eq!(items("tea or coffee or juice"), ["tea", "coffee", "juice"]);
+        assert_eq!(items("'a', 'b', 'c'"), ["a", "b", "c"]);
+
+        assert_eq!(split("a; b, c; d").unwrap().1, ListSeparator::Semicolon);
+        assert_eq!(items("a, b and c"), ["a", "b", "c"]);
+        assert_eq!(split("a, b and c").unwrap().1, ListSeparator::Comma);
+    }
+
+    #[test]
+    fn test_two_inline_items_are_one_value() {
+        for s in [
+            "Paris, France",
+            "Smith, John",
+            "Hello, world!",
+            "Tom and Jerry",
+            "salt and pepper",
+            "red; green",
+        ] {
+            assert_eq!(split(s), None, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_markdown_lists() {
+        assert_eq!(items("- rust\n- go\n"), ["rust", "go"]);
+        assert_eq!(items("* rust\n  * go"), ["rust", "go"]);
+        assert_eq!(items("1. rust\n2) go"), ["rust", "go"]);
+        assert_eq!(split("1. a\n2. b").unwrap().1, ListSeparator::Numbered);
+
+        // Prose with line breaks isn't a list
+        assert_eq!(split("Here you go:\n- rust\n- go"), None);
+    }
+
+    #[test]
+    fn test_not_a_list() {
+        assert_eq!(split("rust"), None);
+        assert_eq!(split("1,234"), None);
+        assert_eq!(split("-5"), None);
+        assert_eq!(split("and"), None);
+        assert_eq!(split("   "), None);
+        assert_eq!(items("1,234, 5,678, 9"), ["1,234", "5,678", "9"]);
+    }
+}
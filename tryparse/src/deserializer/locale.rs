@@ -0,0 +1,387 @@
+//! Locale-aware number formats.
+//!
+//! Models prompted in other languages answer in that language's number format:
+//! "1.234,56" in German, "1 234,56" in French, "1'234.56" in Swiss German,
+//! "١٢٣" with Arabic-Indic digits or full-width "１２３" from CJK input methods.
+//! [`NumberLocale`] on the [`CoercionContext`](super::CoercionContext) says which
+//! characters are decimal and grouping separators; the default auto-detects.
+//!
+//! Non-ASCII digits are normalized and recorded as
+//! [`Transformation::StringNormalized`]. When auto-detection has to guess
+//! (is "1,234" one thousand or one point two?), the guess is recorded as
+//! [`Transformation::AmbiguousNumberFormat`].
+
+use std::borrow::Cow;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::value::Transformation;
+
+/// Which characters separate decimals and digit groups in numbers written as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NumberLocale {
+    /// Detect per value: the last of `.`/`,` is the decimal separator when both
+    /// appear; a separator repeated is grouping; a lone `,` or `.` followed by
+    /// exactly three digits is ambiguous and read English-style.
+    #[default]
+    Auto,
+    /// `1,234.56` and `1'234.56`: point decimal (English, Swiss).
+    DecimalPoint,
+    /// `1.234,56` and `1 234,56`: comma decimal (most of continental Europe, Latin America).
+    DecimalComma,
+    /// Explicit separators. Spaces are always accepted as grouping.
+    Custom {
+        /// Decimal separator.
+        decimal: char,
+        /// Digit-group separator.
+        grouping: char,
+    },
+}
+
+impl NumberLocale {
+    /// Whether a plain `"3.14"` means three point one four under this locale.
+    pub(crate) fn reads_point_as_decimal(self) -> bool {
+        match self {
+            Self::Auto | Self::DecimalPoint => true,
+            Self::DecimalComma => false,
+            Self::Custom { decimal, .. } => decimal == '.',
+        }
+    }
+}
+
+/// A number found in text, in canonical ASCII form (`-1234.56`).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LocalizedNumber {
+    pub digits: String,
+    pub transformations: Vec<Transformation>,
+}
+
+/// Spaces used as digit-group separators: regular, no-break, narrow no-break, thin.
+const SPACE_SEPARATORS: [char; 4] = [' ', '\u{a0}', '\u{202f}', '\u{2009}'];
+
+/// A whole string that is one number: sign, currency before or after, separators, percent.
+static LOCALIZED_NUMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^([-+]?)\s*(?:\p{Sc}\s*)?([-+]?)(\d(?:[\d\p{P}\u{a0}\u{202f}\u{2009} ]*\d)?)\s*\p{Sc}?\s*%?$",
+    )
+    .expect("Invalid localized number regex pattern")
+});
+
+/// Reads `s` as a number in `locale`, returning canonical digits.
+///
+/// Whole-string numbers are interpreted with the locale's separators. Anything
+/// else falls back to `extract` (the English-style "find the one number in
+/// this text" search), with separators swapped first for comma-decimal locales.
+pub(crate) fn parse_localized_number(
+    s: &str,
+    locale: NumberLocale,
+    extract: impl Fn(&str) -> Option<String>,
+) -> Option<LocalizedNumber> {
+    let mut transformations = Vec::new();
+    let normalized = normalize_digits(s);
+    if let Cow::Owned(normalized) = &normalized {
+        transformations.push(Transformation::StringNormalized {
+            original: s.to_string(),
+            normalized: normalized.clone(),
+        });
+    }
+    let text = normalized.trim();
+
+    if let Some(caps) = LOCALIZED_NUMBER.captures(text) {
+        let sign = if caps[1].is_empty() {
+            &caps[2]
+        } else {
+            &caps[1]
+        };
+        if let Some((body, ambiguity)) = interpret_separators(&caps[3], locale) {
+            if let Some(assumed) = ambiguity {
+                transformations.push(Transformation::AmbiguousNumberFormat {
+                    original: s.to_string(),
+                    assumed: assumed.to_string(),
+                });
+            }
+            let sign = if sign == "-" { "-" } else { "" };
+            return Some(LocalizedNumber {
+                digits: format!("{}{}", sign, body),
+                transformations,
+            });
+        }
+    }
+
+    let digits = if locale.reads_point_as_decimal() {
+        extract(text)?
+    } else {
+        let decimal = match locale {
+            NumberLocale::Custom { decimal, .. } => decimal,
+            _ => ',',
+        };
+        // Swap so the English-style search sees "1,234.5" instead of "1.234,5"
+        let swapped: String = text
+            .chars()
+            .map(|c| match c {
+                c if c == decimal => '.',
+                '.' => ',',
+                c => c,
+            })
+            .collect();
+        extract(&swapped)?
+    };
+    Some(LocalizedNumber {
+        digits,
+        transformations,
+    })
+}
+
+/// Turns the digits-and-separators body of a number into `1234.56` form.
+///
+/// Returns the description of the guess made, if the body was ambiguous.
+fn interpret_separators(
+    body: &str,
+    locale: NumberLocale,
+) -> Option<(String, Option<&'static str>)> {
+    let (decimal, grouping): (Option<char>, &[char]) = match locale {
+        NumberLocale::DecimalPoint => (Some('.'), &[',', '\'']),
+        NumberLocale::DecimalComma => (Some(','), &['.', '\'']),
+        NumberLocale::Custom { decimal, grouping } => {
+            return convert(body, Some(decimal), &[grouping]).map(|b| (b, None));
+        }
+        NumberLocale::Auto => (None, &[]),
+    };
+    if decimal.is_some() {
+        return convert(body, decimal, grouping).map(|b| (b, None));
+    }
+
+    let last_point = body.rfind('.');
+    let last_comma = body.rfind(',');
+    match (last_point, last_comma) {
+        // Both present: whichever comes last is the decimal separator
+        (Some(p), Some(c)) if p > c => convert(body, Some('.'), &[',', '\'']).map(|b| (b, None)),
+        (Some(_), Some(_)) => convert(body, Some(','), &['.', '\'']).map(|b| (b, None)),
+        (Some(_), None) => auto_single(body, '.'),
+        (None, Some(_)) => auto_single(body, ','),
+        (None, None) => convert(body, Some('.'), &['\'']).map(|b| (b, None)),
+    }
+}
+
+/// Auto-detection when only one of `.`/`,` appears.
+fn auto_single(body: &str, separator: char) -> Option<(String, Option<&'static str>)> {
+    let other = if separator == '.' { ',' } else { '.' };
+    if body.matches(separator).count() > 1 {
+        // "1.234.567" / "1,234,567": repeated means grouping
+        return convert(body, Some(other), &[separator, '\'']).map(|b| (b, None));
+    }
+
+    let (int, frac) = body.split_once(separator)?;
+    let int_digits = int.trim_matches(|c: char| SPACE_SEPARATORS.contains(&c));
+    let looks_grouped = frac.len() == 3
+        && frac.bytes().all(|b| b.is_ascii_digit())
+        && int_digits != "0"
+        && !int_digits.is_empty();
+
+    if !looks_grouped {
+        // "1,5", "3.14", "0,125": unambiguously a decimal
+        return convert(body, Some(separator), &['\'']).map(|b| (b, None));
+    }
+
+    // "1,234" or "1.234": read the way English writes it, but say so
+    if separator == ',' {
+        convert(body, Some('.'), &[',', '\'']).map(|b| (b, Some("',' as thousands separator")))
+    } else {
+        convert(body, Some('.'), &['\'']).map(|b| (b, Some("'.' as decimal separator")))
+    }
+}
+
+/// Drops grouping separators (validating 3-digit groups) and rewrites the decimal separator.
+fn convert(body: &str, decimal: Option<char>, grouping: &[char]) -> Option<String> {
+    let (int, frac) = match decimal {
+        Some(d) => match body.split_once(d) {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (body, None),
+        },
+        None => (body, None),
+    };
+
+    let is_group = |c: char| grouping.contains(&c) || SPACE_SEPARATORS.contains(&c);
+    let groups: Vec<&str> = int.split(is_group).collect();
+    let valid_groups = groups.len() == 1
+        || (matches!(groups[0].len(), 1..=3) && groups[1..].iter().all(|g| g.len() == 3));
+    if !valid_groups || !groups.iter().all(|g| g.bytes().all(|b| b.is_ascii_digit())) {
+        return None;
+    }
+
+    let mut result: String = groups.concat();
+    if let Some(frac) = frac {
+        if frac.is_empty() || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        result.push('.');
+        result.push_str(frac);
+    }
+    Some(result)
+}
+
+/// First code points of Unicode decimal digit runs (each covers 0-9).
+const DIGIT_ZEROS: &[u32] = &[
+    0x0660, // Arabic-Indic
+    0x06F0, // Extended Arabic-Indic (Persian, Urdu)
+    0x07C0, // NKo
+    0x0966, // Devanagari
+    0x09E6, // Bengali
+    0x0A66, // Gurmukhi
+    0x0AE6, // Gujarati
+    0x0B66, // Oriya
+    0x0BE6, // Tamil
+    0x0C66, // Telugu
+    0x0CE6, // Kannada
+    0x0D66, // Malayalam
+    0x0E50, // Thai
+    0x0ED0, // Lao
+    0x0F20, // Tibetan
+    0x1040, // Myanmar
+    0x17E0, // Khmer
+    0x1810, // Mongolian
+    0xFF10, // Full-width
+];
+
+/// Maps non-ASCII digits and number punctuation to ASCII.
+///
+/// Borrows when there was nothing to change.
+pub(crate) fn normalize_digits(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut changed = false;
+    let normalized: String = s
+        .chars()
+        .map(|c| {
+            let mapped = map_digit_char(c);
+            changed |= mapped != c;
+            mapped
+        })
+        .collect();
+
+    if changed {
+        Cow::Owned(normalized)
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn map_digit_char(c: char) -> char {
+    let code = c as u32;
+    if let Some(zero) = DIGIT_ZEROS
+        .iter()
+        .find(|zero| (**zero..**zero + 10).contains(&code))
+    {
+        return char::from(b'0' + (code - zero) as u8);
+    }
+    match c {
+        '\u{066B}' => '.', // Arabic decimal separator
+        '\u{066C}' => ',', // Arabic thousands separator
+        '．' => '.',
+        '，' => ',',
+        '－' | '−' => '-',
+        '＋' => '+',
+        '％' | '٪' => '%',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deserializer::primitives::extract_single_number;
+
+    fn parse(s: &str, locale: NumberLocale) -> Option<String> {
+        parse_localized_number(s, locale, extract_single_number).map(|n| n.digits)
+    }
+
+    fn auto(s: &str) -> Option<String> {
+        parse(s, NumberLocale::Auto)
+    }
+
+    #[test]
+    fn test_auto_detects_separators() {
+        assert_eq!(auto("1.234,56").as_deref(), Some("1234.56"));
+        assert_eq!(auto("1,234.56").as_deref(), Some("1234.56"));
+        assert_eq!(auto("1 234,56").as_deref(), Some("1234.56"));
+        assert_eq!(auto("1\u{202f}234,56 €").as_deref(), Some("1234.56"));
+        assert_eq!(auto("1'234.56").as_deref(), Some("1234.56"));
+        assert_eq!(auto("1.234.567").as_deref(), Some("1234567"));
+        assert_eq!(auto("1,5").as_deref(), Some("1.5"));
+        assert_eq!(auto("-€3,25").as_deref(), Some("-3.25"));
+    }
+
+    #[test]
+    fn test_auto_records_ambiguity() {
+        let number =
+            parse_localized_number("1,234", NumberLocale::Auto, extract_single_number).unwrap();
+        assert_eq!(number.digits, "1234");
+        assert!(matches!(
+            &number.transformations[..],
+            [Transformation::AmbiguousNumberFormat { assumed, .. }]
+                if assumed == "',' as thousands separator"
+        ));
+
+        let number =
+            parse_localized_number("0,234", NumberLocale::Auto, extract_single_number).unwrap();
+        assert_eq!(number.digits, "0.234");
+        assert!(number.transformations.is_empty());
+    }
+
+    #[test]
+    fn test_explicit_locales() {
+        assert_eq!(
+            parse("1.234", NumberLocale::DecimalComma).as_deref(),
+            Some("1234")
+        );
+        assert_eq!(
+            parse("1,234", NumberLocale::DecimalComma).as_deref(),
+            Some("1.234")
+        );
+        assert_eq!(
+            parse("1,234", NumberLocale::DecimalPoint).as_deref(),
+            Some("1234")
+        );
+        assert_eq!(
+            parse(
+                "1_234;5",
+                NumberLocale::Custom {
+                    decimal: ';',
+                    grouping: '_'
+                }
+            )
+            .as_deref(),
+            Some("1234.5")
+        );
+        // Falls back to searching the text, with separators swapped
+        assert_eq!(
+            parse("about 2,5 cups", NumberLocale::DecimalComma).as_deref(),
+            Some("2.5")
+        );
+    }
+
+    #[test]
+    fn test_invalid_grouping() {
+        assert_eq!(parse("12,34.5", NumberLocale::DecimalComma), None);
+        assert_eq!(auto("12 34"), None);
+    }
+
+    #[test]
+    fn test_non_ascii_digits() {
+        let number =
+            parse_localized_number("١٢٣", NumberLocale::Auto, extract_single_number).unwrap();
+        assert_eq!(number.digits, "123");
+        assert!(matches!(
+            &number.transformations[..],
+            [Transformation::StringNormalized { normalized, .. }] if normalized == "123"
+        ));
+
+        assert_eq!(auto("１２３").as_deref(), Some("123"));
+        assert_eq!(auto("۱۲٫۵").as_deref(), Some("12.5"));
+        assert_eq!(auto("٣٬٠٠٠").as_deref(), Some("3000"));
+        assert_eq!(auto("१२३४").as_deref(), Some("1234"));
+    }
+}
@@ -0,0 +1,186 @@
+//! Reading map entries out of arrays.
+//!
+//! Asked for a map, models often return a list of entries instead:
+//!
+//! - `[{"key": "a", "value": 1}, ...]` (also `name`/`k` and `val`/`v`)
+//! - `[["a", 1], ["b", 2]]`
+//! - `[{"a": 1}, {"b": 2}]`
+//!
+//! [`entries_from_array`] recognizes these shapes for both the serde and the
+//! `LlmDeserialize` paths. Later entries replace earlier ones with the same key,
+//! as they would in a JSON object, and the replaced keys are reported.
+//!
+//! [`MapEntryPolicy`] decides what happens to an entry whose key or value
+//! doesn't coerce.
+
+use serde_json::Value;
+
+const KEY_FIELDS: &[&str] = &["key", "name", "k"];
+const VALUE_FIELDS: &[&str] = &["value", "val", "v"];
+
+/// What to do with a map entry whose key or value doesn't coerce.
+///
+/// Every such entry is recorded as a
+/// [`Transformation::MapEntryParseError`](crate::value::Transformation::MapEntryParseError)
+/// with its key, the error and the raw JSON value, unless the map fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MapEntryPolicy {
+    /// Drop the entry.
+    #[default]
+    Skip,
+    /// Fail the whole map with the entry's error.
+    Fail,
+    /// Keep the value's raw JSON text if the value type takes that string as
+    /// is, so string-like value types keep it (`{"a": 1}` → `"{\"a\":1}"`).
+    /// The text isn't coerced any further; entries whose type won't take it,
+    /// and bad keys, are dropped.
+    KeepRaw,
+}
+
+/// Which array shape the entries were read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EntryShape {
+    /// `[{"key": "a", "value": 1}]`
+    KeyValueObjects,
+    /// `[["a", 1]]`
+    Pairs,
+    /// `[{"a": 1}]`
+    SingleEntryObjects,
+}
+
+impl EntryShape {
+    /// Name used in [`Transformation::ArrayToMap`](crate::value::Transformation::ArrayToMap).
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::KeyValueObjects => "key_value_objects",
+            Self::Pairs => "pairs",
+            Self::SingleEntryObjects => "single_entry_objects",
+        }
+    }
+}
+
+/// Map entries read from an array.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ArrayEntries {
+    /// Entries in first-seen key order, with the last value for each key.
+    pub entries: Vec<(String, Value)>,
+    /// The shape every item had.
+    pub shape: EntryShape,
+    /// Keys that appeared more than once, once per repeat.
+    pub duplicates: Vec<String>,
+}
+
+/// Reads entries from `items` if every item has the same entry shape.
+///
+/// Returns `None` for an empty array or mixed shapes.
+pub(crate) fn entries_from_array(items: &[Value]) -> Option<ArrayEntries> {
+    let first = items.first()?;
+    let shape = [
+        EntryShape::KeyValueObjects,
+        EntryShape::Pairs,
+        EntryShape::SingleEntryObjects,
+    ]
+    .into_iter()
+    .find(|&shape| entry(first, shape).is_some())?;
+
+    let mut entries: Vec<(String, Value)> = Vec::with_capacity(items.len());
+    let mut duplicates = Vec::new();
+    for item in items {
+        let (key, value) = entry(item, shape)?;
+        match entries.iter_mut().find(|(k, _)| *k == key) {
+            Some(existing) => {
+                existing.1 = value;
+                duplicates.push(key);
+            }
+            None => entries.push((key, value)),
+        }
+    }
+
+    Some(ArrayEntries {
+        entries,
+        shape,
+        duplicates,
+    })
+}
+
+fn entry(item: &Value, shape: EntryShape) -> Option<(String, Value)> {
+    match (shape, item) {
+        (EntryShape::KeyValueObjects, Value::Object(obj)) if obj.len() == 2 => {
+            let key = field(obj, KEY_FIELDS)?;
+            let value = field(obj, VALUE_FIELDS)?;
+            Some((key_string(key)?, value.clone()))
+        }
+        (EntryShape::Pairs, Value::Array(pair)) => match pair.as_slice() {
+            [key, value] => Some((key_string(key)?, value.clone())),
+            _ => None,
+        },
+        (EntryShape::SingleEntryObjects, Value::Object(obj)) if obj.len() == 1 => {
+            obj.iter().next().map(|(k, v)| (k.clone(), v.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn field<'a>(obj: &'a serde_json::Map<String, Value>, names: &[&str]) -> Option<&'a Value> {
+    obj.iter()
+        .find(|(k, _)| names.iter().any(|n| k.eq_ignore_ascii_case(n)))
+        .map(|(_, v)| v)
+}
+
+/// Keys may be strings, numbers or bools.
+fn key_string(key: &Value) -> Option<String> {
+    match key {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn read(value: Value) -> Option<ArrayEntries> {
+        entries_from_array(value.as_array().unwrap())
+    }
+
+    #[test]
+    fn test_shapes() {
+        let expected = vec![("a".to_string(), json!(1)), ("b".to_string(), json!(2))];
+
+        let kv = read(json!([{"key": "a", "value": 1}, {"Name": "b", "v": 2}])).unwrap();
+        assert_eq!(kv.shape, EntryShape::KeyValueObjects);
+        assert_eq!(kv.entries, expected);
+
+        let pairs = read(json!([["a", 1], ["b", 2]])).unwrap();
+        assert_eq!(pairs.shape, EntryShape::Pairs);
+        assert_eq!(pairs.entries, expected);
+
+        let single = read(json!([{"a": 1}, {"b": 2}])).unwrap();
+        assert_eq!(single.shape, EntryShape::SingleEntryObjects);
+        assert_eq!(single.entries, expected);
+    }
+
+    #[test]
+    fn test_duplicates_keep_last() {
+        let entries = read(json!([["a", 1], ["b", 2], ["a", 3]])).unwrap();
+
+        assert_eq!(
+            entries.entries,
+            vec![("a".to_string(), json!(3)), ("b".to_string(), json!(2))]
+        );
+        assert_eq!(entries.duplicates, vec!["a"]);
+    }
+
+    #[test]
+    fn test_rejects_other_arrays() {
+        assert_eq!(read(json!([])), None);
+        assert_eq!(read(json!([1, 2])), None);
+        assert_eq!(read(json!([["a", 1], ["b"]])), None);
+        assert_eq!(read(json!([["a", 1], {"b": 2}])), None);
+        assert_eq!(read(json!([[{"a": 1}, 1]])), None);
+    }
+}
@@ -1,21 +1,43 @@
 //! Smart deserializer with type coercion.
 
 pub mod enum_coercer;
+mod external;
+mod list_split;
+mod locale;
+mod map_entries;
+pub(crate) mod natural_numbers;
+mod nullable;
+mod policy;
 pub mod primitives;
 pub mod struct_coercer;
 pub mod traits;
 pub mod union_coercer;
 
 pub use enum_coercer::{EnumMatcher, EnumVariant};
-use primitives::value_type_name;
+use list_split::{split_list, ListSeparator};
+pub use locale::NumberLocale;
+pub use map_entries::MapEntryPolicy;
+use map_entries::{entries_from_array, ArrayEntries};
+pub use natural_numbers::RangePolicy;
+pub use nullable::{Maybe, NullSentinels};
+pub use policy::{CoercionPolicy, PolicyAction};
+use primitives::{float_to_i128, value_type_name};
 use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
 use serde_json::Value;
+use std::{
+    ops::RangeInclusive,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
 pub use struct_coercer::{FieldDescriptor, StructDeserializer};
 pub use traits::{CoercionContext, LlmDeserialize};
 pub use union_coercer::{UnionDeserializer, UnionMatch};
 
 use crate::{
     error::DeserializeError,
+    parser::DuplicateKeyPolicy,
     value::{FlexValue, Transformation},
 };
 
@@ -48,13 +70,84 @@ use crate::{
 /// ```
 pub struct CoercingDeserializer {
     value: FlexValue,
+    settings: Arc<Settings>,
+}
+
+/// Settings shared by a deserializer and the ones it creates for nested values.
+#[derive(Debug, Clone, Default)]
+struct Settings {
+    policy: CoercionPolicy,
+    null_sentinels: NullSentinels,
+    range_policy: RangePolicy,
+    number_locale: NumberLocale,
+    /// Policy penalties of the transformations recorded so far, nested ones
+    /// included.
+    penalty: Arc<AtomicU32>,
 }
 
 impl CoercingDeserializer {
     /// Creates a new coercing deserializer from a `FlexValue`.
     #[inline]
     pub fn new(value: FlexValue) -> Self {
-        Self { value }
+        Self::with_policy(value, CoercionPolicy::default())
+    }
+
+    /// Creates a coercing deserializer that fails on transformations `policy` forbids.
+    pub fn with_policy(value: FlexValue, policy: CoercionPolicy) -> Self {
+        Self {
+            value,
+            settings: Arc::new(Settings {
+                policy,
+                ..Settings::default()
+            }),
+        }
+    }
+
+    /// Sets the strings that `Option<T>` reads as `None`.
+    ///
+    /// Defaults to [`NullSentinels::none`], so only a real `null` is `None`;
+    /// pass [`NullSentinels::common`] to read placeholders like `"N/A"` too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// use tryparse::deserializer::{CoercingDeserializer, NullSentinels};
+    /// use tryparse::value::{FlexValue, Source};
+    ///
+    /// let value = FlexValue::new(json!("Unknown"), Source::Direct);
+    /// let mut deserializer =
+    ///     CoercingDeserializer::new(value).with_null_sentinels(NullSentinels::common());
+    /// let owner = Option::<String>::deserialize(&mut deserializer).unwrap();
+    /// assert_eq!(owner, None);
+    /// ```
+    #[must_use]
+    pub fn with_null_sentinels(mut self, sentinels: NullSentinels) -> Self {
+        Arc::make_mut(&mut self.settings).null_sentinels = sentinels;
+        self
+    }
+
+    /// Sets how numeric ranges like "10 to 20" collapse to one number.
+    #[must_use]
+    pub fn with_range_policy(mut self, policy: RangePolicy) -> Self {
+        Arc::make_mut(&mut self.settings).range_policy = policy;
+        self
+    }
+
+    /// Sets the decimal and grouping separators for numbers written as text.
+    #[must_use]
+    pub fn with_number_locale(mut self, locale: NumberLocale) -> Self {
+        Arc::make_mut(&mut self.settings).number_locale = locale;
+        self
+    }
+
+    /// Creates a deserializer for a nested value that shares these settings.
+    fn nested(value: FlexValue, settings: &Arc<Settings>) -> Self {
+        Self {
+            value,
+            settings: Arc::clone(settings),
+        }
     }
 
     /// Consumes the deserializer and returns the `FlexValue` with all transformations.
@@ -63,9 +156,37 @@ impl CoercingDeserializer {
         self.value
     }
 
-    /// Adds a transformation to the internal value.
-    fn add_transformation(&mut self, trans: Transformation) {
+    /// Sums the policy penalties of the transformations this deserializer
+    /// and its nested ones recorded.
+    pub(crate) fn policy_penalty(&self) -> u32 {
+        self.settings.penalty.load(Ordering::Relaxed)
+    }
+
+    /// Adds a transformation to the internal value, failing if the policy forbids it.
+    fn add_transformation(&mut self, trans: Transformation) -> Result<(), DeserializeError> {
+        self.settings.policy.check(&trans)?;
+        self.settings
+            .penalty
+            .fetch_add(self.settings.policy.penalty(&trans), Ordering::Relaxed);
         self.value.add_transformation(trans);
+        Ok(())
+    }
+
+    /// Visits the value as a map if it is a JSON object.
+    fn visit_object<'de, V: Visitor<'de>>(&self, visitor: V) -> Result<V::Value, DeserializeError> {
+        match &self.value.value {
+            Value::Object(obj) => visitor.visit_map(MapDeserializer {
+                entries: obj.clone().into_iter().collect(),
+                index: 0,
+                value: None,
+                source: self.value.source.clone(),
+                settings: Arc::clone(&self.settings),
+            }),
+            other => Err(DeserializeError::type_mismatch(
+                "map",
+                value_type_name(other),
+            )),
+        }
     }
 }
 
@@ -84,6 +205,9 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
                     visitor.visit_i64(i)
                 } else if let Some(u) = n.as_u64() {
                     visitor.visit_u64(u)
+                } else if let Some(wide) = WideInteger::parse(&n.to_string()) {
+                    // Only reachable with `arbitrary_precision`, which keeps the digits
+                    wide.visit(visitor)
                 } else if let Some(f) = n.as_f64() {
                     visitor.visit_f64(f)
                 } else {
@@ -108,12 +232,12 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
                 if lower == "true" || lower == "yes" || lower == "1" {
                     self.add_transformation(Transformation::StringToNumber {
                         original: s.clone(),
-                    });
+                    })?;
                     visitor.visit_bool(true)
                 } else if lower == "false" || lower == "no" || lower == "0" {
                     self.add_transformation(Transformation::StringToNumber {
                         original: s.clone(),
-                    });
+                    })?;
                     visitor.visit_bool(false)
                 } else {
                     Err(DeserializeError::type_mismatch(
@@ -124,7 +248,7 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
             }
             Value::Number(n) => {
                 if let Some(i) = n.as_i64() {
-                    self.add_transformation(Transformation::FloatToInt { original: i as f64 });
+                    self.add_transformation(Transformation::FloatToInt { original: i as f64 })?;
                     visitor.visit_bool(i != 0)
                 } else {
                     Err(DeserializeError::type_mismatch("bool", "number"))
@@ -141,56 +265,70 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "i8", i8::MIN.into()..=i8::MAX.into())
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "i16", i16::MIN.into()..=i16::MAX.into())
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "i32", i32::MIN.into()..=i32::MAX.into())
     }
 
     fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "i64", i64::MIN.into()..=i64::MAX.into())
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "u8", u8::MIN.into()..=u8::MAX.into())
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "u16", u16::MIN.into()..=u16::MAX.into())
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "u32", u32::MIN.into()..=u32::MAX.into())
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_integer(visitor, |n| n)
+        self.deserialize_integer(visitor, "u64", u64::MIN.into()..=u64::MAX.into())
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_wide_integer(visitor, "i128")
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_wide_integer(visitor, "u128")
     }
 
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -250,14 +388,14 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
                 let s = n.to_string();
                 self.add_transformation(Transformation::StringToNumber {
                     original: s.clone(),
-                });
+                })?;
                 visitor.visit_string(s)
             }
             Value::Bool(b) => {
                 let s = b.to_string();
                 self.add_transformation(Transformation::StringToNumber {
                     original: s.clone(),
-                });
+                })?;
                 visitor.visit_string(s)
             }
             _ => Err(DeserializeError::type_mismatch(
@@ -289,6 +427,12 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
     {
         match &self.value.value {
             Value::Null => visitor.visit_none(),
+            // "N/A", "none", "-", ...
+            Value::String(s) if self.settings.null_sentinels.matches(s) => {
+                let original = s.clone();
+                self.add_transformation(Transformation::NullSentinel { original })?;
+                visitor.visit_none()
+            }
             _ => visitor.visit_some(self),
         }
     }
@@ -338,12 +482,29 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
                     items: arr,
                     index: 0,
                     source: self.value.source.clone(),
+                    settings: Arc::clone(&self.settings),
                 };
                 visitor.visit_seq(seq)
             }
-            _ => {
+            other => {
+                // Split list-shaped text: "a, b, and c", "- a\n- b", ... if the
+                // items turn out to be scalars
+                if let Some((items, separator)) = other.as_str().and_then(split_list) {
+                    let source = self.value.source.clone();
+                    let settings = Arc::clone(&self.settings);
+                    return visitor.visit_seq(SplitSeq {
+                        whole: Some(self),
+                        items,
+                        separator,
+                        index: 0,
+                        split: false,
+                        source,
+                        settings,
+                    });
+                }
+
                 // Try to wrap single value in array
-                self.add_transformation(Transformation::SingleToArray);
+                self.add_transformation(Transformation::SingleToArray)?;
                 let seq = SingleValueSeq { value: Some(self) };
                 visitor.visit_seq(seq)
             }
@@ -373,22 +534,35 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
     where
         V: Visitor<'de>,
     {
-        match self.value.value.clone() {
-            Value::Object(obj) => {
-                let entries: Vec<_> = obj.into_iter().collect();
-                let map = MapDeserializer {
-                    entries,
-                    index: 0,
-                    value: None,
-                    source: self.value.source.clone(),
-                };
-                visitor.visit_map(map)
-            }
-            _ => Err(DeserializeError::type_mismatch(
-                "map",
-                value_type_name(&self.value.value),
-            )),
+        let Value::Array(arr) = &self.value.value else {
+            return self.visit_object(visitor);
+        };
+        // [["a", 1]], [{"key": "a", "value": 1}] or [{"a": 1}]
+        let Some(ArrayEntries {
+            entries,
+            shape,
+            duplicates,
+        }) = entries_from_array(arr)
+        else {
+            return Err(DeserializeError::type_mismatch("map", "array"));
+        };
+
+        self.add_transformation(Transformation::ArrayToMap {
+            shape: shape.name().to_string(),
+        })?;
+        for key in duplicates {
+            self.add_transformation(Transformation::DuplicateKey {
+                path: key,
+                policy: DuplicateKeyPolicy::LastWins.name().to_string(),
+            })?;
         }
+        visitor.visit_map(MapDeserializer {
+            entries,
+            index: 0,
+            value: None,
+            source: self.value.source.clone(),
+            settings: Arc::clone(&self.settings),
+        })
     }
 
     fn deserialize_struct<V>(
@@ -400,7 +574,7 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
     where
         V: Visitor<'de>,
     {
-        self.deserialize_map(visitor)
+        self.visit_object(visitor)
     }
 
     fn deserialize_enum<V>(
@@ -421,6 +595,7 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
                         variant: key,
                         value,
                         source: self.value.source.clone(),
+                        settings: Arc::clone(&self.settings),
                     })
                 } else {
                     // This should be unreachable given the guard above
@@ -453,49 +628,134 @@ impl<'de> de::Deserializer<'de> for &mut CoercingDeserializer {
 
 // Helper implementations
 impl CoercingDeserializer {
+    /// Deserializes an integer of type `target`, whose values are `bounds`.
+    ///
+    /// Out-of-range values name `target` and keep the number as written.
     fn deserialize_integer<'de, V>(
         &mut self,
         visitor: V,
-        _convert: fn(i64) -> i64,
+        target: &'static str,
+        bounds: RangeInclusive<i128>,
     ) -> Result<V::Value, DeserializeError>
     where
         V: Visitor<'de>,
     {
-        match self.value.value.clone() {
+        let (n, literal) = match self.value.value.clone() {
             Value::Number(n) => {
-                if let Some(i) = n.as_i64() {
-                    visitor.visit_i64(i)
+                let integer = if let Some(i) = n.as_i64() {
+                    Some(i128::from(i))
                 } else if let Some(u) = n.as_u64() {
-                    visitor.visit_u64(u)
+                    Some(i128::from(u))
                 } else if let Some(f) = n.as_f64() {
-                    self.add_transformation(Transformation::FloatToInt { original: f });
-                    visitor.visit_i64(f as i64)
+                    self.add_transformation(Transformation::FloatToInt { original: f })?;
+                    float_to_i128(f)
                 } else {
-                    Err(DeserializeError::invalid_value("invalid number"))
-                }
+                    return Err(DeserializeError::invalid_value("invalid number"));
+                };
+                (integer, n.to_string())
             }
             Value::String(s) => {
                 // Try standard parsing first
-                if let Ok(i) = s.parse::<i64>() {
-                    self.add_transformation(Transformation::StringToNumber { original: s });
-                    visitor.visit_i64(i)
+                let integer = if let Ok(i) = s.parse::<i128>() {
+                    self.add_transformation(Transformation::StringToNumber {
+                        original: s.clone(),
+                    })?;
+                    Some(i)
+                }
+                // Natural-language numbers: "1.2k", "twenty-three", "~40", "10 to 20"
+                else if let Some(f) = self.natural_number(&s)? {
+                    if f.fract() != 0.0 {
+                        self.add_transformation(Transformation::FloatToInt { original: f })?;
+                    }
+                    float_to_i128(f.round())
                 }
                 // Fall back to BAML's advanced number parsing (handles percentages, fractions, currency)
-                else if let Some(f) = primitives::parse_comma_separated_number(&s) {
-                    self.add_transformation(Transformation::FloatToInt { original: f });
-                    visitor.visit_i64(f.round() as i64)
+                else if let Some(f) =
+                    primitives::parse_comma_separated_number(&s, self.settings.number_locale)
+                {
+                    self.add_transformation(Transformation::FloatToInt { original: f })?;
+                    float_to_i128(f.round())
                 } else {
-                    Err(DeserializeError::type_mismatch(
+                    return Err(DeserializeError::type_mismatch(
                         "integer",
                         format!("string: {}", s),
-                    ))
+                    ));
+                };
+                (integer, s)
+            }
+            _ => {
+                return Err(DeserializeError::type_mismatch(
+                    "integer",
+                    value_type_name(&self.value.value),
+                ))
+            }
+        };
+
+        match n.filter(|n| bounds.contains(n)) {
+            Some(n) => match i64::try_from(n) {
+                Ok(i) => visitor.visit_i64(i),
+                // Only u64 values above i64::MAX are left
+                Err(_) => visitor.visit_u64(n as u64),
+            },
+            None => Err(DeserializeError::out_of_range(literal, target)),
+        }
+    }
+
+    /// Deserializes `i128`/`u128` from the number's exact digits where possible.
+    ///
+    /// Without `arbitrary_precision`, integers beyond `u64` were already rounded
+    /// through f64 when the JSON was parsed; with it, they arrive here intact.
+    fn deserialize_wide_integer<'de, V>(
+        &mut self,
+        visitor: V,
+        target: &'static str,
+    ) -> Result<V::Value, DeserializeError>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value.value {
+            Value::Number(n) => {
+                if let Some(wide) = WideInteger::parse(&n.to_string()) {
+                    return wide.visit(visitor);
                 }
             }
-            _ => Err(DeserializeError::type_mismatch(
-                "integer",
-                value_type_name(&self.value.value),
-            )),
+            Value::String(s) => {
+                if let Some(wide) = WideInteger::parse(s.trim()) {
+                    self.add_transformation(Transformation::StringToNumber {
+                        original: s.clone(),
+                    })?;
+                    return wide.visit(visitor);
+                }
+            }
+            _ => {}
+        }
+        self.deserialize_integer(visitor, target, i64::MIN.into()..=u64::MAX.into())
+    }
+
+    /// Parses suffixed numbers, number words, approximate markers and ranges.
+    fn natural_number(&mut self, s: &str) -> Result<Option<f64>, DeserializeError> {
+        let parsed = natural_numbers::parse_natural_number(
+            s,
+            self.settings.range_policy,
+            self.settings.number_locale,
+        )?;
+        match parsed {
+            Some(number) => {
+                for transformation in number.transformations {
+                    self.add_transformation(transformation)?;
+                }
+                Ok(Some(number.value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Parses `s` with Rust's float syntax, unless the locale uses `.` for grouping.
+    fn plain_float(&self, s: &str) -> Option<f64> {
+        if s.contains('.') && !self.settings.number_locale.reads_point_as_decimal() {
+            return None;
         }
+        s.parse().ok()
     }
 
     fn deserialize_float<'de, V>(
@@ -516,13 +776,19 @@ impl CoercingDeserializer {
             }
             Value::String(s) => {
                 // Try standard parsing first
-                if let Ok(f) = s.parse::<f64>() {
-                    self.add_transformation(Transformation::StringToNumber { original: s });
+                if let Some(f) = self.plain_float(&s) {
+                    self.add_transformation(Transformation::StringToNumber { original: s })?;
+                    visitor.visit_f64(f)
+                }
+                // Natural-language numbers: "1.2k", "twenty-three", "~40", "10 to 20"
+                else if let Some(f) = self.natural_number(&s)? {
                     visitor.visit_f64(f)
                 }
                 // Fall back to BAML's advanced number parsing (handles percentages, fractions, currency)
-                else if let Some(f) = primitives::parse_comma_separated_number(&s) {
-                    self.add_transformation(Transformation::StringToNumber { original: s });
+                else if let Some(f) =
+                    primitives::parse_comma_separated_number(&s, self.settings.number_locale)
+                {
+                    self.add_transformation(Transformation::StringToNumber { original: s })?;
                     visitor.visit_f64(f)
                 } else {
                     Err(DeserializeError::type_mismatch(
@@ -539,11 +805,35 @@ impl CoercingDeserializer {
     }
 }
 
+/// An integer that doesn't necessarily fit in 64 bits.
+enum WideInteger {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+impl WideInteger {
+    fn parse(digits: &str) -> Option<Self> {
+        digits
+            .parse::<i128>()
+            .map(Self::Signed)
+            .or_else(|_| digits.parse::<u128>().map(Self::Unsigned))
+            .ok()
+    }
+
+    fn visit<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeserializeError> {
+        match self {
+            Self::Signed(i) => visitor.visit_i128(i),
+            Self::Unsigned(u) => visitor.visit_u128(u),
+        }
+    }
+}
+
 // Sequence deserializer for arrays
 struct SeqDeserializer {
     items: Vec<Value>,
     index: usize,
     source: crate::value::Source,
+    settings: Arc<Settings>,
 }
 
 impl<'de> SeqAccess<'de> for SeqDeserializer {
@@ -557,7 +847,7 @@ impl<'de> SeqAccess<'de> for SeqDeserializer {
             let value = self.items[self.index].clone();
             self.index += 1;
             let flex_value = FlexValue::new(value, self.source.clone());
-            let mut deserializer = CoercingDeserializer::new(flex_value);
+            let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
             seed.deserialize(&mut deserializer).map(Some)
         } else {
             Ok(None)
@@ -584,12 +874,194 @@ impl<'de, 'a> SeqAccess<'de> for SingleValueSeq<'a> {
     }
 }
 
+// Sequence of the items split out of list-shaped text. The first item decides:
+// if its type reads a scalar, every item is served; otherwise the whole text
+// is the only item, as with SingleValueSeq
+struct SplitSeq<'a> {
+    whole: Option<&'a mut CoercingDeserializer>,
+    items: Vec<String>,
+    separator: ListSeparator,
+    index: usize,
+    split: bool,
+    source: crate::value::Source,
+    settings: Arc<Settings>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for SplitSeq<'a> {
+    type Error = DeserializeError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if let Some(whole) = self.whole.take() {
+            let item = FlexValue::new(Value::String(self.items[0].clone()), self.source.clone());
+            let probe = SplitItemProbe {
+                whole,
+                item: CoercingDeserializer::nested(item, &self.settings),
+                separator: self.separator,
+                split: &mut self.split,
+            };
+            self.index = 1;
+            return seed.deserialize(probe).map(Some);
+        }
+        if !self.split || self.index >= self.items.len() {
+            return Ok(None);
+        }
+
+        let item = Value::String(self.items[self.index].clone());
+        self.index += 1;
+        let mut deserializer =
+            CoercingDeserializer::nested(FlexValue::new(item, self.source.clone()), &self.settings);
+        seed.deserialize(&mut deserializer).map(Some)
+    }
+}
+
+// Deserializes the first split item if the target reads a scalar, or the whole
+// text otherwise, recording which one happened on the whole text
+struct SplitItemProbe<'a, 'b> {
+    whole: &'a mut CoercingDeserializer,
+    item: CoercingDeserializer,
+    separator: ListSeparator,
+    split: &'b mut bool,
+}
+
+impl<'a> SplitItemProbe<'a, '_> {
+    fn split_item(self) -> Result<CoercingDeserializer, DeserializeError> {
+        let original = self
+            .whole
+            .value
+            .value
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        self.whole
+            .add_transformation(Transformation::SplitToArray {
+                original,
+                separator: self.separator.name().to_string(),
+            })?;
+        *self.split = true;
+        Ok(self.item)
+    }
+
+    fn whole(self) -> Result<&'a mut CoercingDeserializer, DeserializeError> {
+        self.whole
+            .add_transformation(Transformation::SingleToArray)?;
+        Ok(self.whole)
+    }
+}
+
+macro_rules! probe_scalar {
+    ($($method:ident)*) => {$(
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            (&mut self.split_item()?).$method(visitor)
+        }
+    )*};
+}
+
+macro_rules! probe_whole {
+    ($($method:ident)*) => {$(
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.whole()?.$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for SplitItemProbe<'_, '_> {
+    type Error = DeserializeError;
+
+    probe_scalar! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string
+    }
+
+    probe_whole! {
+        deserialize_any deserialize_bytes deserialize_byte_buf deserialize_option
+        deserialize_unit deserialize_seq deserialize_map deserialize_identifier
+        deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.whole()?.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.whole()?.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.whole()?.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.whole()?.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.whole()?.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.whole()?.deserialize_enum(name, variants, visitor)
+    }
+}
+
 // Map deserializer
 struct MapDeserializer {
     entries: Vec<(String, Value)>,
     index: usize,
     value: Option<Value>,
     source: crate::value::Source,
+    settings: Arc<Settings>,
 }
 
 impl<'de> MapAccess<'de> for MapDeserializer {
@@ -604,7 +1076,7 @@ impl<'de> MapAccess<'de> for MapDeserializer {
             self.value = Some(value.clone());
             let key_value = Value::String(key.clone());
             let flex_value = FlexValue::new(key_value, self.source.clone());
-            let mut deserializer = CoercingDeserializer::new(flex_value);
+            let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
             seed.deserialize(&mut deserializer).map(Some)
         } else {
             Ok(None)
@@ -619,7 +1091,7 @@ impl<'de> MapAccess<'de> for MapDeserializer {
             Some(value) => {
                 self.index += 1;
                 let flex_value = FlexValue::new(value, self.source.clone());
-                let mut deserializer = CoercingDeserializer::new(flex_value);
+                let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
                 seed.deserialize(&mut deserializer)
             }
             None => Err(DeserializeError::Custom("value is missing".to_string())),
@@ -632,6 +1104,7 @@ struct EnumDeserializer {
     variant: String,
     value: Value,
     source: crate::value::Source,
+    settings: Arc<Settings>,
 }
 
 impl<'de> de::EnumAccess<'de> for EnumDeserializer {
@@ -644,7 +1117,7 @@ impl<'de> de::EnumAccess<'de> for EnumDeserializer {
     {
         let variant_value = Value::String(self.variant.clone());
         let flex_value = FlexValue::new(variant_value, self.source.clone());
-        let mut deserializer = CoercingDeserializer::new(flex_value);
+        let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
         let v = seed.deserialize(&mut deserializer)?;
         Ok((v, self))
     }
@@ -662,7 +1135,7 @@ impl<'de> de::VariantAccess<'de> for EnumDeserializer {
         T: DeserializeSeed<'de>,
     {
         let flex_value = FlexValue::new(self.value, self.source);
-        let mut deserializer = CoercingDeserializer::new(flex_value);
+        let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
         seed.deserialize(&mut deserializer)
     }
 
@@ -671,7 +1144,7 @@ impl<'de> de::VariantAccess<'de> for EnumDeserializer {
         V: Visitor<'de>,
     {
         let flex_value = FlexValue::new(self.value, self.source);
-        let mut deserializer = CoercingDeserializer::new(flex_value);
+        let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
         de::Deserializer::deserialize_seq(&mut deserializer, visitor)
     }
 
@@ -684,7 +1157,7 @@ impl<'de> de::VariantAccess<'de> for EnumDeserializer {
         V: Visitor<'de>,
     {
         let flex_value = FlexValue::new(self.value, self.source);
-        let mut deserializer = CoercingDeserializer::new(flex_value);
+        let mut deserializer = CoercingDeserializer::nested(flex_value, &self.settings);
         de::Deserializer::deserialize_map(&mut deserializer, visitor)
     }
 }
@@ -738,4 +1211,60 @@ mod tests {
         let result: Test = Test::deserialize(&mut deserializer).unwrap();
         assert_eq!(result.items, vec!["single"]);
     }
+
+    #[test]
+    fn test_deserialize_split_to_array() {
+        let value = FlexValue::new(
+            json!({"items": "a, b, and c", "ids": "- 1\n- 2"}),
+            Source::Direct,
+        );
+        let mut deserializer = CoercingDeserializer::new(value);
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Test {
+            items: Vec<String>,
+            ids: Vec<u32>,
+        }
+
+        let result: Test = Test::deserialize(&mut deserializer).unwrap();
+        assert_eq!(result.items, vec!["a", "b", "c"]);
+        assert_eq!(result.ids, vec![1, 2]);
+
+        let value = FlexValue::new(json!("a or b or c"), Source::Direct);
+        let mut deserializer = CoercingDeserializer::new(value);
+        let items = <Vec<String> as Deserialize>::deserialize(&mut deserializer).unwrap();
+        assert_eq!(items, vec!["a", "b", "c"]);
+        assert!(matches!(
+            deserializer.into_value().transformations(),
+            [Transformation::SplitToArray { separator, .. }] if separator == "conjunction"
+        ));
+    }
+
+    #[test]
+    fn test_deserialize_split_needs_scalar_items() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Tag {
+            name: String,
+        }
+
+        // A struct item gets the whole text, not the split items
+        let value = FlexValue::new(json!({"name": "a, b, and c"}), Source::Direct);
+        let mut deserializer = CoercingDeserializer::new(value);
+        let tags = <Vec<Tag> as Deserialize>::deserialize(&mut deserializer).unwrap();
+        assert_eq!(
+            tags,
+            vec![Tag {
+                name: "a, b, and c".to_string()
+            }]
+        );
+        assert!(matches!(
+            deserializer.into_value().transformations(),
+            [Transformation::SingleToArray]
+        ));
+
+        let value = FlexValue::new(json!("Paris, France"), Source::Direct);
+        let mut deserializer = CoercingDeserializer::new(value);
+        let items = <Vec<String> as Deserialize>::deserialize(&mut deserializer).unwrap();
+        assert_eq!(items, vec!["Paris, France"]);
+    }
 }
@@ -0,0 +1,501 @@
+//! Natural-language number parsing.
+//!
+//! Handles the ways models write numbers that plain parsing misses:
+//! - Magnitude suffixes: "1.2k" → 1200, "$2.5B" → 2500000000, "3 million" → 3000000
+//! - English number words: "twenty-three" → 23, "a hundred and five" → 105
+//! - Approximate markers: "~40", "40+", "about 40", "40ish" → 40
+//! - Ranges: "10 to 20", "10–20", "between 10 and 20", "ten to twenty" → resolved by
+//!   [`RangePolicy`]
+//!
+//! The integer and float coercions call into this after plain parsing and
+//! fractions have failed, and before the looser "find the one number in this
+//! string" fallback (which would read "1.2k" as 1.2). Every rule that fires
+//! records its own [`Transformation`] so scoring can tell them apart.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::locale::{parse_localized_number, NumberLocale};
+use crate::{error::DeserializeError, value::Transformation};
+
+/// How to turn a range like "10 to 20" or "between 10 and 20" into a single number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RangePolicy {
+    /// Take the lower bound.
+    Low,
+    /// Take the upper bound.
+    High,
+    /// Take the midpoint: "10 to 20" → 15.
+    #[default]
+    Midpoint,
+    /// Refuse ranges: deserialization fails.
+    Reject,
+}
+
+impl RangePolicy {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::High => "high",
+            Self::Midpoint => "midpoint",
+            Self::Reject => "reject",
+        }
+    }
+}
+
+/// A number recovered from text, with the transformations that produced it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NaturalNumber {
+    pub value: f64,
+    pub transformations: Vec<Transformation>,
+}
+
+/// Parses suffixed numbers, number words, approximate markers and ranges.
+///
+/// Returns `Ok(None)` when none of those rules apply, including for plain
+/// numbers like "42" or "$1,234" which the callers already handle. Returns an
+/// error only for ranges under [`RangePolicy::Reject`].
+pub(crate) fn parse_natural_number(
+    s: &str,
+    policy: RangePolicy,
+    locale: NumberLocale,
+) -> Result<Option<NaturalNumber>, DeserializeError> {
+    let original = s.trim();
+    let (text, marker) = strip_approximate(original);
+    let mut transformations = Vec::new();
+
+    if let Some(marker) = marker {
+        transformations.push(Transformation::ApproximateValue {
+            original: original.to_string(),
+            marker,
+        });
+    }
+
+    // Whole-string forms first, so "twenty-three" isn't read as the range 20-3
+    let value = if let Some(value) = parse_single(text, original, locale, &mut transformations) {
+        value
+    } else if let Some((low, high)) = parse_range(text, original, locale, &mut transformations) {
+        let value = match policy {
+            RangePolicy::Low => low,
+            RangePolicy::High => high,
+            RangePolicy::Midpoint => (low + high) / 2.0,
+            RangePolicy::Reject => {
+                return Err(DeserializeError::invalid_value(format!(
+                    "'{}' is a range ({} to {}), but ranges are rejected",
+                    original, low, high
+                )))
+            }
+        };
+        transformations.push(Transformation::RangeResolved {
+            original: original.to_string(),
+            low,
+            high,
+            policy: policy.name().to_string(),
+        });
+        value
+    } else {
+        return Ok(None);
+    };
+
+    if transformations.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(NaturalNumber {
+        value,
+        transformations,
+    }))
+}
+
+// ================================================================================================
+// Approximate markers
+// ================================================================================================
+
+/// Leading words/symbols that mark an estimate or a bound rather than an exact value.
+const APPROXIMATE_PREFIXES: &[&str] = &[
+    "approximately",
+    "approx.",
+    "approx",
+    "roughly",
+    "around",
+    "about",
+    "nearly",
+    "almost",
+    "circa",
+    "ca.",
+    "maybe",
+    "more than",
+    "less than",
+    "fewer than",
+    "at least",
+    "at most",
+    "up to",
+    "over",
+    "under",
+    "~",
+    "≈",
+    ">=",
+    "<=",
+    ">",
+    "<",
+];
+
+/// Trailing approximate markers: "40+", "an hour or so", "40ish".
+const APPROXIMATE_SUFFIXES: &[&str] = &["or so", "or more", "-ish", "ish", "+"];
+
+/// Removes one approximate marker from the start or end of `text`.
+///
+/// Returns the remaining text and the marker that was removed, if any.
+pub(crate) fn strip_approximate(text: &str) -> (&str, Option<String>) {
+    let lower = text.to_lowercase();
+    // Lowercasing can change byte lengths for non-ASCII text; only strip when it didn't
+    if lower.len() != text.len() {
+        return (text, None);
+    }
+
+    for prefix in APPROXIMATE_PREFIXES {
+        if let Some(rest) = lower.strip_prefix(prefix) {
+            // Word prefixes need a following space ("about 5", not "abouts")
+            let wordy = prefix.ends_with(|c: char| c.is_alphabetic());
+            if wordy && !rest.starts_with(char::is_whitespace) {
+                continue;
+            }
+            let rest = text[prefix.len()..].trim_start();
+            if !rest.is_empty() {
+                return (rest, Some((*prefix).to_string()));
+            }
+        }
+    }
+    for suffix in APPROXIMATE_SUFFIXES {
+        if lower.ends_with(suffix) {
+            let rest = text[..text.len() - suffix.len()].trim_end();
+            if !rest.is_empty() {
+                return (rest, Some((*suffix).to_string()));
+            }
+        }
+    }
+    (text, None)
+}
+
+// ================================================================================================
+// Single values
+// ================================================================================================
+
+/// A number followed by a magnitude suffix: "1.2k", "$2.5 B", "3 million", "1,5 Mio".
+static SUFFIXED_NUMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^([-+]?\p{Sc}?\s*(?:\d(?:[\d.,']*\d)?|\.\d+))\s*([A-Za-z]+)\.?$")
+        .expect("Invalid suffixed number regex pattern")
+});
+
+fn parse_single(
+    text: &str,
+    original: &str,
+    locale: NumberLocale,
+    transformations: &mut Vec<Transformation>,
+) -> Option<f64> {
+    // Plain numbers never fall back to searching inside the text here
+    let plain = |s: &str| parse_localized_number(s, locale, |_| None);
+
+    if let Some(number) = plain(text) {
+        transformations.extend(number.transformations);
+        return number.digits.parse().ok();
+    }
+
+    if let Some(caps) = SUFFIXED_NUMBER.captures(text) {
+        let multiplier = magnitude(&caps[2])?;
+        let number = plain(&caps[1])?;
+        let value: f64 = number.digits.parse().ok()?;
+        transformations.extend(number.transformations);
+        transformations.push(Transformation::MagnitudeSuffix {
+            original: original.to_string(),
+            multiplier,
+        });
+        return Some(value * multiplier);
+    }
+
+    let value = parse_number_words(text)?;
+    transformations.push(Transformation::NumberWords {
+        original: original.to_string(),
+    });
+    Some(value)
+}
+
+/// Maps a magnitude suffix to its multiplier.
+///
+/// Lowercase "m" and "t" are deliberately not suffixes: "5m" is as likely to be
+/// minutes or meters as millions.
+fn magnitude(suffix: &str) -> Option<f64> {
+    let multiplier = match suffix {
+        "k" | "K" => 1e3,
+        "M" | "MM" | "mm" | "mn" => 1e6,
+        "B" | "b" | "bn" => 1e9,
+        "T" | "tn" => 1e12,
+        word => match word.to_lowercase().as_str() {
+            "hundred" => 1e2,
+            "thousand" => 1e3,
+            "million" | "mil" => 1e6,
+            "billion" => 1e9,
+            "trillion" => 1e12,
+            _ => return None,
+        },
+    };
+    Some(multiplier)
+}
+
+// ================================================================================================
+// Number words
+// ================================================================================================
+
+fn small_number(word: &str) -> Option<u64> {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    if let Some(n) = ONES.iter().position(|w| *w == word) {
+        return Some(n as u64);
+    }
+    TENS.iter()
+        .position(|w| *w == word)
+        .map(|n| (n as u64 + 2) * 10)
+}
+
+fn scale_word(word: &str) -> Option<u64> {
+    match word {
+        "thousand" => Some(1_000),
+        "million" => Some(1_000_000),
+        "billion" => Some(1_000_000_000),
+        "trillion" => Some(1_000_000_000_000),
+        _ => None,
+    }
+}
+
+/// Parses English cardinals: "twenty-three", "one hundred and five",
+/// "three thousand two hundred", "a dozen", "minus five".
+fn parse_number_words(text: &str) -> Option<f64> {
+    let lower = text.to_lowercase();
+    let tokens: Vec<&str> = lower
+        .split(|c: char| c.is_whitespace() || c == '-' || c == ',')
+        .filter(|t| !t.is_empty() && *t != "and")
+        .collect();
+
+    let mut negative = false;
+    let mut total: u64 = 0;
+    let mut current: u64 = 0;
+    let mut pending_article = false;
+    let mut seen_number = false;
+
+    for (index, token) in tokens.iter().enumerate() {
+        match *token {
+            "minus" | "negative" if index == 0 => negative = true,
+            "a" | "an" if !seen_number && current == 0 => pending_article = true,
+            "hundred" => {
+                if current >= 100 || (current == 0 && !pending_article) {
+                    return None;
+                }
+                current = current.max(1).checked_mul(100)?;
+                pending_article = false;
+                seen_number = true;
+            }
+            "dozen" => {
+                current = current.max(1).checked_mul(12)?;
+                pending_article = false;
+                seen_number = true;
+            }
+            word => {
+                if let Some(scale) = scale_word(word) {
+                    if current == 0 && !pending_article {
+                        return None;
+                    }
+                    total = total.checked_add(current.max(1).checked_mul(scale)?)?;
+                    current = 0;
+                    pending_article = false;
+                    seen_number = true;
+                } else if let Some(n) = small_number(word) {
+                    if pending_article {
+                        return None;
+                    }
+                    // Only "twenty" + "three" style pairs may follow a non-round value
+                    let last_two = current % 100;
+                    let fits = last_two == 0 || (n < 10 && last_two >= 20 && last_two % 10 == 0);
+                    if !fits {
+                        return None;
+                    }
+                    current += n;
+                    seen_number = true;
+                } else {
+                    return None;
+                }
+            }
+        }
+    }
+
+    if !seen_number || pending_article {
+        return None;
+    }
+    let value = total.checked_add(current)? as f64;
+    Some(if negative { -value } else { value })
+}
+
+// ================================================================================================
+// Ranges
+// ================================================================================================
+
+static BETWEEN_RANGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:between|from)\s+(.+?)\s+(?:and|to|-|–|—)\s+(.+)$")
+        .expect("Invalid between-range regex pattern")
+});
+
+// A hyphen only separates bounds with spaces around it: "555-1234" and "1-2"
+// are phone numbers, identifiers or scores more often than ranges
+static BARE_RANGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(.+?)(?:\s*(?:–|—|\bto\b)\s*|\s+-\s+)(.+)$")
+        .expect("Invalid range regex pattern")
+});
+
+/// Parses "10 to 20", "10–20", "10 - 20", "between 10 and 20". Bounds may use
+/// suffixes or words.
+fn parse_range(
+    text: &str,
+    original: &str,
+    locale: NumberLocale,
+    transformations: &mut Vec<Transformation>,
+) -> Option<(f64, f64)> {
+    let caps = BETWEEN_RANGE
+        .captures(text)
+        .or_else(|| BARE_RANGE.captures(text))?;
+
+    let mut bound_transformations = Vec::new();
+    let low = parse_single(caps[1].trim(), original, locale, &mut bound_transformations)?;
+    let high = parse_single(caps[2].trim(), original, locale, &mut bound_transformations)?;
+    // "20-10" is more likely a score or an identifier than a range
+    if low > high {
+        return None;
+    }
+
+    transformations.extend(bound_transformations);
+    Some((low, high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(s: &str) -> Option<f64> {
+        parse_natural_number(s, RangePolicy::default(), NumberLocale::Auto)
+            .unwrap()
+            .map(|n| n.value)
+    }
+
+    #[test]
+    fn test_magnitude_suffixes() {
+        assert_eq!(value("1.2k"), Some(1_200.0));
+        assert_eq!(value("$2.5B"), Some(2_500_000_000.0));
+        assert_eq!(value("3 million"), Some(3_000_000.0));
+        assert_eq!(value("-4K"), Some(-4_000.0));
+        assert_eq!(value("1,500 thousand"), Some(1_500_000.0));
+        assert_eq!(value("5m"), None);
+        assert_eq!(value("5 apples"), None);
+    }
+
+    #[test]
+    fn test_number_words() {
+        assert_eq!(value("twenty-three"), Some(23.0));
+        assert_eq!(value("a hundred and five"), Some(105.0));
+        assert_eq!(value("three thousand two hundred"), Some(3_200.0));
+        assert_eq!(value("One Million"), Some(1_000_000.0));
+        assert_eq!(value("a dozen"), Some(12.0));
+        assert_eq!(value("minus five"), Some(-5.0));
+        assert_eq!(value("zero"), Some(0.0));
+        assert_eq!(value("three four"), None);
+        assert_eq!(value("twenty twenty"), None);
+        assert_eq!(value("a"), None);
+        assert_eq!(value("hundred"), None);
+    }
+
+    #[test]
+    fn test_approximate_markers() {
+        assert_eq!(value("~40"), Some(40.0));
+        assert_eq!(value("40+"), Some(40.0));
+        assert_eq!(value("about 40"), Some(40.0));
+        assert_eq!(value("40ish"), Some(40.0));
+        assert_eq!(value("roughly 1.5k"), Some(1_500.0));
+
+        let parsed = parse_natural_number("~40", RangePolicy::default(), NumberLocale::Auto)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            parsed.transformations,
+            vec![Transformation::ApproximateValue {
+                original: "~40".to_string(),
+                marker: "~".to_string(),
+            }]
+        );
+
+        // Markers need a following word boundary
+        assert_eq!(value("overt"), None);
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(value("10 to 20"), Some(15.0));
+        assert_eq!(value("10 - 20"), Some(15.0));
+        assert_eq!(value("10–20"), Some(15.0));
+        assert_eq!(value("between 10 and 20"), Some(15.0));
+        assert_eq!(value("ten to twenty"), Some(15.0));
+        assert_eq!(value("10k – 20k"), Some(15_000.0));
+        assert_eq!(value("20 - 10"), None);
+        assert_eq!(value("2024-03-03"), None);
+
+        let low = parse_natural_number("10 to 20", RangePolicy::Low, NumberLocale::Auto).unwrap();
+        assert_eq!(low.unwrap().value, 10.0);
+        let high = parse_natural_number("10 to 20", RangePolicy::High, NumberLocale::Auto).unwrap();
+        assert_eq!(high.unwrap().value, 20.0);
+        assert!(parse_natural_number("10 to 20", RangePolicy::Reject, NumberLocale::Auto).is_err());
+
+        let parsed =
+            parse_natural_number("between 10 and 20", RangePolicy::Low, NumberLocale::Auto)
+                .unwrap()
+                .unwrap();
+        assert!(matches!(
+            &parsed.transformations[..],
+            [Transformation::RangeResolved { low, high, policy, .. }]
+                if *low == 10.0 && *high == 20.0 && policy == "low"
+        ));
+    }
+
+    #[test]
+    fn test_unspaced_hyphens_are_not_ranges() {
+        assert_eq!(value("555-1234"), None);
+        assert_eq!(value("1-2"), None);
+        assert_eq!(value("10-20"), None);
+        assert_eq!(value("1M-2M"), None);
+    }
+
+    #[test]
+    fn test_plain_numbers_are_left_to_callers() {
+        assert_eq!(value("42"), None);
+        assert_eq!(value("$1,234.56"), None);
+        assert_eq!(value(""), None);
+    }
+}
@@ -0,0 +1,307 @@
+//! Null-like placeholders and the absent / null / present distinction.
+//!
+//! Models rarely write `null` when they have nothing to say. They write `"N/A"`,
+//! `"none"`, `"-"`, `"unknown"` or an empty string. Opt in with
+//! [`NullSentinels::common`] (or a set of your own) and, for `Option<T>`
+//! targets, these become `None` and a
+//! [`Transformation::NullSentinel`](crate::value::Transformation::NullSentinel)
+//! is recorded. Other targets are unaffected, so a `String` field still gets `"N/A"`.
+//!
+//! [`Maybe<T>`] keeps apart what `Option<T>` merges: a key that was left out
+//! ([`Maybe::Absent`]), one set to null or a sentinel ([`Maybe::Null`]), and a
+//! real value ([`Maybe::Value`]). That's the distinction a PATCH-style update needs.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    deserializer::traits::{CoercionContext, LlmDeserialize},
+    error::Result,
+    value::FlexValue,
+};
+
+/// Placeholders that [`NullSentinels::common`] treats as "no value".
+const COMMON_SENTINELS: &[&str] = &[
+    "n/a", "na", "none", "null", "nil", "-", "--", "—", "unknown",
+];
+
+/// Strings that mean "no value" when the target is optional.
+///
+/// The default set is empty, so only a real `null` is `None`. Matching ignores
+/// case and surrounding whitespace. [`Self::common`] holds `"n/a"`, `"na"`,
+/// `"none"`, `"null"`, `"nil"`, `"-"`, `"--"`, `"—"` and `"unknown"`; the empty
+/// string is not included, since `Some("")` is often a real answer, but can be
+/// added with `.with("")`.
+/// Sentinels win over the inner type, so with the common set an `Option<Status>`
+/// reads `"unknown"` as `None` even if `Status` has an `Unknown` variant; use
+/// [`Self::without`] to keep such words.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::deserializer::{CoercionContext, NullSentinels};
+///
+/// let sentinels = NullSentinels::common().with("tbd").without("unknown");
+/// assert!(sentinels.matches(" TBD "));
+/// assert!(!sentinels.matches("unknown"));
+///
+/// let ctx = CoercionContext::new().with_null_sentinels(sentinels);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullSentinels {
+    values: Vec<String>,
+}
+
+impl NullSentinels {
+    /// Creates an empty set: only a real JSON `null` means "no value".
+    ///
+    /// This is the default.
+    pub fn none() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Creates the set of common placeholders, like `"N/A"`, `"none"` and `"-"`.
+    pub fn common() -> Self {
+        Self {
+            values: COMMON_SENTINELS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Adds a sentinel.
+    pub fn with(mut self, sentinel: &str) -> Self {
+        let sentinel = normalize(sentinel);
+        if !self.values.contains(&sentinel) {
+            self.values.push(sentinel);
+        }
+        self
+    }
+
+    /// Removes a sentinel.
+    pub fn without(mut self, sentinel: &str) -> Self {
+        let sentinel = normalize(sentinel);
+        self.values.retain(|s| *s != sentinel);
+        self
+    }
+
+    /// Returns true if `s` is one of the sentinels.
+    pub fn matches(&self, s: &str) -> bool {
+        let s = normalize(s);
+        self.values.contains(&s)
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.trim().to_lowercase()
+}
+
+/// A value that can be absent, explicitly null, or present.
+///
+/// With `LlmDeserialize` (derive), a `Maybe<T>` field is optional: a missing key
+/// gives [`Maybe::Absent`], `null` or a [null sentinel](NullSentinels) gives
+/// [`Maybe::Null`], and anything else is coerced to `T`.
+///
+/// With serde, mark the field `#[serde(default)]` so a missing key becomes `Absent`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use tryparse::deserializer::Maybe;
+///
+/// #[derive(Deserialize, Debug)]
+/// struct UserPatch {
+///     #[serde(default)]
+///     nickname: Maybe<String>,
+///     #[serde(default)]
+///     age: Maybe<u32>,
+///     #[serde(default)]
+///     email: Maybe<String>,
+/// }
+///
+/// let patch: UserPatch = tryparse::parse(r#"{"nickname": null, "age": "31"}"#).unwrap();
+/// assert_eq!(patch.nickname, Maybe::Null); // clear it
+/// assert_eq!(patch.age, Maybe::Value(31)); // set it
+/// assert_eq!(patch.email, Maybe::Absent); // leave it alone
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Maybe<T> {
+    /// The key was not present.
+    #[default]
+    Absent,
+    /// The key was present with `null` or a null sentinel.
+    Null,
+    /// The key was present with a value.
+    Value(T),
+}
+
+impl<T> Maybe<T> {
+    /// Returns true for [`Maybe::Absent`].
+    pub const fn is_absent(&self) -> bool {
+        matches!(self, Self::Absent)
+    }
+
+    /// Returns true for [`Maybe::Null`].
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns true for [`Maybe::Value`].
+    pub const fn is_value(&self) -> bool {
+        matches!(self, Self::Value(_))
+    }
+
+    /// Converts from `&Maybe<T>` to `Maybe<&T>`.
+    pub const fn as_ref(&self) -> Maybe<&T> {
+        match self {
+            Self::Absent => Maybe::Absent,
+            Self::Null => Maybe::Null,
+            Self::Value(v) => Maybe::Value(v),
+        }
+    }
+
+    /// Returns the value, merging `Absent` and `Null` into `None`.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Value(v) => Some(v),
+            Self::Absent | Self::Null => None,
+        }
+    }
+
+    /// Applies the update to `target`: `Absent` leaves it, `Null` clears it,
+    /// `Value` replaces it.
+    pub fn apply_to(self, target: &mut Option<T>) {
+        match self {
+            Self::Absent => {}
+            Self::Null => *target = None,
+            Self::Value(v) => *target = Some(v),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Maybe<T> {
+    /// `Some` becomes `Value` and `None` becomes `Null`.
+    fn from(option: Option<T>) -> Self {
+        option.map_or(Self::Null, Self::Value)
+    }
+}
+
+impl<T: LlmDeserialize> LlmDeserialize for Maybe<T> {
+    fn try_deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Option<Self> {
+        Option::<T>::try_deserialize(value, ctx).map(Self::from)
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        Option::<T>::deserialize(value, ctx).map(Self::from)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Maybe<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Maybe<T> {
+    /// Serializes `Value` as the value and both `Absent` and `Null` as `null`.
+    ///
+    /// Add `#[serde(skip_serializing_if = "Maybe::is_absent")]` to leave absent keys out.
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Value(v) => serializer.serialize_some(v),
+            Self::Absent | Self::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::value::{Source, Transformation};
+
+    fn lenient<T: LlmDeserialize>(value: &FlexValue, ctx: &mut CoercionContext) -> Result<T> {
+        T::deserialize(value, ctx)
+    }
+
+    #[test]
+    fn test_common_sentinels() {
+        assert!(!NullSentinels::default().matches("N/A"));
+
+        let sentinels = NullSentinels::common();
+        for s in ["N/A", " None ", "NULL", "-", "unknown"] {
+            assert!(sentinels.matches(s), "{:?}", s);
+        }
+        assert!(!sentinels.matches(""));
+        assert!(sentinels.clone().with("").matches("  "));
+        assert!(!sentinels.matches("0"));
+        assert!(!sentinels.matches("nothing to report"));
+    }
+
+    #[test]
+    fn test_option_reads_sentinel_as_none() {
+        let mut ctx = CoercionContext::new().with_null_sentinels(NullSentinels::common());
+        let value = FlexValue::new(json!("N/A"), Source::Direct);
+
+        assert_eq!(
+            <Option<i64> as LlmDeserialize>::try_deserialize(&value, &mut ctx),
+            None
+        );
+        assert_eq!(lenient::<Option<i64>>(&value, &mut ctx).unwrap(), None);
+        assert_eq!(lenient::<Option<String>>(&value, &mut ctx).unwrap(), None);
+        assert!(ctx
+            .transformations()
+            .iter()
+            .any(|t| matches!(t, Transformation::NullSentinel { original } if original == "N/A")));
+    }
+
+    #[test]
+    fn test_custom_sentinels() {
+        let mut ctx = CoercionContext::new().with_null_sentinels(NullSentinels::none().with("tbd"));
+
+        let value = FlexValue::new(json!("TBD"), Source::Direct);
+        assert_eq!(lenient::<Option<String>>(&value, &mut ctx).unwrap(), None);
+
+        let value = FlexValue::new(json!("N/A"), Source::Direct);
+        assert_eq!(
+            lenient::<Option<String>>(&value, &mut ctx).unwrap(),
+            Some("N/A".to_string())
+        );
+    }
+
+    #[test]
+    fn test_maybe_llm() {
+        let mut ctx = CoercionContext::new().with_null_sentinels(NullSentinels::common());
+
+        let value = FlexValue::new(json!(null), Source::Direct);
+        assert_eq!(
+            lenient::<Maybe<i64>>(&value, &mut ctx).unwrap(),
+            Maybe::Null
+        );
+
+        let value = FlexValue::new(json!("none"), Source::Direct);
+        assert_eq!(
+            lenient::<Maybe<i64>>(&value, &mut ctx).unwrap(),
+            Maybe::Null
+        );
+
+        let value = FlexValue::new(json!("42"), Source::Direct);
+        assert_eq!(
+            lenient::<Maybe<i64>>(&value, &mut ctx).unwrap(),
+            Maybe::Value(42)
+        );
+    }
+
+    #[test]
+    fn test_maybe_apply_to() {
+        let mut nickname = Some("Al".to_string());
+
+        Maybe::Absent.apply_to(&mut nickname);
+        assert_eq!(nickname.as_deref(), Some("Al"));
+
+        Maybe::Value("Bo".to_string()).apply_to(&mut nickname);
+        assert_eq!(nickname.as_deref(), Some("Bo"));
+
+        Maybe::Null.apply_to(&mut nickname);
+        assert_eq!(nickname, None);
+    }
+}
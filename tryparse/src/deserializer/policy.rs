@@ -0,0 +1,209 @@
+//! Per-kind rules for which coercions are acceptable.
+//!
+//! Every coercion records a [`Transformation`]. A [`CoercionPolicy`] maps
+//! transformation kinds to an action: allow (the default), penalize with a
+//! custom score, or forbid. A forbidden transformation makes the candidate
+//! being deserialized fail with [`DeserializeError::ForbiddenTransformation`],
+//! so the next candidate is tried and, if none is clean, parsing fails.
+//! When any kind is penalized, every candidate is deserialized and the one
+//! with the lowest score under the policy wins, instead of the first that
+//! deserializes.
+//!
+//! Applies to both APIs: set it on the [`ParseOptions`](crate::options::ParseOptions)
+//! given to [`crate::parse_with`] for serde types, or on the context given to
+//! [`crate::parse_llm_with_context`].
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Deserialize;
+//! use tryparse::deserializer::CoercionPolicy;
+//! use tryparse::options::ParseOptions;
+//! use tryparse::value::TransformationKind;
+//!
+//! #[derive(Deserialize, Debug)]
+//! struct Invoice {
+//!     cents: i64,
+//! }
+//!
+//! let options = ParseOptions::new()
+//!     .with_policy(CoercionPolicy::new().forbid(TransformationKind::FloatToInt));
+//!
+//! assert!(tryparse::parse_with::<Invoice>(r#"{"cents": 1999}"#, &options).is_ok());
+//! assert!(tryparse::parse_with::<Invoice>(r#"{"cents": 19.99}"#, &options).is_err());
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::{
+    error::DeserializeError,
+    value::{Transformation, TransformationKind},
+};
+
+/// What to do when a transformation of a given kind is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PolicyAction {
+    /// Accept it with its built-in penalty.
+    Allow,
+    /// Accept it, but score it with this penalty instead of the built-in one.
+    Penalize(u32),
+    /// Reject the candidate.
+    Forbid,
+}
+
+/// Per-[`TransformationKind`] rules applied during deserialization.
+///
+/// Kinds without a rule are allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoercionPolicy {
+    rules: BTreeMap<TransformationKind, PolicyAction>,
+}
+
+impl CoercionPolicy {
+    /// Creates a policy that allows everything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forbids coercions that lose or invent information:
+    /// rounding a float into an integer, wrapping a single value as an array,
+    /// splitting a string into items, resolving a repeated map or object key,
+    /// defaulting a field whose value didn't parse, skipping array items or map
+    /// entries that didn't parse, collapsing a range to one number, and
+    /// dropping a JavaScript expression.
+    pub fn lossless() -> Self {
+        Self::new()
+            .forbid(TransformationKind::FloatToInt)
+            .forbid(TransformationKind::SingleToArray)
+            .forbid(TransformationKind::SplitToArray)
+            .forbid(TransformationKind::DuplicateKey)
+            .forbid(TransformationKind::DefaultButHadUnparseableValue)
+            .forbid(TransformationKind::ArrayItemParseError)
+            .forbid(TransformationKind::MapEntryParseError)
+            .forbid(TransformationKind::RangeResolved)
+            .forbid(TransformationKind::ExpressionDropped)
+    }
+
+    /// Sets the action for `kind`, replacing any earlier rule.
+    pub fn with_rule(mut self, kind: TransformationKind, action: PolicyAction) -> Self {
+        self.rules.insert(kind, action);
+        self
+    }
+
+    /// Allows `kind` (useful to relax a preset like [`Self::lossless`]).
+    pub fn allow(self, kind: TransformationKind) -> Self {
+        self.with_rule(kind, PolicyAction::Allow)
+    }
+
+    /// Scores `kind` with `penalty` instead of its built-in penalty.
+    pub fn penalize(self, kind: TransformationKind, penalty: u32) -> Self {
+        self.with_rule(kind, PolicyAction::Penalize(penalty))
+    }
+
+    /// Forbids `kind`.
+    pub fn forbid(self, kind: TransformationKind) -> Self {
+        self.with_rule(kind, PolicyAction::Forbid)
+    }
+
+    /// Returns the action for `kind`.
+    pub fn action(&self, kind: TransformationKind) -> PolicyAction {
+        self.rules
+            .get(&kind)
+            .copied()
+            .unwrap_or(PolicyAction::Allow)
+    }
+
+    /// Returns true if no rules are set.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns true if any kind is scored with a custom penalty.
+    pub fn has_penalties(&self) -> bool {
+        self.rules
+            .values()
+            .any(|action| matches!(action, PolicyAction::Penalize(_)))
+    }
+
+    /// Returns the penalty for `transformation` under this policy.
+    pub fn penalty(&self, transformation: &Transformation) -> u32 {
+        match self.action(transformation.kind()) {
+            PolicyAction::Penalize(penalty) => penalty,
+            PolicyAction::Allow | PolicyAction::Forbid => transformation.penalty(),
+        }
+    }
+
+    /// Returns an error naming the rule if `transformation` is forbidden.
+    pub fn check(&self, transformation: &Transformation) -> Result<(), DeserializeError> {
+        match self.action(transformation.kind()) {
+            PolicyAction::Forbid => Err(DeserializeError::ForbiddenTransformation {
+                kind: transformation.kind(),
+                detail: format!("{:?}", transformation),
+            }),
+            PolicyAction::Allow | PolicyAction::Penalize(_) => Ok(()),
+        }
+    }
+
+    /// Checks every transformation, returning the first violation.
+    pub fn check_all<'a>(
+        &self,
+        transformations: impl IntoIterator<Item = &'a Transformation>,
+    ) -> Result<(), DeserializeError> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        transformations.into_iter().try_for_each(|t| self.check(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_allows_everything() {
+        let policy = CoercionPolicy::new();
+        let t = Transformation::FloatToInt { original: 1.5 };
+
+        assert_eq!(
+            policy.action(TransformationKind::FloatToInt),
+            PolicyAction::Allow
+        );
+        assert_eq!(policy.penalty(&t), t.penalty());
+        assert!(policy.check(&t).is_ok());
+    }
+
+    #[test]
+    fn test_penalize_overrides_penalty() {
+        let policy = CoercionPolicy::new().penalize(TransformationKind::StringToNumber, 40);
+        let t = Transformation::StringToNumber {
+            original: "42".to_string(),
+        };
+
+        assert_eq!(policy.penalty(&t), 40);
+        assert!(policy.check(&t).is_ok());
+        assert!(policy.has_penalties());
+        assert!(!CoercionPolicy::lossless().has_penalties());
+    }
+
+    #[test]
+    fn test_forbid_names_the_rule() {
+        let policy = CoercionPolicy::lossless();
+        let err = policy
+            .check(&Transformation::SingleToArray)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("single_to_array"), "{}", err);
+    }
+
+    #[test]
+    fn test_allow_relaxes_preset() {
+        let policy = CoercionPolicy::lossless().allow(TransformationKind::SingleToArray);
+
+        assert!(policy.check(&Transformation::SingleToArray).is_ok());
+        assert!(policy
+            .check_all(&[Transformation::FloatToInt { original: 2.5 }])
+            .is_err());
+    }
+}
@@ -6,6 +6,7 @@
 //! - Fractions: "1/2" → 0.5
 //! - Currency symbols: "¥1,234" → 1234.0
 //! - String-to-number coercion
+//! - Suffixes, number words and ranges: "1.2k", "twenty-three", "10 to 20"
 //! - Array unwrapping: [42] → 42
 
 use once_cell::sync::Lazy;
@@ -13,8 +14,15 @@ use regex::Regex;
 use serde_json::Value;
 
 use crate::{
-    deserializer::traits::{CoercionContext, LlmDeserialize},
+    deserializer::{
+        list_split::split_list,
+        locale::{parse_localized_number, NumberLocale},
+        map_entries::{entries_from_array, ArrayEntries, MapEntryPolicy},
+        natural_numbers::parse_natural_number,
+        traits::{CoercionContext, LlmDeserialize},
+    },
     error::{DeserializeError, ParseError, Result},
+    parser::DuplicateKeyPolicy,
     value::{FlexValue, Transformation},
 };
 
@@ -31,19 +39,18 @@ impl LlmDeserialize for i64 {
         }
     }
 
-    fn deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Result<Self> {
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
         match &value.value {
             // Direct number
             Value::Number(n) => {
                 if let Some(i) = n.as_i64() {
                     Ok(i)
                 } else if let Some(u) = n.as_u64() {
-                    Ok(u as i64)
+                    Ok(i64::try_from(u).map_err(|_| DeserializeError::out_of_range(u, "i64"))?)
                 } else if let Some(f) = n.as_f64() {
                     // BAML ALGORITHM: Float to int with flag
-                    let mut v = value.clone();
-                    v.add_transformation(Transformation::FloatToInt { original: f });
-                    Ok(f.round() as i64)
+                    ctx.add_transformation(Transformation::FloatToInt { original: f });
+                    Ok(float_to_i64(f.round())?)
                 } else {
                     Err(ParseError::DeserializeFailed(
                         DeserializeError::TypeMismatch {
@@ -60,26 +67,37 @@ impl LlmDeserialize for i64 {
 
                 // Try direct parsing
                 if let Ok(n) = s.parse::<i64>() {
+                    ctx.add_transformation(Transformation::StringToNumber {
+                        original: s.to_string(),
+                    });
                     Ok(n)
                 } else if let Ok(n) = s.parse::<u64>() {
-                    Ok(n as i64)
-                } else if let Ok(f) = s.parse::<f64>() {
+                    ctx.add_transformation(Transformation::StringToNumber {
+                        original: s.to_string(),
+                    });
+                    Ok(i64::try_from(n).map_err(|_| DeserializeError::out_of_range(n, "i64"))?)
+                } else if let Some(f) = parse_plain_float(s, ctx) {
                     // BAML ALGORITHM: Parse as float, then convert
-                    let mut v = value.clone();
-                    v.add_transformation(Transformation::FloatToInt { original: f });
-                    Ok(f.round() as i64)
+                    ctx.add_transformation(Transformation::FloatToInt { original: f });
+                    Ok(float_to_i64(f.round())?)
                 }
                 // BAML ALGORITHM: Parse fractions "1/2" → 0.5 → 0
                 else if let Some(f) = parse_fraction(s) {
-                    let mut v = value.clone();
-                    v.add_transformation(Transformation::FloatToInt { original: f });
-                    Ok(f.round() as i64)
+                    ctx.add_transformation(Transformation::FloatToInt { original: f });
+                    Ok(float_to_i64(f.round())?)
+                }
+                // Natural-language numbers: "1.2k", "twenty-three", "~40", "10 to 20"
+                else if let Some(f) = natural_number(s, ctx)? {
+                    if f.fract() != 0.0 {
+                        ctx.add_transformation(Transformation::FloatToInt { original: f });
+                    }
+                    Ok(float_to_i64(f.round())?)
                 }
                 // BAML ALGORITHM: Parse comma-separated "$1,234.56" → 1234
-                else if let Some(f) = parse_comma_separated_number(s) {
-                    let mut v = value.clone();
-                    v.add_transformation(Transformation::FloatToInt { original: f });
-                    Ok(f.round() as i64)
+                // ("1.234,56" and "١٢٣" too, per the context's locale)
+                else if let Some(f) = localized_number(s, ctx) {
+                    ctx.add_transformation(Transformation::FloatToInt { original: f });
+                    Ok(float_to_i64(f.round())?)
                 } else {
                     Err(ParseError::DeserializeFailed(
                         DeserializeError::TypeMismatch {
@@ -92,11 +110,10 @@ impl LlmDeserialize for i64 {
 
             // BAML ALGORITHM: Array unwrapping [42] → 42
             Value::Array(items) if items.len() == 1 => {
-                let mut v = value.clone();
-                v.add_transformation(Transformation::SingleToArray);
+                ctx.add_transformation(Transformation::SingleToArray);
 
                 let inner = FlexValue::new(items[0].clone(), value.source.clone());
-                Self::deserialize(&inner, _ctx)
+                Self::deserialize(&inner, ctx)
             }
 
             _ => Err(ParseError::DeserializeFailed(
@@ -107,6 +124,96 @@ impl LlmDeserialize for i64 {
             )),
         }
     }
+
+    fn is_scalar() -> bool {
+        true
+    }
+}
+
+// ================================================================================================
+// i128 / u128 Implementations
+// ================================================================================================
+
+impl LlmDeserialize for i128 {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        exact_integer(value)
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        // ±2^127
+        deserialize_wide_integer(value, ctx, "i128", |f| {
+            (-1.7014118346046923e38..1.7014118346046923e38)
+                .contains(&f)
+                .then_some(f as i128)
+        })
+    }
+
+    fn is_scalar() -> bool {
+        true
+    }
+}
+
+impl LlmDeserialize for u128 {
+    fn try_deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Option<Self> {
+        exact_integer(value)
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        // 2^128
+        deserialize_wide_integer(value, ctx, "u128", |f| {
+            (0.0..3.402823669209385e38)
+                .contains(&f)
+                .then_some(f as u128)
+        })
+    }
+
+    fn is_scalar() -> bool {
+        true
+    }
+}
+
+/// Reads an integer from the number's digits, so nothing passes through f64.
+///
+/// Numbers beyond `u64` only keep their digits with the `arbitrary_precision` feature.
+fn exact_integer<T: std::str::FromStr>(value: &FlexValue) -> Option<T> {
+    match &value.value {
+        Value::Number(n) => n.to_string().parse().ok(),
+        _ => None,
+    }
+}
+
+fn deserialize_wide_integer<T: std::str::FromStr>(
+    value: &FlexValue,
+    ctx: &mut CoercionContext,
+    target: &'static str,
+    from_float: fn(f64) -> Option<T>,
+) -> Result<T> {
+    if let Some(n) = exact_integer(value) {
+        return Ok(n);
+    }
+
+    if let Value::String(s) = &value.value {
+        let trimmed = s.trim().trim_end_matches(',');
+        if let Ok(n) = trimmed.parse() {
+            ctx.add_transformation(Transformation::StringToNumber {
+                original: s.clone(),
+            });
+            return Ok(n);
+        }
+    }
+
+    // Floats, "1.2k", "$1,234", [42], ... go through the f64 coercions
+    let f = f64::deserialize(value, ctx).map_err(|_| {
+        ParseError::DeserializeFailed(DeserializeError::TypeMismatch {
+            expected: "integer",
+            found: value_type_name(&value.value),
+        })
+    })?;
+    let rounded = f.round();
+    if rounded != f {
+        ctx.add_transformation(Transformation::FloatToInt { original: f });
+    }
+    from_float(rounded).ok_or_else(|| DeserializeError::out_of_range(f, target).into())
 }
 
 // ================================================================================================
@@ -122,7 +229,7 @@ impl LlmDeserialize for f64 {
         }
     }
 
-    fn deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Result<Self> {
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
         match &value.value {
             // Direct number
             Value::Number(n) => {
@@ -147,7 +254,10 @@ impl LlmDeserialize for f64 {
                 let s = s.trim().trim_end_matches(','); // BAML trims trailing commas
 
                 // Try direct parsing
-                if let Ok(f) = s.parse::<f64>() {
+                if let Some(f) = parse_plain_float(s, ctx) {
+                    ctx.add_transformation(Transformation::StringToNumber {
+                        original: s.to_string(),
+                    });
                     Ok(f)
                 } else if let Ok(i) = s.parse::<i64>() {
                     Ok(i as f64)
@@ -158,13 +268,17 @@ impl LlmDeserialize for f64 {
                 else if let Some(f) = parse_fraction(s) {
                     Ok(f)
                 }
+                // Natural-language numbers: "1.2k", "twenty-three", "~40", "10 to 20"
+                else if let Some(f) = natural_number(s, ctx)? {
+                    Ok(f)
+                }
                 // BAML ALGORITHM: Parse comma-separated "$1,234.56" → 1234.56
-                else if let Some(f) = parse_comma_separated_number(s) {
+                // ("1.234,56" and "١٢٣" too, per the context's locale)
+                else if let Some(f) = localized_number(s, ctx) {
                     // BAML adds a flag here to penalize strings like
                     // "1 cup unsalted butter, room temperature"
                     // This helps unions like "float | string" choose correctly
-                    let mut v = value.clone();
-                    v.add_transformation(Transformation::StringToNumber {
+                    ctx.add_transformation(Transformation::StringToNumber {
                         original: s.to_string(),
                     });
                     Ok(f)
@@ -180,11 +294,10 @@ impl LlmDeserialize for f64 {
 
             // BAML ALGORITHM: Array unwrapping [42.5] → 42.5
             Value::Array(items) if items.len() == 1 => {
-                let mut v = value.clone();
-                v.add_transformation(Transformation::SingleToArray);
+                ctx.add_transformation(Transformation::SingleToArray);
 
                 let inner = FlexValue::new(items[0].clone(), value.source.clone());
-                Self::deserialize(&inner, _ctx)
+                Self::deserialize(&inner, ctx)
             }
 
             _ => Err(ParseError::DeserializeFailed(
@@ -195,6 +308,10 @@ impl LlmDeserialize for f64 {
             )),
         }
     }
+
+    fn is_scalar() -> bool {
+        true
+    }
 }
 
 // ================================================================================================
@@ -210,7 +327,7 @@ impl LlmDeserialize for bool {
         }
     }
 
-    fn deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Result<Self> {
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
         match &value.value {
             Value::Bool(b) => Ok(*b),
 
@@ -244,11 +361,10 @@ impl LlmDeserialize for bool {
 
             // BAML ALGORITHM: Array unwrapping [true] → true
             Value::Array(items) if items.len() == 1 => {
-                let mut v = value.clone();
-                v.add_transformation(Transformation::SingleToArray);
+                ctx.add_transformation(Transformation::SingleToArray);
 
                 let inner = FlexValue::new(items[0].clone(), value.source.clone());
-                Self::deserialize(&inner, _ctx)
+                Self::deserialize(&inner, ctx)
             }
 
             _ => Err(ParseError::DeserializeFailed(
@@ -259,6 +375,10 @@ impl LlmDeserialize for bool {
             )),
         }
     }
+
+    fn is_scalar() -> bool {
+        true
+    }
 }
 
 // ================================================================================================
@@ -288,6 +408,10 @@ impl LlmDeserialize for String {
             }
         }
     }
+
+    fn is_scalar() -> bool {
+        true
+    }
 }
 
 // ================================================================================================
@@ -331,7 +455,25 @@ static CURRENCY_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"\p{Sc}").expect("Invalid currency regex pattern"));
 
 /// Port from: engine/baml-lib/jsonish/src/deserializer/coercer/coerce_primitive.rs:256-272
-pub(crate) fn parse_comma_separated_number(s: &str) -> Option<f64> {
+pub(crate) fn parse_comma_separated_number(s: &str, locale: NumberLocale) -> Option<f64> {
+    parse_localized_number(s, locale, extract_single_number)?
+        .digits
+        .parse::<f64>()
+        .ok()
+}
+
+/// Extracts the single number in `s` per the context's [`NumberLocale`],
+/// recording normalized digits and ambiguous separators in `ctx`.
+pub(crate) fn localized_digits(s: &str, ctx: &mut CoercionContext) -> Option<String> {
+    let number = parse_localized_number(s, ctx.number_locale(), extract_single_number)?;
+    for transformation in number.transformations {
+        ctx.add_transformation(transformation);
+    }
+    Some(number.digits)
+}
+
+/// English-style search for the one number in `s` ("1 cup, sifted" → "1").
+pub(crate) fn extract_single_number(s: &str) -> Option<String> {
     // Regex pattern from BAML:
     // - Optional sign: ([-+]?)
     // - Optional currency: \$?
@@ -356,9 +498,52 @@ pub(crate) fn parse_comma_separated_number(s: &str) -> Option<f64> {
 
     // BAML ALGORITHM: Remove percentage sign
     // NOTE: We do NOT divide by 100 - BAML keeps "50%" as 50.0, not 0.5
-    let without_percent = without_currency.trim_end_matches('%');
+    Some(without_currency.trim_end_matches('%').to_string())
+}
 
-    without_percent.parse::<f64>().ok()
+/// Parses `s` with Rust's float syntax, unless the context's locale uses `.`
+/// for grouping (then "1.234" is one thousand two hundred thirty-four).
+fn parse_plain_float(s: &str, ctx: &CoercionContext) -> Option<f64> {
+    if s.contains('.') && !ctx.number_locale().reads_point_as_decimal() {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Locale-aware version of [`parse_comma_separated_number`] that records what it did in `ctx`.
+fn localized_number(s: &str, ctx: &mut CoercionContext) -> Option<f64> {
+    localized_digits(s, ctx)?.parse().ok()
+}
+
+/// Parses suffixed numbers, number words, approximate markers and ranges,
+/// recording what was done in `ctx`.
+fn natural_number(s: &str, ctx: &mut CoercionContext) -> Result<Option<f64>> {
+    let parsed = parse_natural_number(s, ctx.range_policy(), ctx.number_locale())
+        .map_err(ParseError::DeserializeFailed)?;
+    Ok(parsed.map(|number| {
+        for transformation in number.transformations {
+            ctx.add_transformation(transformation);
+        }
+        number.value
+    }))
+}
+
+/// Truncates `f` to an `i64`, rejecting NaN, infinities and out-of-range values
+/// instead of saturating like `as` does. Round first if that's what you want.
+pub(crate) fn float_to_i64(f: f64) -> std::result::Result<i64, DeserializeError> {
+    // 2^63 is exactly representable; i64::MAX is not
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    if (-LIMIT..LIMIT).contains(&f) {
+        Ok(f as i64)
+    } else {
+        Err(DeserializeError::out_of_range(f, "i64"))
+    }
+}
+
+/// Truncates `f` to an `i128`, or `None` for NaN and infinities. Large
+/// values saturate, which puts them outside every narrower type's range.
+pub(crate) fn float_to_i128(f: f64) -> Option<i128> {
+    f.is_finite().then_some(f as i128)
 }
 
 /// Get a human-readable type name for error messages.
@@ -398,8 +583,28 @@ impl<T: LlmDeserialize> LlmDeserialize for Vec<T> {
 
     fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
         match &value.value {
+            Value::Array(arr) if ctx.skip_bad_items() => {
+                // BAML ALGORITHM: Deserialize each item, collect successes, track errors
+                let mut items = Vec::with_capacity(arr.len());
+                for (index, item) in arr.iter().enumerate() {
+                    let flex = FlexValue::new(item.clone(), value.source.clone());
+                    // A dropped item's partial transformations must not reach the result
+                    let mut scratch = ctx.clone();
+                    match T::deserialize(&flex, &mut scratch) {
+                        Ok(item) => {
+                            *ctx = scratch;
+                            items.push(item);
+                        }
+                        Err(e) => ctx.add_transformation(Transformation::ArrayItemParseError {
+                            index,
+                            error: e.to_string(),
+                        }),
+                    }
+                }
+                Ok(items)
+            }
             Value::Array(arr) => {
-                // BAML ALGORITHM: Deserialize each item, collect successes
+                // Every item must coerce
                 let items: Result<Vec<T>> = arr
                     .iter()
                     .map(|item| {
@@ -409,9 +614,18 @@ impl<T: LlmDeserialize> LlmDeserialize for Vec<T> {
                     .collect();
                 items
             }
+            Value::String(s) if T::is_scalar() => match split_items(s, value, ctx) {
+                Some(items) => Ok(items),
+                None => {
+                    let item = T::deserialize(value, ctx)?;
+                    ctx.add_transformation(Transformation::SingleToArray);
+                    Ok(vec![item])
+                }
+            },
             _ => {
                 // BAML ALGORITHM: Single value to array
                 let item = T::deserialize(value, ctx)?;
+                ctx.add_transformation(Transformation::SingleToArray);
                 Ok(vec![item])
             }
         }
@@ -422,6 +636,59 @@ impl<T: LlmDeserialize> LlmDeserialize for Vec<T> {
     }
 }
 
+/// Splits `"a, b and c"` or a markdown list into items, if every item coerces to `T`.
+fn split_items<T: LlmDeserialize>(
+    s: &str,
+    value: &FlexValue,
+    ctx: &mut CoercionContext,
+) -> Option<Vec<T>> {
+    let (parts, separator) = split_list(s)?;
+    let mut scratch = ctx.clone();
+    let items = parts
+        .into_iter()
+        .map(|part| {
+            let flex = FlexValue::new(Value::String(part), value.source.clone());
+            T::deserialize(&flex, &mut scratch).ok()
+        })
+        .collect::<Option<Vec<T>>>()?;
+
+    scratch.add_transformation(Transformation::SplitToArray {
+        original: s.to_string(),
+        separator: separator.name().to_string(),
+    });
+    *ctx = scratch;
+    Some(items)
+}
+
+// ================================================================================================
+// Option<T>
+// ================================================================================================
+
+impl<T: LlmDeserialize> LlmDeserialize for Option<T> {
+    fn try_deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Option<Self> {
+        match &value.value {
+            Value::Null => Some(None),
+            // "N/A" is a coercion; leave it to the lenient pass
+            Value::String(s) if ctx.null_sentinels().matches(s) => None,
+            _ => T::try_deserialize(value, ctx).map(Some),
+        }
+    }
+
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        match &value.value {
+            Value::Null => Ok(None),
+            // "N/A", "none", "-", ...
+            Value::String(s) if ctx.null_sentinels().matches(s) => {
+                ctx.add_transformation(Transformation::NullSentinel {
+                    original: s.clone(),
+                });
+                Ok(None)
+            }
+            _ => T::deserialize(value, ctx).map(Some),
+        }
+    }
+}
+
 // ================================================================================================
 // HashMap<K, V>
 // ================================================================================================
@@ -434,74 +701,149 @@ where
     V: LlmDeserialize,
 {
     fn try_deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Option<Self> {
-        // Strict: Must be an object, all keys and values must deserialize strictly
-        match &value.value {
-            Value::Object(obj) => {
-                let mut map = HashMap::new();
-
-                for (key_str, val) in obj.iter() {
-                    // Deserialize key from string
-                    let key_value =
-                        FlexValue::new(Value::String(key_str.clone()), value.source.clone());
-                    let key = K::try_deserialize(&key_value, ctx)?;
+        try_deserialize_object(value, ctx)
+    }
 
-                    // Deserialize value
-                    let value_flex = FlexValue::new(val.clone(), value.source.clone());
-                    let value_result = V::try_deserialize(&value_flex, ctx)?;
+    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+        deserialize_object(value, ctx)
+    }
 
-                    map.insert(key, value_result);
-                }
+    fn type_name() -> &'static str {
+        "HashMap<K, V>"
+    }
+}
 
-                Some(map)
+/// Strictly deserializes an object into any map type.
+///
+/// Shared by every map impl (`HashMap`, `IndexMap`, ...) so they coerce identically.
+pub(crate) fn try_deserialize_object<K, V, M>(
+    value: &FlexValue,
+    ctx: &mut CoercionContext,
+) -> Option<M>
+where
+    K: LlmDeserialize,
+    V: LlmDeserialize,
+    M: Default + Extend<(K, V)>,
+{
+    // Strict: Must be an object, all keys and values must deserialize strictly
+    match &value.value {
+        Value::Object(obj) => {
+            let mut map = M::default();
+
+            for (key_str, val) in obj.iter() {
+                // Deserialize key from string
+                let key_value =
+                    FlexValue::new(Value::String(key_str.clone()), value.source.clone());
+                let key = K::try_deserialize(&key_value, ctx)?;
+
+                // Deserialize value
+                let value_flex = FlexValue::new(val.clone(), value.source.clone());
+                let value_result = V::try_deserialize(&value_flex, ctx)?;
+
+                map.extend(std::iter::once((key, value_result)));
             }
-            _ => None,
+
+            Some(map)
         }
+        _ => None,
     }
+}
 
-    fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
-        match &value.value {
-            Value::Object(obj) => {
-                let mut map = HashMap::new();
-
-                // BAML ALGORITHM: Deserialize each entry, track errors but continue
-                for (key_str, val) in obj.iter() {
-                    // Deserialize key from string
-                    let key_value =
-                        FlexValue::new(Value::String(key_str.clone()), value.source.clone());
-                    let key = match K::deserialize(&key_value, ctx) {
-                        Ok(k) => k,
-                        Err(_e) => {
-                            // TODO: Track key deserialization error
-                            // For now, skip this entry
-                            continue;
-                        }
-                    };
-
-                    // Deserialize value
-                    let value_flex = FlexValue::new(val.clone(), value.source.clone());
-                    let value_result = match V::deserialize(&value_flex, ctx) {
-                        Ok(v) => v,
-                        Err(_e) => {
-                            // TODO: Track value deserialization error
-                            // For now, skip this entry
-                            continue;
-                        }
-                    };
+/// Leniently deserializes an object into any map type.
+pub(crate) fn deserialize_object<K, V, M>(value: &FlexValue, ctx: &mut CoercionContext) -> Result<M>
+where
+    K: LlmDeserialize,
+    V: LlmDeserialize,
+    M: Default + Extend<(K, V)>,
+{
+    match &value.value {
+        Value::Object(obj) => deserialize_entries(obj.iter(), value, ctx),
+        Value::Array(arr) => {
+            // [["a", 1]], [{"key": "a", "value": 1}] or [{"a": 1}]
+            let ArrayEntries {
+                entries,
+                shape,
+                duplicates,
+            } = entries_from_array(arr).ok_or_else(|| {
+                ParseError::DeserializeFailed(DeserializeError::type_mismatch("object", "array"))
+            })?;
+
+            ctx.add_transformation(Transformation::ArrayToMap {
+                shape: shape.name().to_string(),
+            });
+            for key in duplicates {
+                ctx.add_transformation(Transformation::DuplicateKey {
+                    path: key,
+                    policy: DuplicateKeyPolicy::LastWins.name().to_string(),
+                });
+            }
+            deserialize_entries(entries.iter().map(|(k, v)| (k, v)), value, ctx)
+        }
+        _ => Err(ParseError::DeserializeFailed(
+            DeserializeError::type_mismatch("object", "non-object"),
+        )),
+    }
+}
 
-                    map.insert(key, value_result);
+fn deserialize_entries<'a, K, V, M>(
+    entries: impl Iterator<Item = (&'a String, &'a Value)>,
+    value: &FlexValue,
+    ctx: &mut CoercionContext,
+) -> Result<M>
+where
+    K: LlmDeserialize,
+    V: LlmDeserialize,
+    M: Default + Extend<(K, V)>,
+{
+    let policy = ctx.map_entry_policy();
+    let mut map = M::default();
+
+    // BAML ALGORITHM: Deserialize each entry; the policy decides what a failure costs
+    for (key_str, val) in entries {
+        let key_value = FlexValue::new(Value::String(key_str.clone()), value.source.clone());
+        let value_flex = FlexValue::new(val.clone(), value.source.clone());
+
+        // A failed entry's transformations are dropped with it
+        let mut scratch = ctx.clone();
+        let (key, error) = match K::deserialize(&key_value, &mut scratch) {
+            Ok(key) => match V::deserialize(&value_flex, &mut scratch) {
+                Ok(v) => {
+                    *ctx = scratch;
+                    map.extend(std::iter::once((key, v)));
+                    continue;
                 }
+                Err(e) => (Some(key), e),
+            },
+            Err(e) => (None, e),
+        };
+        if policy == MapEntryPolicy::Fail {
+            return Err(error);
+        }
 
-                Ok(map)
+        // Only a bad value can be kept raw; the key is already text. The raw
+        // text is taken strictly, so it's kept as is or not at all
+        let raw = val.to_string();
+        let kept = match key {
+            Some(key) if policy == MapEntryPolicy::KeepRaw => {
+                let raw_flex = FlexValue::new(Value::String(raw.clone()), value.source.clone());
+                let mut scratch = ctx.clone();
+                V::try_deserialize(&raw_flex, &mut scratch).map(|v| {
+                    *ctx = scratch;
+                    (key, v)
+                })
             }
-            _ => Err(ParseError::DeserializeFailed(
-                DeserializeError::type_mismatch("object", "non-object"),
-            )),
-        }
+            _ => None,
+        };
+        ctx.add_transformation(Transformation::MapEntryParseError {
+            key: key_str.clone(),
+            error: error.to_string(),
+            raw,
+            kept_raw: kept.is_some(),
+        });
+        map.extend(kept);
     }
 
-    fn type_name() -> &'static str {
-        "HashMap<K, V>"
-    }
+    Ok(map)
 }
 
 // Force this module to be linked when the library is compiled
@@ -518,7 +860,10 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::value::Source;
+    use crate::{
+        deserializer::{NumberLocale, RangePolicy},
+        value::Source,
+    };
 
     #[test]
     fn test_parse_fraction() {
@@ -532,14 +877,26 @@ mod tests {
 
     #[test]
     fn test_parse_comma_separated() {
-        assert_eq!(parse_comma_separated_number("1,234"), Some(1234.0));
-        assert_eq!(parse_comma_separated_number("1,234.56"), Some(1234.56));
-        assert_eq!(parse_comma_separated_number("$1,234.56"), Some(1234.56));
         assert_eq!(
-            parse_comma_separated_number("1,234,567.89"),
+            parse_comma_separated_number("1,234", NumberLocale::Auto),
+            Some(1234.0)
+        );
+        assert_eq!(
+            parse_comma_separated_number("1,234.56", NumberLocale::Auto),
+            Some(1234.56)
+        );
+        assert_eq!(
+            parse_comma_separated_number("$1,234.56", NumberLocale::Auto),
+            Some(1234.56)
+        );
+        assert_eq!(
+            parse_comma_separated_number("1,234,567.89", NumberLocale::Auto),
             Some(1234567.89)
         );
-        assert_eq!(parse_comma_separated_number("1.23e5"), Some(123000.0));
+        assert_eq!(
+            parse_comma_separated_number("1.23e5", NumberLocale::Auto),
+            Some(123000.0)
+        );
     }
 
     #[test]
@@ -593,6 +950,63 @@ mod tests {
         assert_eq!(i64::deserialize(&value, &mut ctx).unwrap(), 42);
     }
 
+    #[test]
+    fn test_i64_out_of_range() {
+        let mut ctx = CoercionContext::new();
+
+        for raw in [json!(u64::MAX), json!(1e30), json!("1e30"), json!("NaN")] {
+            let value = FlexValue::new(raw.clone(), Source::Direct);
+            let err = i64::deserialize(&value, &mut ctx).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    ParseError::DeserializeFailed(DeserializeError::NumberOutOfRange {
+                        target: "i64",
+                        ..
+                    })
+                ),
+                "{}: {:?}",
+                raw,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_float_to_i64_bounds() {
+        assert_eq!(
+            float_to_i64(-9_223_372_036_854_775_808.0).unwrap(),
+            i64::MIN
+        );
+        assert_eq!(float_to_i64(42.9).unwrap(), 42);
+        assert!(float_to_i64(9_223_372_036_854_775_808.0).is_err());
+        assert!(float_to_i64(f64::INFINITY).is_err());
+        assert!(float_to_i64(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_u128_and_i128() {
+        let mut ctx = CoercionContext::new();
+
+        let value = FlexValue::new(json!(u64::MAX), Source::Direct);
+        assert_eq!(
+            u128::deserialize(&value, &mut ctx).unwrap(),
+            u64::MAX as u128
+        );
+
+        let value = FlexValue::new(json!("123456789012345678901234567890"), Source::Direct);
+        assert_eq!(
+            u128::deserialize(&value, &mut ctx).unwrap(),
+            123_456_789_012_345_678_901_234_567_890
+        );
+
+        let value = FlexValue::new(json!("-2.5k"), Source::Direct);
+        assert_eq!(i128::deserialize(&value, &mut ctx).unwrap(), -2500);
+
+        let value = FlexValue::new(json!(-1), Source::Direct);
+        assert!(u128::deserialize(&value, &mut ctx).is_err());
+    }
+
     #[test]
     fn test_f64_from_comma_separated() {
         let mut ctx = CoercionContext::new();
@@ -600,6 +1014,71 @@ mod tests {
         assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 1234.56);
     }
 
+    #[test]
+    fn test_i64_from_natural_language() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!("twenty-three"), Source::Direct);
+        assert_eq!(i64::deserialize(&value, &mut ctx).unwrap(), 23);
+
+        let value = FlexValue::new(json!("1.2k"), Source::Direct);
+        assert_eq!(i64::deserialize(&value, &mut ctx).unwrap(), 1200);
+
+        let value = FlexValue::new(json!("~40"), Source::Direct);
+        assert_eq!(i64::deserialize(&value, &mut ctx).unwrap(), 40);
+
+        assert!(matches!(
+            ctx.transformations(),
+            [
+                Transformation::NumberWords { .. },
+                Transformation::MagnitudeSuffix { .. },
+                Transformation::ApproximateValue { .. },
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_f64_range_policy() {
+        let value = FlexValue::new(json!("between 10 and 20"), Source::Direct);
+
+        let mut ctx = CoercionContext::new();
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 15.0);
+
+        let mut ctx = CoercionContext::new().with_range_policy(RangePolicy::High);
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 20.0);
+
+        let mut ctx = CoercionContext::new().with_range_policy(RangePolicy::Reject);
+        assert!(f64::deserialize(&value, &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_f64_number_locale() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!("1.234,56"), Source::Direct);
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 1234.56);
+
+        let value = FlexValue::new(json!("٣٫٥"), Source::Direct);
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 3.5);
+
+        let mut ctx = CoercionContext::new().with_number_locale(NumberLocale::DecimalComma);
+        let value = FlexValue::new(json!("1.234"), Source::Direct);
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 1234.0);
+        assert_eq!(i64::deserialize(&value, &mut ctx).unwrap(), 1234);
+
+        let value = FlexValue::new(json!("2,5"), Source::Direct);
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn test_ambiguous_number_recorded() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!("€1,234"), Source::Direct);
+        assert_eq!(f64::deserialize(&value, &mut ctx).unwrap(), 1234.0);
+        assert!(matches!(
+            ctx.transformations(),
+            [Transformation::AmbiguousNumberFormat { .. }]
+        ));
+    }
+
     #[test]
     fn test_bool_from_string() {
         let mut ctx = CoercionContext::new();
@@ -640,6 +1119,83 @@ mod tests {
         assert_eq!(i64::try_deserialize(&value, &mut ctx), None);
     }
 
+    #[test]
+    fn test_vec_splits_delimited_string() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!("rust, go, and zig"), Source::Direct);
+
+        let langs = Vec::<String>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(langs, vec!["rust", "go", "zig"]);
+        assert!(matches!(
+            ctx.transformations(),
+            [Transformation::SplitToArray { separator, .. }] if separator == "comma"
+        ));
+
+        let value = FlexValue::new(json!("1. 10\n2. 20"), Source::Direct);
+        assert_eq!(
+            Vec::<i64>::deserialize(&value, &mut CoercionContext::new()).unwrap(),
+            vec![10, 20]
+        );
+    }
+
+    #[test]
+    fn test_vec_skip_bad_items() {
+        let value = FlexValue::new(json!([1, "lots", 3, {"n": 4}]), Source::Direct);
+        assert!(Vec::<i64>::deserialize(&value, &mut CoercionContext::new()).is_err());
+
+        let mut ctx = CoercionContext::new().with_skip_bad_items(true);
+        let items = Vec::<i64>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(items, vec![1, 3]);
+        let skipped: Vec<_> = ctx
+            .transformations()
+            .iter()
+            .filter_map(|t| match t {
+                Transformation::ArrayItemParseError { index, .. } => Some(*index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(skipped, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_vec_skip_bad_items_drops_their_transformations() {
+        // The second map coerces "2" before failing on "x"
+        let value = FlexValue::new(json!([{"a": 1}, {"b": "2", "c": "x"}]), Source::Direct);
+        let mut ctx = CoercionContext::new()
+            .with_skip_bad_items(true)
+            .with_map_entry_policy(MapEntryPolicy::Fail);
+
+        let items = Vec::<HashMap<String, i64>>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            ctx.transformations(),
+            [Transformation::ArrayItemParseError { index: 1, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_vec_split_falls_back_to_single() {
+        // "b" isn't a number, so the string isn't split
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!("1 and b"), Source::Direct);
+        let _ = Vec::<i64>::deserialize(&value, &mut ctx);
+        assert!(!ctx
+            .transformations()
+            .iter()
+            .any(|t| matches!(t, Transformation::SplitToArray { .. })));
+
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!("1,234"), Source::Direct);
+        assert_eq!(
+            Vec::<i64>::deserialize(&value, &mut ctx).unwrap(),
+            vec![1234]
+        );
+        assert!(matches!(
+            ctx.transformations().last(),
+            Some(Transformation::SingleToArray)
+        ));
+    }
+
     // ===== HashMap Tests =====
 
     #[test]
@@ -742,6 +1298,126 @@ mod tests {
         assert_eq!(map.len(), 0);
     }
 
+    #[test]
+    fn test_hashmap_from_entry_array() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(
+            json!([{"key": "one", "value": "1"}, {"key": "two", "value": 2}]),
+            Source::Direct,
+        );
+
+        let map = HashMap::<String, i64>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(map.get("one"), Some(&1));
+        assert_eq!(map.get("two"), Some(&2));
+        assert!(ctx.transformations().iter().any(
+            |t| matches!(t, Transformation::ArrayToMap { shape } if shape == "key_value_objects")
+        ));
+        assert_eq!(
+            HashMap::<String, i64>::try_deserialize(&value, &mut CoercionContext::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hashmap_duplicate_keys() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!([["a", 1], ["a", 2]]), Source::Direct);
+
+        let map = HashMap::<String, i64>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(map.get("a"), Some(&2));
+        assert!(ctx
+            .transformations()
+            .iter()
+            .any(|t| matches!(t, Transformation::DuplicateKey { path, .. } if path == "a")));
+
+        let value = FlexValue::new(json!([1, 2]), Source::Direct);
+        assert!(HashMap::<String, i64>::deserialize(&value, &mut ctx).is_err());
+    }
+
+    #[test]
+    fn test_hashmap_records_skipped_entries() {
+        let mut ctx = CoercionContext::new();
+        let value = FlexValue::new(json!({"one": 1, "two": {"n": "x"}}), Source::Direct);
+
+        let map = HashMap::<String, bool>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(map.len(), 1);
+        assert!(ctx.transformations().iter().any(|t| matches!(
+            t,
+            Transformation::MapEntryParseError { key, raw, kept_raw: false, .. }
+                if key == "two" && raw == r#"{"n":"x"}"#
+        )));
+    }
+
+    #[test]
+    fn test_hashmap_entry_policy() {
+        let value = FlexValue::new(json!({"one": 1, "two": {"count": 2}}), Source::Direct);
+
+        let mut ctx = CoercionContext::new().with_map_entry_policy(MapEntryPolicy::Fail);
+        assert!(HashMap::<String, i64>::deserialize(&value, &mut ctx).is_err());
+
+        // The raw text "{\"count\":2}" isn't a number, so it's not coerced into one
+        let mut ctx = CoercionContext::new().with_map_entry_policy(MapEntryPolicy::KeepRaw);
+        let map = HashMap::<String, i64>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(map.get("two"), None);
+        assert!(ctx.transformations().iter().any(|t| matches!(
+            t,
+            Transformation::MapEntryParseError {
+                kept_raw: false,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_hashmap_failed_entries_drop_their_transformations() {
+        // The second entry coerces "2" before failing on "x"
+        let value = FlexValue::new(json!({"a": [1], "b": ["2", "x"]}), Source::Direct);
+        let mut ctx = CoercionContext::new().with_map_entry_policy(MapEntryPolicy::Skip);
+
+        let map = HashMap::<String, Vec<i64>>::deserialize(&value, &mut ctx).unwrap();
+        assert_eq!(map.len(), 1);
+        assert!(matches!(
+            ctx.transformations(),
+            [Transformation::MapEntryParseError {
+                kept_raw: false,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_hashmap_keep_raw_keeps_text_untouched() {
+        /// Only takes strings, so objects fail and their raw text is kept.
+        #[derive(Debug, PartialEq)]
+        struct Note(String);
+
+        impl LlmDeserialize for Note {
+            fn try_deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Option<Self> {
+                String::try_deserialize(value, ctx).map(Note)
+            }
+
+            fn deserialize(value: &FlexValue, ctx: &mut CoercionContext) -> Result<Self> {
+                Self::try_deserialize(value, ctx).ok_or_else(|| {
+                    ParseError::DeserializeFailed(DeserializeError::type_mismatch(
+                        "string",
+                        value_type_name(&value.value),
+                    ))
+                })
+            }
+        }
+
+        let value = FlexValue::new(json!({"a": "hi", "b": {"count": 2}}), Source::Direct);
+        let mut ctx = CoercionContext::new().with_map_entry_policy(MapEntryPolicy::KeepRaw);
+        let map = HashMap::<String, Note>::deserialize(&value, &mut ctx).unwrap();
+
+        assert_eq!(map["a"], Note("hi".to_string()));
+        assert_eq!(map["b"], Note(r#"{"count":2}"#.to_string()));
+        assert!(matches!(
+            ctx.transformations(),
+            [Transformation::MapEntryParseError { kept_raw: true, .. }]
+        ));
+    }
+
     #[test]
     fn test_hashmap_with_invalid_value() {
         let mut ctx = CoercionContext::new();
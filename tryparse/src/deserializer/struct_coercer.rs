@@ -104,7 +104,9 @@ impl FieldMatcher {
             // Key variations match expected
             key_camel == self.expected || key_snake == self.expected ||
             // Key variations match expected variations
-            key_camel == expected_camel || key_snake == expected_snake
+            key_camel == expected_camel || key_snake == expected_snake ||
+            // Headers like "Unit Price"
+            header_to_snake_case(key) == expected_snake
         }) {
             return Some((k, v));
         }
@@ -227,6 +229,30 @@ pub fn to_snake_case(s: &str) -> String {
     result
 }
 
+/// Like [`to_snake_case`], but also for space-separated words, so headers
+/// of tables, CSV files and `Key: value` lines like `Unit Price` match
+/// `unit_price`.
+fn header_to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+
+    for ch in s.trim().chars() {
+        if ch.is_uppercase() {
+            if !result.is_empty() && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.push(ch.to_ascii_lowercase());
+        } else if ch == '-' || ch == '.' || ch.is_whitespace() {
+            if !result.ends_with('_') {
+                result.push('_');
+            }
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
 /// Remove accents from characters to enable fuzzy matching.
 ///
 /// Port from `match_string.rs:141-159`.
@@ -289,6 +315,9 @@ pub struct FieldDescriptor {
     pub type_name: &'static str,
     /// Whether this field is optional (Option<T>)
     pub is_optional: bool,
+    /// Whether a present value that doesn't parse fails the struct instead
+    /// of defaulting an optional field (Maybe<T>)
+    pub fails_if_unparseable: bool,
 }
 
 impl FieldDescriptor {
@@ -298,8 +327,24 @@ impl FieldDescriptor {
             name: name.into(),
             type_name,
             is_optional,
+            fails_if_unparseable: false,
         }
     }
+
+    /// Makes a present value that doesn't parse fail the struct, even if the
+    /// field is optional.
+    ///
+    /// Used for `Maybe<T>` fields, where the default would claim the key was
+    /// absent.
+    pub fn fail_if_unparseable(mut self) -> Self {
+        self.fails_if_unparseable = true;
+        self
+    }
+
+    /// Returns true if a value that doesn't parse is replaced by the default.
+    fn defaults_on_error(&self) -> bool {
+        self.is_optional && !self.fails_if_unparseable
+    }
 }
 
 /// Helper for deserializing struct fields with BAML's two-mode coercion.
@@ -420,40 +465,38 @@ impl StructDeserializer {
     where
         F: FnMut(&str, &FlexValue, &mut CoercionContext, bool) -> Result<Box<dyn Any>>,
     {
-        // Must be an object
-        let obj = match &value.value {
-            Value::Object(obj) => obj,
-            Value::Array(arr) => {
-                // BAML ALGORITHM: For single-field structs, try to coerce array
-                if self.fields.len() == 1 {
-                    return self.try_single_field_coercion(value, ctx, type_name, deserialize_fn);
+        // BAML ALGORITHM: Circular reference detection
+        ctx.check_can_enter_lenient(type_name, value)?;
+        let mut nested_ctx = ctx.with_visited_lenient(type_name, value);
+
+        let result = match &value.value {
+            // BAML ALGORITHM: Try strict matching first (try_cast), then fall
+            // back to lenient matching (coerce)
+            Value::Object(obj) => {
+                match self.try_strict_match(obj, &mut nested_ctx, &mut deserialize_fn) {
+                    Some(result) => result,
+                    None => self.try_lenient_match(obj, &mut nested_ctx, deserialize_fn)?,
                 }
-                // BAML ALGORITHM: Try array-to-struct coercion
-                // Match array elements to struct fields in order: [val1, val2] → {field1: val1, field2: val2}
-                return self.try_array_to_struct_coercion(arr, ctx, type_name, deserialize_fn);
+            }
+            // BAML ALGORITHM: For single-field structs, try to coerce entire value
+            _ if self.fields.len() == 1 => {
+                self.try_single_field_coercion(value, &mut nested_ctx, type_name, deserialize_fn)?
+            }
+            // BAML ALGORITHM: Try array-to-struct coercion
+            // Match array elements to struct fields in order: [val1, val2] → {field1: val1, field2: val2}
+            Value::Array(arr) => {
+                self.try_array_to_struct_coercion(arr, &mut nested_ctx, type_name, deserialize_fn)?
             }
             _ => {
-                // BAML ALGORITHM: For single-field structs, try to coerce entire value
-                if self.fields.len() == 1 {
-                    return self.try_single_field_coercion(value, ctx, type_name, deserialize_fn);
-                }
                 return Err(ParseError::DeserializeFailed(
                     DeserializeError::type_mismatch("object", "non-object"),
                 ));
             }
         };
 
-        // BAML ALGORITHM: Circular reference detection
-        ctx.check_can_enter_lenient(type_name, value)?;
-        let mut nested_ctx = ctx.with_visited_lenient(type_name, value);
-
-        // BAML ALGORITHM: Try strict matching first (try_cast)
-        if let Some(result) = self.try_strict_match(obj, &mut nested_ctx, &mut deserialize_fn) {
-            return Ok(result);
-        }
-
-        // BAML ALGORITHM: Fall back to lenient matching (coerce)
-        self.try_lenient_match(obj, &mut nested_ctx, deserialize_fn)
+        // Keep what field coercions recorded on the nested context
+        ctx.merge_nested(nested_ctx);
+        Ok(result)
     }
 
     /// Try strict matching (BAML's try_cast).
@@ -537,11 +580,14 @@ impl StructDeserializer {
                             result.insert(field.name.clone(), field_value);
                         }
                         Err(e) => {
-                            if field.is_optional {
-                                // Optional field - use None/default
-                                let transformation = Transformation::DefaultValueInserted {
-                                    field: field.name.clone(),
-                                };
+                            if field.defaults_on_error() {
+                                // Optional field failed to parse - use None/default
+                                let transformation =
+                                    Transformation::DefaultButHadUnparseableValue {
+                                        field: field.name.clone(),
+                                        value: value.to_string(),
+                                        error: e.to_string(),
+                                    };
                                 self.transformations.push(transformation.clone());
                                 ctx.add_transformation(transformation);
                                 // Caller should handle Option::None
@@ -621,10 +667,12 @@ impl StructDeserializer {
                 Ok(result)
             }
             Err(e) => {
-                if field.is_optional {
-                    // Optional field - use default
-                    let transformation = Transformation::DefaultValueInserted {
+                if field.defaults_on_error() {
+                    // Optional field failed to parse - use default
+                    let transformation = Transformation::DefaultButHadUnparseableValue {
                         field: field.name.clone(),
+                        value: value.value.to_string(),
+                        error: e.to_string(),
                     };
                     self.transformations.push(transformation.clone());
                     ctx.add_transformation(transformation);
@@ -712,7 +760,7 @@ impl StructDeserializer {
                     result.insert(field.name.clone(), field_value);
                 }
                 Err(e) => {
-                    if field.is_optional {
+                    if field.defaults_on_error() {
                         // Optional field failed to parse - use default
                         let transformation = Transformation::DefaultButHadUnparseableValue {
                             field: field.name.clone(),
@@ -3,9 +3,16 @@
 //! This module defines the fundamental traits and context for deserializing
 //! LLM responses with sophisticated coercion logic ported from BAML.
 
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
-use crate::{constraints::ConstraintResults, error::Result, value::FlexValue};
+use crate::{
+    constraints::ConstraintResults,
+    datetime::{DateOrder, DurationUnit},
+    deserializer::{CoercionPolicy, MapEntryPolicy, NullSentinels, NumberLocale, RangePolicy},
+    error::{ParseError, Result},
+    parser::{DuplicateKeyPolicy, NonFinitePolicy},
+    value::FlexValue,
+};
 
 /// Default maximum recursion depth for deserialization.
 /// Matches BAML's limit to prevent stack overflow.
@@ -33,6 +40,28 @@ pub struct CoercionContext {
     constraints: ConstraintResults,
     /// Transformations applied during deserialization
     transformations: Vec<crate::value::Transformation>,
+    /// How to read ambiguous numeric dates like "03/04/2024"
+    date_order: DateOrder,
+    /// Unix seconds used as "now" for relative dates (None = system clock)
+    reference_time: Option<i64>,
+    /// Unit for bare numbers coerced into a `Duration`
+    duration_unit: DurationUnit,
+    /// How numeric ranges like "10 to 20" collapse to one number
+    range_policy: RangePolicy,
+    /// Decimal and grouping separators for numbers written as text
+    number_locale: NumberLocale,
+    /// Which transformation kinds are allowed, re-scored or forbidden
+    policy: Arc<CoercionPolicy>,
+    /// Strings like "N/A" that mean `None` for optional targets
+    null_sentinels: Arc<NullSentinels>,
+    /// What happens to map entries whose key or value doesn't coerce
+    map_entry_policy: MapEntryPolicy,
+    /// Drop list items that don't coerce instead of failing the list
+    skip_bad_items: bool,
+    /// How repeated keys in parsed objects are resolved
+    duplicate_keys: DuplicateKeyPolicy,
+    /// How `Infinity` and `NaN` in parsed literals are represented
+    non_finite: NonFinitePolicy,
 }
 
 impl CoercionContext {
@@ -48,6 +77,17 @@ impl CoercionContext {
             scope: vec!["<root>".to_string()],
             constraints: ConstraintResults::new(),
             transformations: Vec::new(),
+            date_order: DateOrder::default(),
+            reference_time: None,
+            duration_unit: DurationUnit::default(),
+            range_policy: RangePolicy::default(),
+            number_locale: NumberLocale::default(),
+            policy: Arc::new(CoercionPolicy::default()),
+            null_sentinels: Arc::new(NullSentinels::default()),
+            map_entry_policy: MapEntryPolicy::default(),
+            skip_bad_items: false,
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            non_finite: NonFinitePolicy::default(),
         }
     }
 
@@ -61,9 +101,183 @@ impl CoercionContext {
             scope: vec!["<root>".to_string()],
             constraints: ConstraintResults::new(),
             transformations: Vec::new(),
+            date_order: DateOrder::default(),
+            reference_time: None,
+            duration_unit: DurationUnit::default(),
+            range_policy: RangePolicy::default(),
+            number_locale: NumberLocale::default(),
+            policy: Arc::new(CoercionPolicy::default()),
+            null_sentinels: Arc::new(NullSentinels::default()),
+            map_entry_policy: MapEntryPolicy::default(),
+            skip_bad_items: false,
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            non_finite: NonFinitePolicy::default(),
         }
     }
 
+    /// Sets how ambiguous numeric dates like `03/04/2024` are read.
+    pub fn with_date_order(mut self, order: DateOrder) -> Self {
+        self.date_order = order;
+        self
+    }
+
+    /// Fixes "now" (as unix seconds) for relative dates like "yesterday".
+    ///
+    /// Defaults to the system clock. Mostly useful for reproducible parsing and tests.
+    pub fn with_reference_time(mut self, unix_seconds: i64) -> Self {
+        self.reference_time = Some(unix_seconds);
+        self
+    }
+
+    /// Sets the unit for bare numbers like `90` when deserializing a `Duration`.
+    pub fn with_duration_unit(mut self, unit: DurationUnit) -> Self {
+        self.duration_unit = unit;
+        self
+    }
+
+    /// Sets how numeric ranges like "10 to 20" or "between 10 and 20" become one number.
+    pub fn with_range_policy(mut self, policy: RangePolicy) -> Self {
+        self.range_policy = policy;
+        self
+    }
+
+    /// Sets the decimal and grouping separators for numbers written as text.
+    pub fn with_number_locale(mut self, locale: NumberLocale) -> Self {
+        self.number_locale = locale;
+        self
+    }
+
+    /// Sets the coercion policy applied to every recorded transformation.
+    pub fn with_policy(mut self, policy: CoercionPolicy) -> Self {
+        self.policy = Arc::new(policy);
+        self
+    }
+
+    /// Sets the strings read as "no value" for `Option` and `Maybe` targets.
+    pub fn with_null_sentinels(mut self, sentinels: NullSentinels) -> Self {
+        self.null_sentinels = Arc::new(sentinels);
+        self
+    }
+
+    /// Sets what happens to map entries whose key or value doesn't coerce.
+    pub fn with_map_entry_policy(mut self, policy: MapEntryPolicy) -> Self {
+        self.map_entry_policy = policy;
+        self
+    }
+
+    /// Drops `Vec<T>` items that don't coerce instead of failing the whole list.
+    ///
+    /// Each dropped item is recorded as a
+    /// [`Transformation::ArrayItemParseError`](crate::value::Transformation::ArrayItemParseError)
+    /// with its index and error. Use `#[llm(skip_bad_items)]` to enable this for one field.
+    pub fn with_skip_bad_items(mut self, skip: bool) -> Self {
+        self.skip_bad_items = skip;
+        self
+    }
+
+    /// Sets how objects in the response that repeat a key are resolved.
+    ///
+    /// Used by [`parse_llm_with_context`](crate::parse_llm_with_context) when
+    /// parsing the response, before any deserialization.
+    pub fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Sets how `Infinity` and `NaN` in JSON5, Python and JavaScript literals
+    /// in the response are represented.
+    ///
+    /// Used by [`parse_llm_with_context`](crate::parse_llm_with_context) when
+    /// parsing the response, before any deserialization.
+    pub fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Turns skip-bad-items mode on or off in place, returning the previous setting.
+    ///
+    /// Used by `#[llm(skip_bad_items)]` to scope the mode to one field.
+    #[doc(hidden)]
+    pub fn set_skip_bad_items(&mut self, skip: bool) -> bool {
+        std::mem::replace(&mut self.skip_bad_items, skip)
+    }
+
+    /// Returns the day/month ordering policy for numeric dates.
+    pub const fn date_order(&self) -> DateOrder {
+        self.date_order
+    }
+
+    /// Returns "now" as unix seconds, from [`Self::with_reference_time`] or the system clock.
+    pub fn reference_time(&self) -> i64 {
+        self.reference_time.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs() as i64)
+        })
+    }
+
+    /// Returns the unit for bare numbers coerced into a `Duration`.
+    pub const fn duration_unit(&self) -> DurationUnit {
+        self.duration_unit
+    }
+
+    /// Returns the policy for numeric ranges.
+    pub const fn range_policy(&self) -> RangePolicy {
+        self.range_policy
+    }
+
+    /// Returns the number locale.
+    pub const fn number_locale(&self) -> NumberLocale {
+        self.number_locale
+    }
+
+    /// Returns the policy for map entries that don't coerce.
+    pub const fn map_entry_policy(&self) -> MapEntryPolicy {
+        self.map_entry_policy
+    }
+
+    /// Returns true if list items that don't coerce are dropped.
+    pub const fn skip_bad_items(&self) -> bool {
+        self.skip_bad_items
+    }
+
+    /// Returns the policy for objects that repeat a key.
+    pub const fn duplicate_key_policy(&self) -> DuplicateKeyPolicy {
+        self.duplicate_keys
+    }
+
+    /// Returns the policy for `Infinity` and `NaN` in parsed literals.
+    pub const fn non_finite_policy(&self) -> NonFinitePolicy {
+        self.non_finite
+    }
+
+    /// Returns the coercion policy.
+    pub fn policy(&self) -> &CoercionPolicy {
+        &self.policy
+    }
+
+    /// Returns the strings read as "no value" for optional targets.
+    pub fn null_sentinels(&self) -> &NullSentinels {
+        &self.null_sentinels
+    }
+
+    /// Fails if any recorded transformation is forbidden by the policy.
+    pub fn check_policy(&self) -> Result<()> {
+        self.policy
+            .check_all(&self.transformations)
+            .map_err(ParseError::DeserializeFailed)
+    }
+
+    /// Sums the penalties of the recorded transformations under the policy.
+    ///
+    /// Lower is better; this is what union matching uses to rank variants.
+    pub fn score(&self) -> u32 {
+        self.transformations
+            .iter()
+            .map(|t| self.policy.penalty(t))
+            .sum()
+    }
+
     /// Enters a new scope (e.g., entering a field).
     ///
     /// Returns a new context with the updated scope trail.
@@ -128,6 +342,24 @@ impl CoercionContext {
         std::mem::take(&mut self.transformations)
     }
 
+    /// Copies transformations and constraints recorded on a nested context back into this one.
+    ///
+    /// `nested` must have been derived from `self` (via `enter_scope` or `with_visited_*`),
+    /// so only the entries added after the split are copied.
+    pub(crate) fn merge_nested(&mut self, nested: Self) {
+        let known_transformations = self.transformations.len();
+        self.transformations.extend(
+            nested
+                .transformations
+                .into_iter()
+                .skip(known_transformations),
+        );
+
+        for result in nested.constraints.all().iter().skip(self.constraints.len()) {
+            self.constraints.add(result.clone());
+        }
+    }
+
     /// Checks if we can enter a type for strict matching.
     ///
     /// Returns an error if this would exceed the depth limit or create a cycle.
@@ -248,6 +480,14 @@ pub trait LlmDeserialize: Sized {
     fn type_name() -> &'static str {
         std::any::type_name::<Self>()
     }
+
+    /// Returns true for types written as one scalar: strings, numbers, bools.
+    ///
+    /// `Vec<T>` only splits text like `"a, b and c"` into items when `T` is
+    /// scalar. Defaults to false.
+    fn is_scalar() -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
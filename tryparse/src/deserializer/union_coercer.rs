@@ -71,23 +71,24 @@ impl<T> UnionDeserializer<T> {
 
         // If no strict matches, try lenient mode
         if matches.is_empty() {
-            // Try with separate contexts to track transformations for each variant
+            // Try with separate contexts to track transformations for each variant.
+            // Variants that needed a transformation the policy forbids are dropped.
             let mut ctx1 = ctx.clone();
-            if let Ok(v1) = V1::deserialize(value, &mut ctx1) {
-                let score = calculate_score_from_context(&ctx1);
+            let result1 = V1::deserialize(value, &mut ctx1);
+            if let (Ok(v1), Ok(())) = (result1, ctx1.check_policy()) {
                 matches.push(UnionMatch {
                     value: v1.into(),
-                    score,
+                    score: ctx1.score(),
                     transformations: ctx1.transformations().to_vec(),
                 });
             }
 
             let mut ctx2 = ctx.clone();
-            if let Ok(v2) = V2::deserialize(value, &mut ctx2) {
-                let score = calculate_score_from_context(&ctx2);
+            let result2 = V2::deserialize(value, &mut ctx2);
+            if let (Ok(v2), Ok(())) = (result2, ctx2.check_policy()) {
                 matches.push(UnionMatch {
                     value: v2.into(),
-                    score,
+                    score: ctx2.score(),
                     transformations: ctx2.transformations().to_vec(),
                 });
             }
@@ -172,13 +173,6 @@ impl<T> Default for UnionDeserializer<T> {
     }
 }
 
-/// Calculate score from transformations in a CoercionContext.
-///
-/// This sums up the penalty scores of all transformations.
-fn calculate_score_from_context(ctx: &CoercionContext) -> u32 {
-    ctx.transformations().iter().map(|t| t.penalty()).sum()
-}
-
 /// Apply BAML's union-specific heuristics to pick the best match.
 ///
 /// Port from `array_helper.rs:66-243` with all 8 heuristics.
@@ -198,15 +192,19 @@ fn apply_union_heuristics<T>(a: &UnionMatch<T>, b: &UnionMatch<T>) -> std::cmp::
     let b_is_list = is_list_transformation(&b.transformations);
 
     if a_is_list && b_is_list {
-        // HEURISTIC 1: Prefer real arrays over single-to-array
-        let a_has_single_to_array = a
-            .transformations
-            .iter()
-            .any(|t| matches!(t, Transformation::SingleToArray));
-        let b_has_single_to_array = b
-            .transformations
-            .iter()
-            .any(|t| matches!(t, Transformation::SingleToArray));
+        // HEURISTIC 1: Prefer real arrays over single-to-array or split strings
+        let a_has_single_to_array = a.transformations.iter().any(|t| {
+            matches!(
+                t,
+                Transformation::SingleToArray | Transformation::SplitToArray { .. }
+            )
+        });
+        let b_has_single_to_array = b.transformations.iter().any(|t| {
+            matches!(
+                t,
+                Transformation::SingleToArray | Transformation::SplitToArray { .. }
+            )
+        });
 
         match (a_has_single_to_array, b_has_single_to_array) {
             (true, false) => return std::cmp::Ordering::Greater, // Prefer B
@@ -294,7 +292,9 @@ fn is_list_transformation(transformations: &[Transformation]) -> bool {
     transformations.iter().any(|t| {
         matches!(
             t,
-            Transformation::SingleToArray | Transformation::ArrayItemParseError { .. }
+            Transformation::SingleToArray
+                | Transformation::SplitToArray { .. }
+                | Transformation::ArrayItemParseError { .. }
         )
     })
 }
@@ -27,6 +27,21 @@ pub enum ParseError {
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    /// A parsed object repeated a key under `DuplicateKeyPolicy::Error`.
+    #[error("Duplicate key in object: {path}")]
+    DuplicateKey {
+        /// Path to the repeated key, like `tags` or `items[0].name`.
+        path: String,
+    },
+
+    /// An `Infinity` or `NaN` (JSON5, or Python's `float('inf')`) under
+    /// `NonFinitePolicy::Error`.
+    #[error("Non-finite number: {literal}")]
+    NonFiniteNumber {
+        /// The number as written, like `-Infinity`.
+        literal: String,
+    },
+
     /// Configuration error.
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
@@ -112,6 +127,25 @@ pub enum DeserializeError {
         /// Type name where the cycle was detected.
         type_name: String,
     },
+
+    /// Number doesn't fit the target type, or is NaN or infinite.
+    #[error("Number {value} is out of range for {target}")]
+    NumberOutOfRange {
+        /// The number as written (or as computed, for coerced strings).
+        value: String,
+        /// Target type name.
+        target: &'static str,
+    },
+
+    /// A [`crate::deserializer::CoercionPolicy`] rule forbids a transformation
+    /// the candidate needed.
+    #[error("Coercion policy forbids {kind}: {detail}")]
+    ForbiddenTransformation {
+        /// The forbidden transformation kind.
+        kind: crate::value::TransformationKind,
+        /// The transformation that was applied.
+        detail: String,
+    },
 }
 
 impl DeserializeError {
@@ -139,6 +173,15 @@ impl DeserializeError {
             message: message.into(),
         }
     }
+
+    /// Creates a number out of range error.
+    #[inline]
+    pub fn out_of_range(value: impl fmt::Display, target: &'static str) -> Self {
+        Self::NumberOutOfRange {
+            value: value.to_string(),
+            target,
+        }
+    }
 }
 
 impl serde::de::Error for DeserializeError {
@@ -164,6 +207,12 @@ mod tests {
         assert!(err.to_string().contains("string"));
     }
 
+    #[test]
+    fn test_deserialize_error_out_of_range() {
+        let err = DeserializeError::out_of_range(f64::NAN, "i64");
+        assert_eq!(err.to_string(), "Number NaN is out of range for i64");
+    }
+
     #[test]
     fn test_parse_error_from_json() {
         let json_err = serde_json::from_str::<u32>("not a number").unwrap_err();
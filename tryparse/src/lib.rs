@@ -61,8 +61,10 @@
 //! ```
 
 pub mod constraints;
+pub mod datetime;
 pub mod deserializer;
 pub mod error;
+pub mod options;
 pub mod parser;
 pub mod scoring;
 pub mod value;
@@ -76,6 +78,7 @@ pub fn __ensure_primitives_linked() {
 
 use deserializer::{CoercingDeserializer, CoercionContext, LlmDeserialize};
 use error::{ParseError, Result};
+use options::ParseOptions;
 use parser::FlexibleParser;
 use serde::de::DeserializeOwned;
 use value::FlexValue;
@@ -139,20 +142,93 @@ pub fn parse<T: DeserializeOwned>(input: &str) -> Result<T> {
 /// Returns `ParseError::NoCandidates` if no valid JSON could be extracted.
 /// Returns `ParseError::DeserializeFailed` if deserialization fails for all candidates.
 pub fn parse_with_candidates<T: DeserializeOwned>(input: &str) -> Result<(T, Vec<FlexValue>)> {
-    let parser = FlexibleParser::new();
-    let candidates = parser.parse(input)?;
+    parse_candidates_with_options(input, &ParseOptions::default())
+}
+
+/// Parses an LLM response with the settings in `options`.
+///
+/// Settings combine freely; see [`ParseOptions`] for what can be set. Every
+/// transformation recorded while repairing or deserializing a candidate is
+/// checked against the options' [`deserializer::CoercionPolicy`]; a forbidden one moves on
+/// to the next candidate.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use tryparse::{
+///     deserializer::{CoercionPolicy, NullSentinels},
+///     options::ParseOptions,
+/// };
+///
+/// #[derive(Deserialize, Debug)]
+/// struct Order {
+///     owner: Option<String>,
+///     tags: Vec<String>,
+/// }
+///
+/// let options = ParseOptions::new()
+///     .with_policy(CoercionPolicy::lossless())
+///     .with_null_sentinels(NullSentinels::common());
+///
+/// let order: Order =
+///     tryparse::parse_with(r#"{"owner": "N/A", "tags": ["a", "b"]}"#, &options).unwrap();
+/// assert_eq!(order.owner, None);
+/// assert_eq!(order.tags, vec!["a", "b"]);
+///
+/// // Wrapping a lone string as a one-element array is forbidden.
+/// let err = tryparse::parse_with::<Order>(r#"{"owner": null, "tags": "a"}"#, &options)
+///     .unwrap_err();
+/// assert!(err.to_string().contains("single_to_array"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `ParseError::NoCandidates` if no valid JSON could be extracted.
+/// Returns `ParseError::DeserializeFailed` if deserialization fails for all candidates,
+/// with `DeserializeError::ForbiddenTransformation` when the policy was the reason.
+pub fn parse_with<T: DeserializeOwned>(input: &str, options: &ParseOptions) -> Result<T> {
+    let (result, _candidates) = parse_candidates_with_options(input, options)?;
+    Ok(result)
+}
+
+fn parse_candidates_with_options<T: DeserializeOwned>(
+    input: &str,
+    options: &ParseOptions,
+) -> Result<(T, Vec<FlexValue>)> {
+    let parser =
+        FlexibleParser::with_policies(options.duplicate_key_policy(), options.non_finite_policy());
+    let (candidates, rejected) = parser.parse_with_rejection(input)?;
 
     if candidates.is_empty() {
-        return Err(ParseError::NoCandidates);
+        return Err(rejected.unwrap_or(ParseError::NoCandidates));
     }
 
-    // Try to deserialize each candidate
+    // Try to deserialize each candidate. With penalties in the policy, every
+    // candidate gets a chance and the lowest score under the policy wins.
+    let policy = options.policy();
     let mut errors = Vec::new();
     let ranked = scoring::rank_candidates(candidates);
+    let mut best: Option<(u32, T)> = None;
 
     for candidate in ranked.clone() {
-        let mut deserializer = CoercingDeserializer::new(candidate);
+        if let Err(e) = policy.check_all(candidate.transformations()) {
+            errors.push(e);
+            continue;
+        }
+
+        let parse_score = scoring::score_candidate_with_policy(&candidate, policy);
+        let mut deserializer = CoercingDeserializer::with_policy(candidate, policy.clone())
+            .with_null_sentinels(options.null_sentinels().clone())
+            .with_range_policy(options.range_policy())
+            .with_number_locale(options.number_locale());
         match T::deserialize(&mut deserializer) {
+            Ok(value) if policy.has_penalties() => {
+                let score = parse_score + deserializer.policy_penalty();
+                if !matches!(best, Some((lowest, _)) if lowest <= score) {
+                    best = Some((score, value));
+                }
+            }
             Ok(value) => {
                 return Ok((value, ranked));
             }
@@ -161,13 +237,16 @@ pub fn parse_with_candidates<T: DeserializeOwned>(input: &str) -> Result<(T, Vec
             }
         }
     }
+    if let Some((_, value)) = best {
+        return Ok((value, ranked));
+    }
 
-    // All candidates failed
-    Err(ParseError::DeserializeFailed(
-        errors.into_iter().next().unwrap_or_else(|| {
+    // All candidates failed; a candidate the parser dropped says why best
+    Err(rejected.unwrap_or_else(|| {
+        ParseError::DeserializeFailed(errors.into_iter().next().unwrap_or_else(|| {
             error::DeserializeError::Custom("unknown deserialization error".to_string())
-        }),
-    ))
+        }))
+    }))
 }
 
 /// Parses an LLM response using a custom parser.
@@ -190,10 +269,10 @@ pub fn parse_with_candidates<T: DeserializeOwned>(input: &str) -> Result<(T, Vec
 /// let data: Data = parse_with_parser(response, &parser).unwrap();
 /// ```
 pub fn parse_with_parser<T: DeserializeOwned>(input: &str, parser: &FlexibleParser) -> Result<T> {
-    let candidates = parser.parse(input)?;
+    let (candidates, rejected) = parser.parse_with_rejection(input)?;
 
     if candidates.is_empty() {
-        return Err(ParseError::NoCandidates);
+        return Err(rejected.unwrap_or(ParseError::NoCandidates));
     }
 
     let ranked = scoring::rank_candidates(candidates);
@@ -205,7 +284,7 @@ pub fn parse_with_parser<T: DeserializeOwned>(input: &str, parser: &FlexiblePars
         }
     }
 
-    Err(ParseError::NoCandidates)
+    Err(rejected.unwrap_or(ParseError::NoCandidates))
 }
 
 // ================================================================================================
@@ -293,46 +372,131 @@ pub fn parse_llm<T: LlmDeserialize>(input: &str) -> Result<T> {
 /// Returns `ParseError::NoCandidates` if no valid JSON could be extracted.
 /// Returns `ParseError::DeserializeFailed` if deserialization fails for all candidates.
 pub fn parse_llm_with_candidates<T: LlmDeserialize>(input: &str) -> Result<(T, Vec<FlexValue>)> {
-    let parser = FlexibleParser::new();
-    let candidates = parser.parse(input)?;
+    parse_llm_candidates_with_context(input, &CoercionContext::new())
+}
+
+/// Parses an LLM response with `LlmDeserialize`, using `ctx` as the template
+/// context for every candidate.
+///
+/// This is how to apply context settings such as a [`deserializer::CoercionPolicy`],
+/// [`deserializer::RangePolicy`] or [`deserializer::NumberLocale`]. Each candidate
+/// gets a fresh clone of `ctx`; a candidate whose repairs or coercions include
+/// a transformation the policy forbids is skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use tryparse::deserializer::{CoercionContext, CoercionPolicy};
+/// use tryparse::value::TransformationKind;
+///
+/// let ctx = CoercionContext::new()
+///     .with_policy(CoercionPolicy::new().forbid(TransformationKind::StringToNumber));
+///
+/// let ids: Vec<i64> = tryparse::parse_llm_with_context("[1, 2]", &ctx).unwrap();
+/// assert_eq!(ids, vec![1, 2]);
+///
+/// let err = tryparse::parse_llm_with_context::<Vec<i64>>(r#"["1", "2"]"#, &ctx).unwrap_err();
+/// assert!(err.to_string().contains("string_to_number"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `ParseError::NoCandidates` if no valid JSON could be extracted.
+/// Returns `ParseError::DeserializeFailed` if deserialization fails for all candidates,
+/// with `DeserializeError::ForbiddenTransformation` when the policy was the reason.
+pub fn parse_llm_with_context<T: LlmDeserialize>(input: &str, ctx: &CoercionContext) -> Result<T> {
+    let (result, _candidates) = parse_llm_candidates_with_context(input, ctx)?;
+    Ok(result)
+}
+
+fn parse_llm_candidates_with_context<T: LlmDeserialize>(
+    input: &str,
+    template: &CoercionContext,
+) -> Result<(T, Vec<FlexValue>)> {
+    let parser = FlexibleParser::with_policies(
+        template.duplicate_key_policy(),
+        template.non_finite_policy(),
+    );
+    let (candidates, rejected) = parser.parse_with_rejection(input)?;
 
     if candidates.is_empty() {
-        return Err(ParseError::NoCandidates);
+        return Err(rejected.unwrap_or(ParseError::NoCandidates));
     }
 
     // Rank candidates by quality
     let ranked = scoring::rank_candidates(candidates);
 
+    // Candidates whose parse-level repairs are already forbidden never get a chance
+    let mut policy_error = None;
+    let allowed: Vec<bool> = ranked
+        .iter()
+        .map(
+            |candidate| match template.policy().check_all(candidate.transformations()) {
+                Ok(()) => true,
+                Err(e) => {
+                    policy_error.get_or_insert(e);
+                    false
+                }
+            },
+        )
+        .collect();
+
     // BAML TWO-MODE COERCION:
     // 1. First pass: Try strict deserialization (try_deserialize) on all candidates
     //    This allows array candidates to win for Vec<T> before single-value wrapping
     // 2. Second pass: Try lenient deserialization (deserialize) on all candidates
     //    This applies coercions like single-value wrapping for Vec<T>
 
+    //
+    // With penalties in the policy, each pass tries every candidate and keeps
+    // the lowest score under the policy instead of the first success.
+    let penalized = template.policy().has_penalties();
+
     // First pass: Strict mode (try_deserialize)
+    let mut best: Option<(u32, usize, T, CoercionContext)> = None;
     for (idx, candidate) in ranked.iter().enumerate() {
-        let mut ctx = CoercionContext::new();
+        if !allowed[idx] {
+            continue;
+        }
+        let mut ctx = template.clone();
         if let Some(value) = T::try_deserialize(candidate, &mut ctx) {
-            // Merge transformations from deserialization into the winning candidate
-            let mut updated_ranked = ranked.clone();
-            for transformation in ctx.transformations() {
-                updated_ranked[idx].add_transformation(transformation.clone());
+            if let Err(ParseError::DeserializeFailed(e)) = ctx.check_policy() {
+                policy_error.get_or_insert(e);
+                continue;
+            }
+            if !penalized {
+                return Ok((value, merge_transformations(&ranked, idx, &ctx)));
+            }
+            let score = policy_score(candidate, &ctx);
+            if !matches!(best, Some((lowest, ..)) if lowest <= score) {
+                best = Some((score, idx, value, ctx));
             }
-            return Ok((value, updated_ranked));
         }
     }
+    if let Some((_, idx, value, ctx)) = best {
+        return Ok((value, merge_transformations(&ranked, idx, &ctx)));
+    }
 
     // Second pass: Lenient mode (deserialize)
+    let mut best: Option<(u32, usize, T, CoercionContext)> = None;
     for (idx, candidate) in ranked.iter().enumerate() {
-        let mut ctx = CoercionContext::new();
+        if !allowed[idx] {
+            continue;
+        }
+        let mut ctx = template.clone();
         match T::deserialize(candidate, &mut ctx) {
             Ok(value) => {
-                // Merge transformations from deserialization into the winning candidate
-                let mut updated_ranked = ranked.clone();
-                for transformation in ctx.transformations() {
-                    updated_ranked[idx].add_transformation(transformation.clone());
+                if let Err(ParseError::DeserializeFailed(e)) = ctx.check_policy() {
+                    policy_error.get_or_insert(e);
+                    continue;
+                }
+                if !penalized {
+                    return Ok((value, merge_transformations(&ranked, idx, &ctx)));
+                }
+                let score = policy_score(candidate, &ctx);
+                if !matches!(best, Some((lowest, ..)) if lowest <= score) {
+                    best = Some((score, idx, value, ctx));
                 }
-                return Ok((value, updated_ranked));
             }
             Err(_) => {
                 // Continue to next candidate
@@ -340,9 +504,34 @@ pub fn parse_llm_with_candidates<T: LlmDeserialize>(input: &str) -> Result<(T, V
             }
         }
     }
+    if let Some((_, idx, value, ctx)) = best {
+        return Ok((value, merge_transformations(&ranked, idx, &ctx)));
+    }
+
+    // All candidates failed; name the parser's or the policy's rule if that's
+    // what stopped us
+    Err(rejected
+        .or(policy_error.map(ParseError::DeserializeFailed))
+        .unwrap_or(ParseError::NoCandidates))
+}
+
+/// Returns `ranked` with the transformations recorded while deserializing
+/// the winning candidate at `idx` merged into it.
+fn merge_transformations(
+    ranked: &[FlexValue],
+    idx: usize,
+    ctx: &CoercionContext,
+) -> Vec<FlexValue> {
+    let mut updated_ranked = ranked.to_vec();
+    for transformation in ctx.transformations() {
+        updated_ranked[idx].add_transformation(transformation.clone());
+    }
+    updated_ranked
+}
 
-    // All candidates failed
-    Err(ParseError::NoCandidates)
+/// Scores `candidate` and what deserializing it recorded under the policy.
+fn policy_score(candidate: &FlexValue, ctx: &CoercionContext) -> u32 {
+    scoring::score_candidate_with_policy(candidate, ctx.policy()) + ctx.score()
 }
 
 #[cfg(test)]
@@ -0,0 +1,135 @@
+//! Settings for the serde-based [`parse_with`](crate::parse_with).
+//!
+//! [`ParseOptions`] gathers everything the serde path can be configured with,
+//! so settings combine: a [`CoercionPolicy`] together with [`NullSentinels`],
+//! a [`DuplicateKeyPolicy`] together with a [`NumberLocale`], and so on. The
+//! `LlmDeserialize` path takes the same settings on a
+//! [`CoercionContext`](crate::deserializer::CoercionContext).
+
+use crate::{
+    deserializer::{CoercionPolicy, NullSentinels, NumberLocale, RangePolicy},
+    parser::{DuplicateKeyPolicy, NonFinitePolicy},
+};
+
+/// How [`parse_with`](crate::parse_with) parses and deserializes a response.
+///
+/// Every setting starts at its default, which is what [`parse`](crate::parse) uses.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use tryparse::{
+///     deserializer::{CoercionPolicy, NullSentinels},
+///     options::ParseOptions,
+///     value::TransformationKind,
+/// };
+///
+/// #[derive(Deserialize, Debug)]
+/// struct Ticket {
+///     owner: Option<String>,
+///     priority: u8,
+/// }
+///
+/// let options = ParseOptions::new()
+///     .with_policy(CoercionPolicy::new().forbid(TransformationKind::StringToNumber))
+///     .with_null_sentinels(NullSentinels::common());
+///
+/// let ticket: Ticket =
+///     tryparse::parse_with(r#"{"owner": "N/A", "priority": 2}"#, &options).unwrap();
+/// assert_eq!(ticket.owner, None);
+///
+/// assert!(tryparse::parse_with::<Ticket>(r#"{"owner": null, "priority": "2"}"#, &options).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Which transformation kinds are allowed, re-scored or forbidden
+    policy: CoercionPolicy,
+    /// Strings like "N/A" that mean `None` for optional targets
+    null_sentinels: NullSentinels,
+    /// How repeated keys in parsed objects are resolved
+    duplicate_keys: DuplicateKeyPolicy,
+    /// How `Infinity` and `NaN` in parsed literals are represented
+    non_finite: NonFinitePolicy,
+    /// How numeric ranges like "10 to 20" collapse to one number
+    range_policy: RangePolicy,
+    /// Decimal and grouping separators for numbers written as text
+    number_locale: NumberLocale,
+}
+
+impl ParseOptions {
+    /// Creates options with every setting at its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the coercion policy applied to every recorded transformation.
+    ///
+    /// A candidate that needs a forbidden transformation is skipped; with
+    /// penalties, the lowest score under the policy wins.
+    pub fn with_policy(mut self, policy: CoercionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the strings read as "no value" for `Option` targets.
+    pub fn with_null_sentinels(mut self, sentinels: NullSentinels) -> Self {
+        self.null_sentinels = sentinels;
+        self
+    }
+
+    /// Sets how objects in the response that repeat a key are resolved.
+    pub fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Sets how `Infinity` and `NaN` in JSON5, Python and JavaScript literals
+    /// in the response are represented.
+    pub fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Sets how numeric ranges like "10 to 20" collapse to one number.
+    pub fn with_range_policy(mut self, policy: RangePolicy) -> Self {
+        self.range_policy = policy;
+        self
+    }
+
+    /// Sets the decimal and grouping separators for numbers written as text.
+    pub fn with_number_locale(mut self, locale: NumberLocale) -> Self {
+        self.number_locale = locale;
+        self
+    }
+
+    /// Returns the coercion policy.
+    pub fn policy(&self) -> &CoercionPolicy {
+        &self.policy
+    }
+
+    /// Returns the strings read as "no value".
+    pub fn null_sentinels(&self) -> &NullSentinels {
+        &self.null_sentinels
+    }
+
+    /// Returns how repeated object keys are resolved.
+    pub const fn duplicate_key_policy(&self) -> DuplicateKeyPolicy {
+        self.duplicate_keys
+    }
+
+    /// Returns how `Infinity` and `NaN` are represented.
+    pub const fn non_finite_policy(&self) -> NonFinitePolicy {
+        self.non_finite
+    }
+
+    /// Returns how numeric ranges collapse to one number.
+    pub const fn range_policy(&self) -> RangePolicy {
+        self.range_policy
+    }
+
+    /// Returns the separators for numbers written as text.
+    pub const fn number_locale(&self) -> NumberLocale {
+        self.number_locale
+    }
+}
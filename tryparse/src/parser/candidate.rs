@@ -30,6 +30,9 @@ pub enum CandidateSource {
 
     /// Extracted using regex or other pattern
     Pattern { pattern: String },
+
+    /// Extracted from an XML-style tag like `<answer>`
+    Tagged { tag: String },
 }
 
 impl Candidate {
@@ -59,6 +62,14 @@ impl Candidate {
         }
     }
 
+    /// Creates a new candidate from XML-style tag extraction
+    pub fn tagged(content: impl Into<String>, tag: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            source: CandidateSource::Tagged { tag: tag.into() },
+        }
+    }
+
     /// Converts this candidate source to a FlexValue source
     pub fn to_source(&self) -> Source {
         match &self.source {
@@ -72,6 +83,10 @@ impl Candidate {
             CandidateSource::Pattern { pattern } => Source::Heuristic {
                 pattern: pattern.clone(),
             },
+            CandidateSource::Tagged { tag } => Source::Tagged {
+                tag: tag.clone(),
+                fixes: Vec::new(),
+            },
         }
     }
 }
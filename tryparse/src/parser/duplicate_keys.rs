@@ -0,0 +1,343 @@
+//! Repeated keys in parsed JSON objects.
+//!
+//! serde_json keeps the last value when an object repeats a key, so
+//! `{"tags": ["a"], "tags": ["b"]}` quietly loses `["a"]`. [`parse_json`]
+//! validates the text with serde_json, then builds the value itself so each
+//! repeated key is resolved by a [`DuplicateKeyPolicy`] and recorded as a
+//! [`Transformation::DuplicateKey`] with its path.
+
+use serde_json::{map::Entry, Map, Value};
+
+use crate::{
+    error::{ParseError, Result},
+    parser::strategies::ParsingStrategy,
+    value::{FlexValue, Transformation},
+};
+
+/// How a repeated key in a parsed object is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateKeyPolicy {
+    /// Keep the first value.
+    FirstWins,
+    /// Keep the last value, like serde_json.
+    #[default]
+    LastWins,
+    /// Collect every value into an array; array values are concatenated
+    /// (`"tags": ["a"], "tags": "b"` → `"tags": ["a", "b"]`).
+    MergeIntoArray,
+    /// Fail with [`ParseError::DuplicateKey`].
+    Error,
+}
+
+impl DuplicateKeyPolicy {
+    /// Returns the name recorded in [`Transformation::DuplicateKey`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::FirstWins => "first_wins",
+            Self::LastWins => "last_wins",
+            Self::MergeIntoArray => "merge_into_array",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// A strategy that parses with the default policy but can be asked for
+/// another one.
+///
+/// Unit strategies like [`DirectJsonStrategy`] implement this instead of
+/// carrying a policy, and [`WithDuplicateKeys`] runs them under the one a
+/// [`FlexibleParser`] was built with.
+///
+/// [`DirectJsonStrategy`]: crate::parser::strategies::DirectJsonStrategy
+/// [`FlexibleParser`]: crate::parser::FlexibleParser
+pub(crate) trait ParseWithDuplicateKeys: ParsingStrategy {
+    /// Parses `input` like [`ParsingStrategy::parse`], resolving repeated
+    /// keys by `policy`.
+    fn parse_with_duplicate_keys(
+        &self,
+        input: &str,
+        policy: DuplicateKeyPolicy,
+    ) -> Result<Vec<FlexValue>>;
+}
+
+/// Runs `S` with repeated keys resolved by `policy`.
+#[derive(Debug)]
+pub(crate) struct WithDuplicateKeys<S> {
+    pub(crate) strategy: S,
+    pub(crate) policy: DuplicateKeyPolicy,
+}
+
+impl<S: ParseWithDuplicateKeys> ParsingStrategy for WithDuplicateKeys<S> {
+    fn name(&self) -> &'static str {
+        self.strategy.name()
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        self.strategy.parse_with_duplicate_keys(input, self.policy)
+    }
+
+    fn priority(&self) -> u8 {
+        self.strategy.priority()
+    }
+}
+
+/// Parses `text` as JSON, resolving repeated keys by `policy`.
+///
+/// Returns `Ok(None)` if `text` isn't valid JSON, and an error only when
+/// `policy` is [`DuplicateKeyPolicy::Error`] and a key repeats.
+pub(crate) fn parse_candidate(
+    text: &str,
+    policy: DuplicateKeyPolicy,
+    make: impl FnOnce(Value) -> FlexValue,
+) -> Result<Option<FlexValue>> {
+    match parse_json(text, policy) {
+        Ok((value, repeated)) => {
+            let mut candidate = make(value);
+            for transformation in repeated {
+                candidate.add_transformation(transformation);
+            }
+            Ok(Some(candidate))
+        }
+        Err(e @ ParseError::DuplicateKey { .. }) => Err(e),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Parses `text` as JSON, resolving repeated keys by `policy`.
+///
+/// Returns the value and one transformation per repeated key.
+pub(crate) fn parse_json(
+    text: &str,
+    policy: DuplicateKeyPolicy,
+) -> Result<(Value, Vec<Transformation>)> {
+    // Everything below may assume well-formed JSON nested at most 128 deep;
+    // `IgnoredAny` would skip serde_json's recursion limit
+    serde_json::from_str::<Value>(text)?;
+
+    let mut builder = Builder {
+        text,
+        pos: 0,
+        policy,
+        repeated: Vec::new(),
+    };
+    let value = builder.value("")?;
+    Ok((value, builder.repeated))
+}
+
+/// Builds a `Value` from text serde_json has already accepted.
+struct Builder<'a> {
+    text: &'a str,
+    pos: usize,
+    policy: DuplicateKeyPolicy,
+    repeated: Vec<Transformation>,
+}
+
+impl Builder<'_> {
+    fn value(&mut self, path: &str) -> Result<Value> {
+        self.skip_whitespace();
+        match self.peek() {
+            b'{' => self.object(path),
+            b'[' => self.array(path),
+            _ => self.scalar(),
+        }
+    }
+
+    fn object(&mut self, path: &str) -> Result<Value> {
+        self.pos += 1;
+        let mut map = Map::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                b'}' => {
+                    self.pos += 1;
+                    return Ok(Value::Object(map));
+                }
+                b',' => {
+                    self.pos += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let key = match self.scalar()? {
+                Value::String(key) => key,
+                other => other.to_string(),
+            };
+            self.skip_whitespace();
+            self.pos += 1; // ':'
+
+            let key_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            let value = self.value(&key_path)?;
+
+            match map.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(mut entry) => {
+                    if self.policy == DuplicateKeyPolicy::Error {
+                        return Err(ParseError::DuplicateKey { path: key_path });
+                    }
+                    self.repeated.push(Transformation::DuplicateKey {
+                        path: key_path,
+                        policy: self.policy.name().to_string(),
+                    });
+                    let existing = entry.get_mut();
+                    match self.policy {
+                        DuplicateKeyPolicy::FirstWins | DuplicateKeyPolicy::Error => {}
+                        DuplicateKeyPolicy::LastWins => *existing = value,
+                        DuplicateKeyPolicy::MergeIntoArray => merge(existing, value),
+                    }
+                }
+            }
+        }
+    }
+
+    fn array(&mut self, path: &str) -> Result<Value> {
+        self.pos += 1;
+        let mut items = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                b']' => {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                b',' => {
+                    self.pos += 1;
+                }
+                _ => {
+                    let item_path = format!("{}[{}]", path, items.len());
+                    items.push(self.value(&item_path)?);
+                }
+            }
+        }
+    }
+
+    /// Reads a string, number, bool or null and lets serde_json decode it.
+    fn scalar(&mut self) -> Result<Value> {
+        let bytes = self.text.as_bytes();
+        let start = self.pos;
+
+        if bytes[self.pos] == b'"' {
+            self.pos += 1;
+            while bytes[self.pos] != b'"' {
+                self.pos += if bytes[self.pos] == b'\\' { 2 } else { 1 };
+            }
+            self.pos += 1;
+        } else {
+            while self.pos < bytes.len()
+                && !matches!(bytes[self.pos], b',' | b']' | b'}' | b':')
+                && !bytes[self.pos].is_ascii_whitespace()
+            {
+                self.pos += 1;
+            }
+        }
+
+        Ok(serde_json::from_str(&self.text[start..self.pos])?)
+    }
+
+    fn peek(&self) -> u8 {
+        self.text.as_bytes()[self.pos]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let bytes = self.text.as_bytes();
+        while self.pos < bytes.len() && bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+}
+
+/// Turns `existing` into an array (if it isn't one) and appends `value`,
+/// or `value`'s items if it is an array.
+fn merge(existing: &mut Value, value: Value) {
+    let items = match existing.take() {
+        Value::Array(items) => items,
+        other => vec![other],
+    };
+    *existing = Value::Array(items);
+    if let Value::Array(items) = existing {
+        match value {
+            Value::Array(more) => items.extend(more),
+            other => items.push(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn paths(repeated: &[Transformation]) -> Vec<&str> {
+        repeated
+            .iter()
+            .filter_map(|t| match t {
+                Transformation::DuplicateKey { path, .. } => Some(path.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_builds_same_value_as_serde_json() {
+        let text = r#"{"a": [1, -2.5e3, "x\"y,}"], "b": {"c": null, "d": true}, "é": "ü"}"#;
+        let (value, repeated) = parse_json(text, DuplicateKeyPolicy::default()).unwrap();
+
+        assert_eq!(value, serde_json::from_str::<Value>(text).unwrap());
+        assert!(repeated.is_empty());
+    }
+
+    #[test]
+    fn test_policies() {
+        let text = r#"{"tags": ["a"], "n": 1, "tags": "b", "tags": ["c", "d"]}"#;
+
+        let (value, repeated) = parse_json(text, DuplicateKeyPolicy::FirstWins).unwrap();
+        assert_eq!(value, json!({"tags": ["a"], "n": 1}));
+        assert_eq!(paths(&repeated), vec!["tags", "tags"]);
+
+        let (value, _) = parse_json(text, DuplicateKeyPolicy::LastWins).unwrap();
+        assert_eq!(value, json!({"tags": ["c", "d"], "n": 1}));
+
+        let (value, _) = parse_json(text, DuplicateKeyPolicy::MergeIntoArray).unwrap();
+        assert_eq!(value, json!({"tags": ["a", "b", "c", "d"], "n": 1}));
+
+        assert!(matches!(
+            parse_json(text, DuplicateKeyPolicy::Error),
+            Err(ParseError::DuplicateKey { path }) if path == "tags"
+        ));
+    }
+
+    #[test]
+    fn test_nested_paths() {
+        let text = r#"{"items": [{"id": 1}, {"id": 2, "id": 3}]}"#;
+        let (value, repeated) = parse_json(text, DuplicateKeyPolicy::FirstWins).unwrap();
+
+        assert_eq!(value, json!({"items": [{"id": 1}, {"id": 2}]}));
+        assert_eq!(paths(&repeated), vec!["items[1].id"]);
+    }
+
+    #[test]
+    fn test_deep_nesting_is_rejected() {
+        let text = format!("{}{}", "[".repeat(5000), "]".repeat(5000));
+        assert!(parse_json(&text, DuplicateKeyPolicy::default()).is_err());
+
+        let text = format!("{}{}", "[".repeat(100), "]".repeat(100));
+        assert!(parse_json(&text, DuplicateKeyPolicy::default()).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_json_is_not_a_candidate() {
+        let parsed = parse_candidate("{'a': 1}", DuplicateKeyPolicy::Error, |v| {
+            FlexValue::new(v, crate::value::Source::Direct)
+        });
+        assert!(matches!(parsed, Ok(None)));
+    }
+}
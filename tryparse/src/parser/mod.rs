@@ -2,22 +2,33 @@
 
 mod candidate;
 mod cleaner;
+mod duplicate_keys;
 pub mod state_machine;
 pub mod strategies;
 
 pub use candidate::{Candidate, CandidateSource};
 pub use cleaner::{Cleaner, GarbageCleaner};
+pub use duplicate_keys::DuplicateKeyPolicy;
+use duplicate_keys::{parse_candidate, WithDuplicateKeys};
+pub use strategies::NonFinitePolicy;
 use strategies::{
-    DirectExtractor, DirectJsonStrategy, Extractor, HeuristicExtractor, HeuristicStrategy,
-    JsonFixerStrategy, MarkdownExtractor, MarkdownStrategy, MultipleObjectsStrategy,
-    ParsingStrategy, RawPrimitiveStrategy, StateMachineStrategy,
+    CsvStrategy, DirectExtractor, DirectJsonStrategy, Extractor, HeadingSectionStrategy,
+    HeuristicExtractor, HeuristicStrategy, JavaScriptStrategy, Json5Strategy, JsonFixerStrategy,
+    KeyValueStrategy, MarkdownExtractor, MarkdownStrategy, MarkdownTableStrategy,
+    MultipleObjectsStrategy, ParsingStrategy, PythonLiteralStrategy, RawPrimitiveStrategy,
+    StateMachineStrategy, XmlStrategy, XmlTagExtractor, XmlTagStrategy,
 };
 
-use crate::{error::Result, value::FlexValue};
+use crate::{
+    error::{ParseError, Result},
+    value::FlexValue,
+};
 
 /// Maximum nesting depth before extraction is triggered to prevent stack overflow.
 pub const MAX_NESTING_DEPTH: usize = 50;
 
+#[cfg(feature = "toml")]
+use strategies::TomlStrategy;
 #[cfg(feature = "yaml")]
 use strategies::YamlStrategy;
 
@@ -40,13 +51,18 @@ use strategies::YamlStrategy;
 pub struct FlexibleParser {
     /// Parsing strategies in priority order.
     strategies: Vec<Box<dyn ParsingStrategy>>,
+    /// How repeated object keys are resolved when parsing extracted candidates.
+    duplicate_keys: DuplicateKeyPolicy,
+    /// How `Infinity` and `NaN` in JSON5, Python and JavaScript literals are
+    /// represented.
+    non_finite: NonFinitePolicy,
 }
 
 impl Clone for FlexibleParser {
     fn clone(&self) -> Self {
         // Recreate with default strategies
         // (We can't clone trait objects without adding a clone method to the trait)
-        Self::new()
+        Self::with_policies(self.duplicate_keys, self.non_finite)
     }
 }
 
@@ -62,20 +78,92 @@ impl FlexibleParser {
     /// Default strategies (in priority order):
     /// 1. DirectJsonStrategy - Fast path for valid JSON
     /// 2. JsonFixerStrategy - Repair common JSON errors
-    /// 3. RawPrimitiveStrategy - Handle raw primitives like "true", "12,111"
-    /// 4. StateMachineStrategy - State machine-based robust parsing
-    /// 5. HeuristicStrategy - Extract JSON from prose
-    /// 6. MarkdownStrategy - Extract from code blocks (if feature enabled)
-    /// 7. YamlStrategy - Parse YAML and convert to JSON (if feature enabled)
+    /// 3. Json5Strategy - Read JSON5 (`+1`, `.5`, `Infinity`, line continuations, ...)
+    /// 4. PythonLiteralStrategy - Read Python reprs with `True`/`None`, tuples and dataclasses
+    /// 5. JavaScriptStrategy - Read JS object literals, dropping `undefined` and expressions
+    /// 6. RawPrimitiveStrategy - Handle raw primitives like "true", "12,111"
+    /// 7. StateMachineStrategy - State machine-based robust parsing
+    /// 8. HeuristicStrategy - Extract JSON from prose
+    /// 9. MarkdownStrategy - Extract from code blocks (if feature enabled)
+    /// 10. XmlTagStrategy - Extract from `<answer>`, `<json>` and `<output>` tags
+    /// 11. XmlStrategy - Convert XML-ish markup like `<user><name>..</name></user>`
+    /// 12. MarkdownTableStrategy - Convert pipe tables to arrays of objects
+    /// 13. KeyValueStrategy - Build an object from `Name: Alice` style lines
+    /// 14. HeadingSectionStrategy - Map `## Heading` sections to keys
+    /// 15. CsvStrategy - Convert CSV/TSV rows and ```` ```csv ```` fences to arrays of objects
+    /// 16. YamlStrategy - Parse YAML and convert to JSON (if feature enabled)
+    /// 17. TomlStrategy - Parse TOML and ```` ```toml ```` fences (if feature enabled)
     pub fn new() -> Self {
+        Self::with_duplicate_key_policy(DuplicateKeyPolicy::default())
+    }
+
+    /// Creates a parser with default strategies that resolve repeated object
+    /// keys by `policy` during direct parsing and repair.
+    ///
+    /// Each repeated key is recorded on the candidate as a
+    /// [`Transformation::DuplicateKey`](crate::value::Transformation::DuplicateKey);
+    /// with [`DuplicateKeyPolicy::Error`] a candidate that repeats a key is dropped, and
+    /// parsing fails with [`ParseError::DuplicateKey`] if no other candidate is found.
+    pub fn with_duplicate_key_policy(policy: DuplicateKeyPolicy) -> Self {
+        Self::with_policies(policy, NonFinitePolicy::default())
+    }
+
+    /// Creates a parser with default strategies that resolve repeated object
+    /// keys by `duplicate_keys`, as in [`Self::with_duplicate_key_policy`],
+    /// and represent `Infinity` and `NaN` in JSON5, Python and JavaScript
+    /// literals by `non_finite`.
+    ///
+    /// With [`NonFinitePolicy::Error`] a candidate holding `Infinity` or `NaN`
+    /// is dropped, and parsing fails with [`ParseError::NonFiniteNumber`] if no
+    /// other candidate is found.
+    pub fn with_policies(duplicate_keys: DuplicateKeyPolicy, non_finite: NonFinitePolicy) -> Self {
         let mut strategies: Vec<Box<dyn ParsingStrategy>> = vec![
-            Box::new(DirectJsonStrategy),
-            Box::new(JsonFixerStrategy::default()),
+            Box::new(WithDuplicateKeys {
+                strategy: DirectJsonStrategy,
+                policy: duplicate_keys,
+            }),
+            Box::new(
+                JsonFixerStrategy::default()
+                    .with_duplicate_key_policy(duplicate_keys)
+                    .with_non_finite_policy(non_finite),
+            ),
+            Box::new(
+                Json5Strategy::new()
+                    .with_duplicate_key_policy(duplicate_keys)
+                    .with_non_finite_policy(non_finite),
+            ),
+            Box::new(
+                PythonLiteralStrategy::new()
+                    .with_duplicate_key_policy(duplicate_keys)
+                    .with_non_finite_policy(non_finite),
+            ),
+            Box::new(
+                JavaScriptStrategy::new()
+                    .with_duplicate_key_policy(duplicate_keys)
+                    .with_non_finite_policy(non_finite),
+            ),
             Box::new(RawPrimitiveStrategy::new()),
-            Box::new(StateMachineStrategy::new()),
-            Box::new(HeuristicStrategy::default()),
-            Box::new(MarkdownStrategy::default()),
-            Box::new(MultipleObjectsStrategy::new()),
+            Box::new(StateMachineStrategy::new().with_duplicate_key_policy(duplicate_keys)),
+            Box::new(HeuristicStrategy::default().with_duplicate_key_policy(duplicate_keys)),
+            Box::new(
+                MarkdownStrategy::default()
+                    .with_duplicate_key_policy(duplicate_keys)
+                    .with_non_finite_policy(non_finite),
+            ),
+            Box::new(
+                XmlTagStrategy::default()
+                    .with_duplicate_key_policy(duplicate_keys)
+                    .with_non_finite_policy(non_finite),
+            ),
+            Box::new(WithDuplicateKeys {
+                strategy: MultipleObjectsStrategy::new(),
+                policy: duplicate_keys,
+            }),
+            Box::new(XmlStrategy::new()),
+            Box::new(MarkdownTableStrategy::new()),
+            Box::new(KeyValueStrategy::new()),
+            Box::new(HeadingSectionStrategy::new()),
+            Box::new(CsvStrategy::new()),
         ];
 
         #[cfg(feature = "yaml")]
@@ -83,10 +171,19 @@ impl FlexibleParser {
             strategies.push(Box::new(YamlStrategy));
         }
 
+        #[cfg(feature = "toml")]
+        {
+            strategies.push(Box::new(TomlStrategy::new()));
+        }
+
         // Sort by priority
         strategies.sort_by_key(|s| s.priority());
 
-        Self { strategies }
+        Self {
+            strategies,
+            duplicate_keys,
+            non_finite,
+        }
     }
 
     /// Creates a new parser with custom strategies.
@@ -94,7 +191,11 @@ impl FlexibleParser {
     /// Strategies will be sorted by priority automatically.
     pub fn with_strategies(mut strategies: Vec<Box<dyn ParsingStrategy>>) -> Self {
         strategies.sort_by_key(|s| s.priority());
-        Self { strategies }
+        Self {
+            strategies,
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            non_finite: NonFinitePolicy::default(),
+        }
     }
 
     /// Parses the input using all strategies and returns all candidates.
@@ -102,8 +203,24 @@ impl FlexibleParser {
     /// Each strategy is tried in priority order. All successful parses
     /// are returned as candidates.
     ///
-    /// Returns an empty vector if no strategy succeeds.
+    /// Returns an empty vector if no strategy succeeds. A strategy that
+    /// fails on a [`DuplicateKeyPolicy::Error`] or [`NonFinitePolicy::Error`]
+    /// rule contributes no candidate; that error is returned only if no
+    /// other strategy produced one.
     pub fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        match self.parse_with_rejection(input)? {
+            (candidates, Some(e)) if candidates.is_empty() => Err(e),
+            (candidates, _) => Ok(candidates),
+        }
+    }
+
+    /// Parses like [`Self::parse`], returning the candidates along with the
+    /// first duplicate key or non-finite error that dropped one, so callers
+    /// can report it if none of the others deserialize either.
+    pub(crate) fn parse_with_rejection(
+        &self,
+        input: &str,
+    ) -> Result<(Vec<FlexValue>, Option<ParseError>)> {
         // Use the new multi-stage approach which fixes the architectural flaw
         self.parse_multi_stage(input)
     }
@@ -122,7 +239,7 @@ impl FlexibleParser {
     /// Optimizations:
     /// - Early termination: Stops after finding candidates from high-priority strategies
     /// - Avoids extraction/fixing if direct parsing succeeds
-    fn parse_multi_stage(&self, input: &str) -> Result<Vec<FlexValue>> {
+    fn parse_multi_stage(&self, input: &str) -> Result<(Vec<FlexValue>, Option<ParseError>)> {
         // Pre-processing: Clean up common issues that break parsing
         let cleaner = GarbageCleaner::new();
 
@@ -138,6 +255,8 @@ impl FlexibleParser {
         let input = preprocessed.as_str();
 
         let mut all_candidates = Vec::new();
+        // The first candidate rejected by a duplicate key or non-finite rule
+        let mut policy_error = None;
 
         // Stage 0: Try all registered strategies on the full input first
         // This includes DirectJsonStrategy, YamlStrategy, etc.
@@ -171,9 +290,14 @@ impl FlexibleParser {
                         if needs_normalization {
                             all_candidates = self.normalize_candidates(all_candidates)?;
                         }
-                        return Ok(all_candidates);
+                        return Ok((all_candidates, policy_error));
                     }
                 }
+                // A repeated key under DuplicateKeyPolicy::Error, or a JSON5 NaN under
+                // NonFinitePolicy::Error, rejects this strategy's candidate
+                Err(e @ (ParseError::DuplicateKey { .. } | ParseError::NonFiniteNumber { .. })) => {
+                    policy_error.get_or_insert(e);
+                }
                 Err(_) => {
                     // Strategy failed, continue with others
                 }
@@ -182,7 +306,7 @@ impl FlexibleParser {
 
         // If we found candidates from strategies, return them
         if !all_candidates.is_empty() {
-            return Ok(all_candidates);
+            return Ok((all_candidates, policy_error));
         }
 
         // Stage 1: Extract candidates
@@ -190,23 +314,41 @@ impl FlexibleParser {
 
         // OPTIMIZATION: If no candidates extracted, return early
         if extracted.is_empty() {
-            return Ok(Vec::new());
+            return Ok((Vec::new(), policy_error));
         }
 
         // Stage 2: Clean candidates
         let cleaned = self.clean_candidates(extracted)?;
 
-        // Stage 3: For each cleaned candidate, try to parse
-        let fixer = JsonFixerStrategy::default();
+        // Stage 3: For each cleaned candidate, try to parse. Once a strategy has
+        // rejected a NaN or Infinity, repair mustn't bring it back as a string
+        let non_finite = match policy_error {
+            Some(ParseError::NonFiniteNumber { .. }) => NonFinitePolicy::Error,
+            _ => self.non_finite,
+        };
+        let fixer = JsonFixerStrategy::default()
+            .with_duplicate_key_policy(self.duplicate_keys)
+            .with_non_finite_policy(non_finite);
 
         for candidate in cleaned {
             // Try direct parsing first
-            if let Ok(value) = serde_json::from_str(&candidate.content) {
-                all_candidates.push(FlexValue::new(value, candidate.to_source()));
-
-                // OPTIMIZATION: If we successfully parsed without fixes, we have a good candidate
-                // Continue to collect all candidates but we know we have at least one good result
-                continue;
+            let source = candidate.to_source();
+            match parse_candidate(&candidate.content, self.duplicate_keys, |value| {
+                FlexValue::new(value, source)
+            }) {
+                Ok(Some(value)) => {
+                    all_candidates.push(value);
+
+                    // OPTIMIZATION: If we successfully parsed without fixes, we have a good candidate
+                    // Continue to collect all candidates but we know we have at least one good result
+                    continue;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    // Valid JSON with a forbidden repeated key; fixing won't help
+                    policy_error.get_or_insert(e);
+                    continue;
+                }
             }
 
             // If direct parsing failed, try applying fixes
@@ -223,6 +365,9 @@ impl FlexibleParser {
                     }
                     all_candidates.append(&mut fixed_candidates);
                 }
+                Err(e @ ParseError::DuplicateKey { .. }) => {
+                    policy_error.get_or_insert(e);
+                }
                 Err(_) => {
                     // This candidate couldn't be parsed even after fixes
                     continue;
@@ -230,7 +375,7 @@ impl FlexibleParser {
             }
         }
 
-        Ok(all_candidates)
+        Ok((all_candidates, policy_error))
     }
 
     /// Cleans extracted candidates to remove garbage and normalize.
@@ -265,6 +410,7 @@ impl FlexibleParser {
             }
 
             // Convert FlexValue back to JSON string, apply all normalizations, and re-parse
+            // (with the `preserve_order` feature this round trip keeps key order)
             match serde_json::to_string(&flex_value.value) {
                 Ok(json_str) => {
                     // Apply invisible character removal first
@@ -276,7 +422,13 @@ impl FlexibleParser {
                     if normalized_str != json_str {
                         // Something was normalized, create new FlexValue
                         if let Ok(new_value) = serde_json::from_str(&normalized_str) {
-                            normalized.push(FlexValue::new(new_value, flex_value.source));
+                            // Keep what was recorded while parsing, like repeated keys
+                            let mut renormalized =
+                                FlexValue::new(new_value, flex_value.source.clone());
+                            for transformation in flex_value.into_transformations() {
+                                renormalized.add_transformation(transformation);
+                            }
+                            normalized.push(renormalized);
                             continue;
                         }
                     }
@@ -302,6 +454,7 @@ impl FlexibleParser {
             Box::new(DirectExtractor),
             Box::new(HeuristicExtractor::default()),
             Box::new(MarkdownExtractor::default()),
+            Box::new(XmlTagExtractor::default()),
         ];
 
         // Run all extractors
@@ -341,12 +494,9 @@ mod tests {
     fn test_new_parser() {
         let parser = FlexibleParser::new();
 
-        // Markdown and MultipleObjects are always enabled, YAML is optional
-        #[cfg(feature = "yaml")]
-        assert_eq!(parser.strategy_count(), 8);
-
-        #[cfg(not(feature = "yaml"))]
-        assert_eq!(parser.strategy_count(), 7);
+        // Markdown and MultipleObjects are always enabled, YAML and TOML are optional
+        let optional = usize::from(cfg!(feature = "yaml")) + usize::from(cfg!(feature = "toml"));
+        assert_eq!(parser.strategy_count(), 16 + optional);
     }
 
     #[test]
@@ -8,10 +8,9 @@
 //! This is heavily inspired by BAML's jsonish parser but adapted for compile-time
 //! schema information.
 
-use serde_json::Value;
-
 use crate::{
     error::{ParseError, Result},
+    parser::duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
     value::{FlexValue, Source},
 };
 
@@ -108,6 +107,8 @@ pub struct StateMachineParser {
     escaped: bool,
     /// Accumulated candidates
     candidates: Vec<String>,
+    /// How repeated object keys in the candidates are resolved
+    duplicate_keys: DuplicateKeyPolicy,
 }
 
 impl StateMachineParser {
@@ -119,9 +120,16 @@ impl StateMachineParser {
             in_string: false,
             escaped: false,
             candidates: Vec::new(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
         }
     }
 
+    /// Sets how repeated object keys in the candidates are resolved.
+    pub fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
     /// Parse the input and return JSON candidates.
     pub fn parse(&mut self, input: &str) -> Result<Vec<FlexValue>> {
         self.reset();
@@ -266,14 +274,15 @@ impl StateMachineParser {
 
         for (index, candidate) in self.candidates.iter().enumerate() {
             // Try to parse as JSON
-            if let Ok(value) = serde_json::from_str::<Value>(candidate) {
-                let source = if use_multi_source {
-                    Source::MultiJson { index }
-                } else {
-                    Source::Direct
-                };
-                flex_values.push(FlexValue::new(value, source));
-            }
+            let source = if use_multi_source {
+                Source::MultiJson { index }
+            } else {
+                Source::Direct
+            };
+            let value = parse_candidate(candidate, self.duplicate_keys, |value| {
+                FlexValue::new(value, source)
+            })?;
+            flex_values.extend(value);
         }
 
         if flex_values.is_empty() {
@@ -0,0 +1,340 @@
+//! CSV/TSV strategy that converts delimited rows to arrays of objects.
+
+use serde_json::{Map, Value};
+
+use crate::{
+    error::Result,
+    parser::{
+        strategies::{Extractor, MarkdownExtractor, ParsingStrategy},
+        CandidateSource,
+    },
+    value::{FlexValue, Source},
+};
+
+/// Delimiters tried when sniffing, in order of preference on a tie.
+pub const CSV_DELIMITERS: &[char] = &[',', '\t', ';', '|'];
+
+/// Fence languages treated as delimited data.
+const FENCE_LANGUAGES: &[&str] = &["csv", "tsv"];
+
+/// Longest header cell, in characters, that still reads like a column name.
+const MAX_HEADER_LEN: usize = 50;
+
+/// Strategy that parses CSV or TSV into an array of objects keyed by the
+/// header row.
+///
+/// This strategy:
+/// 1. Looks for ```` ```csv ```` or ```` ```tsv ```` fences, or else blocks of
+///    lines (separated by blank lines) that read as delimited data
+/// 2. Sniffs the delimiter from [`CSV_DELIMITERS`]: the one that splits the most
+///    rows into as many fields as the header wins
+/// 3. Honors double-quoted fields, with `""` escapes and embedded delimiters
+///    and newlines
+///
+/// Cells are trimmed strings; empty cells and missing trailing cells leave
+/// the key out, and extra cells are dropped. Unfenced blocks must have at
+/// least two columns of short headers and at least two data rows, or one
+/// data row with a number under a header without any (`name,age\nAlice,30`),
+/// so two lines of comma prose aren't a table. Input containing `{` or `[`
+/// is left to the JSON extractors.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{CsvStrategy, ParsingStrategy};
+///
+/// let input = "name;city\nAlice;\"Paris; France\"\nBob;Lyon";
+/// let candidates = CsvStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value[0]["city"], "Paris; France");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CsvStrategy {
+    /// Finds ```` ```csv ```` fences.
+    fences: MarkdownExtractor,
+}
+
+impl CsvStrategy {
+    /// Creates a new CSV strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the content of ```` ```csv ```` and ```` ```tsv ```` fences.
+    fn fenced_blocks(&self, input: &str) -> Vec<String> {
+        self.fences
+            .extract(input)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|c| {
+                matches!(
+                    &c.source,
+                    CandidateSource::Markdown { language: Some(lang) }
+                        if FENCE_LANGUAGES.iter().any(|l| lang.eq_ignore_ascii_case(l))
+                )
+            })
+            .map(|c| c.content)
+            .collect()
+    }
+}
+
+impl ParsingStrategy for CsvStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        let fenced = self.fenced_blocks(input);
+        let tables: Vec<Value> = if !fenced.is_empty() {
+            fenced
+                .iter()
+                .filter_map(|block| table(block, false))
+                .collect()
+        } else if input.contains('{') || input.contains('[') {
+            Vec::new()
+        } else {
+            blocks(input)
+                .iter()
+                .filter_map(|block| table(block, true))
+                .collect()
+        };
+
+        Ok(tables
+            .into_iter()
+            .map(|t| FlexValue::new(t, Source::Csv))
+            .collect())
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        3 // Delimited rows are as structured as YAML, so they're tried as early
+    }
+}
+
+/// Splits `input` into blocks of consecutive non-blank lines.
+fn blocks(input: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current.join("\n"));
+    }
+    blocks
+}
+
+/// Converts delimited `text` to an array of objects, or returns `None` if no
+/// delimiter fits. `strict` also requires header cells that look like column
+/// names, for text that wasn't fenced as CSV.
+fn table(text: &str, strict: bool) -> Option<Value> {
+    let mut rows = sniff(text)?;
+    let header = rows.remove(0);
+
+    if strict
+        && header.iter().any(|h| {
+            h.is_empty() || h.chars().count() > MAX_HEADER_LEN || h.ends_with(['.', '!', '?', ':'])
+        })
+    {
+        return None;
+    }
+    // With one data row, only numbers under names tell a header from prose
+    if strict
+        && rows.len() < 2
+        && (header.iter().any(|h| is_number(h)) || !rows[0].iter().any(|c| is_number(c)))
+    {
+        return None;
+    }
+    // A markdown table's delimiter row; that's `MarkdownTableStrategy`'s job
+    if rows.first().is_some_and(|row| {
+        row.iter()
+            .all(|cell| !cell.is_empty() && cell.chars().all(|c| c == '-' || c == ':'))
+    }) {
+        return None;
+    }
+
+    let objects = rows
+        .into_iter()
+        .map(|row| {
+            let map: Map<String, Value> = header
+                .iter()
+                .zip(row)
+                .filter(|(_, cell)| !cell.is_empty())
+                .map(|(key, cell)| (key.clone(), Value::String(cell)))
+                .collect();
+            Value::Object(map)
+        })
+        .collect();
+    Some(Value::Array(objects))
+}
+
+/// Picks the delimiter that best fits `text` and returns its records.
+///
+/// A delimiter fits if the header has at least two fields and at least
+/// four in five data rows have as many.
+fn sniff(text: &str) -> Option<Vec<Vec<String>>> {
+    CSV_DELIMITERS
+        .iter()
+        .filter_map(|&delimiter| {
+            let rows = records(text, delimiter);
+            let columns = rows.first()?.len();
+            let data_rows = rows.len() - 1;
+            let consistent = rows[1..].iter().filter(|r| r.len() == columns).count();
+            (columns >= 2 && data_rows > 0 && consistent * 5 >= data_rows * 4)
+                .then_some((consistent, columns, rows))
+        })
+        // `max_by_key` keeps the last maximum, so walk in reverse for the first
+        .rev()
+        .max_by_key(|(consistent, columns, _)| (*consistent, *columns))
+        .map(|(_, _, rows)| rows)
+}
+
+/// Whether `cell` reads as a number or amount (`30`, `-2,5`, `$4.99`, `15%`).
+fn is_number(cell: &str) -> bool {
+    cell.chars().any(|c| c.is_ascii_digit())
+        && cell
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+' | '%' | '$'))
+}
+
+/// Splits `text` into records of trimmed fields.
+///
+/// Quoted fields may contain the delimiter, newlines and `""` for a quote;
+/// an unterminated quote runs to the end. Blank lines are skipped.
+fn records(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            '\r' => {}
+            '\n' => {
+                record.push(field.trim().to_string());
+                field.clear();
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            c if c == delimiter => {
+                record.push(field.trim().to_string());
+                field.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+
+    record.push(field.trim().to_string());
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn tables(input: &str) -> Vec<Value> {
+        CsvStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_sniffs_delimiters() {
+        let expected = vec![json!([{"name": "Alice", "age": "30"}, {"name": "Bob", "age": "25"}])];
+        assert_eq!(tables("name,age\nAlice,30\nBob,25"), expected);
+        assert_eq!(tables("name\tage\nAlice\t30\nBob\t25"), expected);
+        assert_eq!(tables("name; age\nAlice; 30\nBob; 25"), expected);
+        assert_eq!(tables("name|age\nAlice|30\nBob|25"), expected);
+
+        // Decimal commas don't fool the sniffer
+        assert_eq!(
+            tables("item;price\nTea;1,50\nCake;3,20")[0][1],
+            json!({"item": "Cake", "price": "3,20"})
+        );
+    }
+
+    #[test]
+    fn test_single_row_needs_numbers_under_names() {
+        assert_eq!(
+            tables("name, age\nAlice, 30"),
+            vec![json!([{"name": "Alice", "age": "30"}])]
+        );
+        assert_eq!(
+            tables("```csv\nred, green\nblue, yellow\n```"),
+            vec![json!([{"red": "blue", "green": "yellow"}])]
+        );
+    }
+
+    #[test]
+    fn test_quoted_fields() {
+        let input = "id,quote,note\r\n1,\"Hello, \"\"world\"\"\",\"two\nlines\"\r\n2,plain,";
+        assert_eq!(
+            tables(input),
+            vec![json!([
+                {"id": "1", "quote": "Hello, \"world\"", "note": "two\nlines"},
+                {"id": "2", "quote": "plain"},
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_blocks_in_prose_and_fences() {
+        let input = "Here you go:\n\nsku,qty\nA1,3\nB2,5\n\nAnything else?";
+        assert_eq!(
+            tables(input),
+            vec![json!([{"sku": "A1", "qty": "3"}, {"sku": "B2", "qty": "5"}])]
+        );
+
+        // A fence skips the header checks
+        let input = "```csv\nName.,Age!\nAlice,30\n```";
+        assert_eq!(
+            tables(input),
+            vec![json!([{"Name.": "Alice", "Age!": "30"}])]
+        );
+    }
+
+    #[test]
+    fn test_not_csv() {
+        assert!(tables("Sure, here it is.\nThanks, bye.").is_empty());
+        assert!(tables("just one line, with a comma").is_empty());
+        assert!(tables("red, green\nblue, yellow").is_empty());
+        assert!(tables("2023, 2024\n10, 12").is_empty());
+        assert!(tables("[{\"a\": 1}, {\"a\": 2}]").is_empty());
+        assert!(tables("| a | b |\n|---|---|\n| 1 | 2 |").is_empty());
+    }
+}
@@ -3,6 +3,7 @@
 use super::ParsingStrategy;
 use crate::{
     error::Result,
+    parser::duplicate_keys::{parse_candidate, DuplicateKeyPolicy, ParseWithDuplicateKeys},
     value::{FlexValue, Source},
 };
 
@@ -10,7 +11,11 @@ use crate::{
 ///
 /// This is the fastest strategy and should always be tried first.
 /// It succeeds only if the entire input (after trimming whitespace)
-/// is valid JSON.
+/// is valid JSON. A repeated object key keeps its last value, like
+/// serde_json, and is recorded on the candidate; [`FlexibleParser`]
+/// applies other [`DuplicateKeyPolicy`]s.
+///
+/// [`FlexibleParser`]: crate::parser::FlexibleParser
 ///
 /// # Examples
 ///
@@ -24,13 +29,12 @@ use crate::{
 #[derive(Debug, Clone, Copy, Default)]
 pub struct DirectJsonStrategy;
 
-impl ParsingStrategy for DirectJsonStrategy {
-    #[inline]
-    fn name(&self) -> &'static str {
-        "direct_json"
-    }
-
-    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+impl ParseWithDuplicateKeys for DirectJsonStrategy {
+    fn parse_with_duplicate_keys(
+        &self,
+        input: &str,
+        policy: DuplicateKeyPolicy,
+    ) -> Result<Vec<FlexValue>> {
         let trimmed = input.trim();
 
         // Fast path: check if it looks like JSON
@@ -38,10 +42,22 @@ impl ParsingStrategy for DirectJsonStrategy {
             return Ok(Vec::new());
         }
 
-        match serde_json::from_str(trimmed) {
-            Ok(value) => Ok(vec![FlexValue::new(value, Source::Direct)]),
-            Err(_) => Ok(Vec::new()), // Not an error, just not applicable
-        }
+        // Invalid JSON is not an error, just not applicable
+        let candidate = parse_candidate(trimmed, policy, |value| {
+            FlexValue::new(value, Source::Direct)
+        })?;
+        Ok(candidate.into_iter().collect())
+    }
+}
+
+impl ParsingStrategy for DirectJsonStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "direct_json"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        self.parse_with_duplicate_keys(input, DuplicateKeyPolicy::default())
     }
 
     #[inline]
@@ -70,6 +86,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
+    use crate::value::Transformation;
 
     #[test]
     fn test_parse_valid_json_object() {
@@ -114,6 +131,22 @@ mod tests {
         assert_eq!(result.len(), 0); // Not applicable
     }
 
+    #[test]
+    fn test_parse_repeated_key() {
+        let input = r#"{"tags": ["a"], "tags": ["b"]}"#;
+
+        let result = DirectJsonStrategy.parse(input).unwrap();
+        assert_eq!(result[0].value, json!({"tags": ["b"]}));
+        assert!(matches!(
+            result[0].transformations(),
+            [Transformation::DuplicateKey { path, .. }] if path == "tags"
+        ));
+
+        assert!(DirectJsonStrategy
+            .parse_with_duplicate_keys(input, DuplicateKeyPolicy::Error)
+            .is_err());
+    }
+
     #[test]
     fn test_looks_like_json() {
         assert!(looks_like_json("{"));
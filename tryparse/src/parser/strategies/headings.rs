@@ -0,0 +1,312 @@
+//! Markdown heading strategy that maps sections to object keys.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use super::{
+    key_value::{strip_bullet, unwrap_markup},
+    XML_TEXT_KEY,
+};
+use crate::{
+    error::Result,
+    parser::strategies::ParsingStrategy,
+    value::{FlexValue, Source},
+};
+
+/// Fewest headings that make a structured answer.
+const MIN_HEADINGS: usize = 2;
+
+/// Matches heading numbering like `1. `, `2.3 ` or `4) `.
+static NUMBERING: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?:(?:\d+\.)+\d*|\d+\))\s+").unwrap());
+
+/// Strategy that turns markdown heading hierarchies into nested objects.
+///
+/// Each `#`-style heading becomes a key (numbering like `1.`, a trailing
+/// colon and bold are removed), holding what follows it up to the next
+/// heading of the same or a higher level:
+///
+/// - A bullet or numbered list becomes an array of strings
+/// - Any other text becomes a string
+/// - Subheadings become a nested object; text before the first subheading
+///   goes under [`XML_TEXT_KEY`]
+/// - Repeated headings collect their sections into an array
+///
+/// Text before the first heading, empty sections and `#` lines inside code
+/// fences are ignored. Input containing `{` is left to the JSON extractors.
+///
+/// A single top-level heading yields two candidates, like
+/// [`XmlStrategy`](super::XmlStrategy): its sections, and the sections
+/// wrapped in the heading. Candidates get [`Source::Sections`], which ranks
+/// below every JSON source.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{HeadingSectionStrategy, ParsingStrategy};
+///
+/// let input = "## Summary\nAll good.\n\n## Risks\n- latency\n- cost";
+/// let candidates = HeadingSectionStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["Summary"], "All good.");
+/// assert_eq!(candidates[0].value["Risks"][1], "cost");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HeadingSectionStrategy;
+
+impl HeadingSectionStrategy {
+    /// Creates a new heading section strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ParsingStrategy for HeadingSectionStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "heading_sections"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        if !input.contains('#') || input.contains('{') {
+            return Ok(Vec::new());
+        }
+
+        let (sections, headings) = parse_sections(input);
+        if headings < MIN_HEADINGS {
+            return Ok(Vec::new());
+        }
+
+        let document = object(&sections);
+        let mut values = Vec::new();
+        if let [root] = sections.as_slice() {
+            if let Some(Value::Object(content)) = section_value(root) {
+                values.push(Value::Object(content));
+            }
+        }
+        if document.as_object().is_some_and(|m| !m.is_empty()) {
+            values.push(document);
+        }
+
+        Ok(values
+            .into_iter()
+            .map(|v| FlexValue::new(v, Source::Sections))
+            .collect())
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        5 // Same as key-value prose: a fallback when there is no JSON
+    }
+}
+
+/// A heading and what follows it.
+#[derive(Debug, Default)]
+struct Section {
+    key: String,
+    level: usize,
+    body: Vec<String>,
+    children: Vec<Section>,
+}
+
+/// Splits `input` into its top-level sections.
+///
+/// Returns the sections and the total number of headings.
+fn parse_sections(input: &str) -> (Vec<Section>, usize) {
+    // The bottom of the stack holds text before the first heading
+    let mut stack = vec![Section::default()];
+    let mut headings = 0;
+    let mut in_fence = false;
+
+    for line in input.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+
+        match heading(line).filter(|_| !in_fence) {
+            Some((level, key)) => {
+                headings += 1;
+                while stack.len() > 1 && stack.last().is_some_and(|s| s.level >= level) {
+                    close_top(&mut stack);
+                }
+                stack.push(Section {
+                    key,
+                    level,
+                    ..Section::default()
+                });
+            }
+            None => {
+                if let Some(section) = stack.last_mut() {
+                    section.body.push(line.to_string());
+                }
+            }
+        }
+    }
+
+    while stack.len() > 1 {
+        close_top(&mut stack);
+    }
+    let sections = stack.pop().map(|root| root.children).unwrap_or_default();
+    (sections, headings)
+}
+
+fn close_top(stack: &mut Vec<Section>) {
+    if let Some(section) = stack.pop() {
+        if let Some(parent) = stack.last_mut() {
+            parent.children.push(section);
+        }
+    }
+}
+
+/// Parses an ATX heading (`## Title ##`) into its level and key.
+fn heading(line: &str) -> Option<(usize, String)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let text = line.trim();
+    let level = text.chars().take_while(|&c| c == '#').count();
+    if indent > 3 || !(1..=6).contains(&level) {
+        return None;
+    }
+
+    let title = &text[level..];
+    if !title.is_empty() && !title.starts_with(char::is_whitespace) {
+        return None; // `#hashtag`
+    }
+
+    let title = title.trim().trim_end_matches('#').trim();
+    let title = NUMBERING.replace(title, "");
+    let key = unwrap_markup(title.trim_end_matches(':')).trim_end_matches(':');
+    (!key.is_empty()).then(|| (level, key.to_string()))
+}
+
+/// Converts sections to an object, collecting repeated keys into arrays.
+fn object(sections: &[Section]) -> Value {
+    let mut entries: Vec<(&str, Vec<Value>)> = Vec::new();
+    for section in sections {
+        let Some(value) = section_value(section) else {
+            continue;
+        };
+        match entries.iter_mut().find(|(k, _)| *k == section.key) {
+            Some((_, values)) => values.push(value),
+            None => entries.push((&section.key, vec![value])),
+        }
+    }
+
+    let map: Map<String, Value> = entries
+        .into_iter()
+        .map(|(key, mut values)| {
+            let value = if values.len() == 1 {
+                values.remove(0)
+            } else {
+                Value::Array(values)
+            };
+            (key.to_string(), value)
+        })
+        .collect();
+    Value::Object(map)
+}
+
+/// Converts a section's content, or returns `None` if it's empty.
+fn section_value(section: &Section) -> Option<Value> {
+    let body = body_value(&section.body);
+    if section.children.is_empty() {
+        return body;
+    }
+
+    let mut value = object(&section.children);
+    if let (Some(body), Value::Object(map)) = (body, &mut value) {
+        map.insert(XML_TEXT_KEY.to_string(), body);
+    }
+    Some(value)
+}
+
+/// Converts body text to an array if it's a list, or else a string.
+fn body_value(lines: &[String]) -> Option<Value> {
+    let text = lines.join("\n");
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut items: Vec<String> = Vec::new();
+    for raw in text.lines().filter(|l| !l.trim().is_empty()) {
+        let line = raw.trim();
+        match strip_bullet(line) {
+            Some(item) => items.push(unwrap_markup(item).to_string()),
+            // An indented line continues the bullet above it
+            None if !items.is_empty() && raw.starts_with(char::is_whitespace) => {
+                if let Some(last) = items.last_mut() {
+                    last.push(' ');
+                    last.push_str(line);
+                }
+            }
+            None => return Some(Value::String(text.to_string())),
+        }
+    }
+    Some(Value::Array(items.into_iter().map(Value::String).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn values(input: &str) -> Vec<Value> {
+        HeadingSectionStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_paragraphs_and_lists() {
+        let input = "Here is the report.\n\n\
+                     ## Summary\nThings went well.\nMostly.\n\n\
+                     ## Risks\n- latency\n* cost that\n  keeps growing\n1. **staffing**\n\n\
+                     ## Empty\n";
+        assert_eq!(
+            values(input),
+            vec![json!({
+                "Summary": "Things went well.\nMostly.",
+                "Risks": ["latency", "cost that keeps growing", "staffing"],
+            })]
+        );
+    }
+
+    #[test]
+    fn test_nested_headings_and_single_root() {
+        let input = "# Report\nIntro text.\n## 1. Findings:\n### Speed\nFast.\n### Cost\nLow.\n## **Next Steps** ##\n- ship";
+        let content = json!({
+            "Findings": {"Speed": "Fast.", "Cost": "Low."},
+            "Next Steps": ["ship"],
+            "#text": "Intro text.",
+        });
+        assert_eq!(
+            values(input),
+            vec![content.clone(), json!({ "Report": content })]
+        );
+    }
+
+    #[test]
+    fn test_repeated_headings_and_code_fences() {
+        let input = "## Step\nA\n## Step\n```sh\n# not a heading\n```\n## Note\nx";
+        assert_eq!(
+            values(input),
+            vec![json!({"Step": ["A", "```sh\n# not a heading\n```"], "Note": "x"})]
+        );
+    }
+
+    #[test]
+    fn test_not_sections() {
+        assert!(values("# Only one\ntext").is_empty());
+        assert!(values("#rust #json\n#tags").is_empty());
+        assert!(values("## A\n{\"a\": 1}\n## B\nx").is_empty());
+        assert_eq!(
+            values("## 2024 Plan\nx\n## 2.1 Goals\ny")[0],
+            json!({"2024 Plan": "x", "Goals": "y"})
+        );
+    }
+}
@@ -6,6 +6,7 @@
 use super::ParsingStrategy;
 use crate::{
     error::Result,
+    parser::duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
     value::{FlexValue, Source},
 };
 
@@ -19,6 +20,8 @@ const MAX_INPUT_SIZE: usize = 1024 * 1024;
 /// - Extracts potential JSON boundaries
 /// - Attempts to parse each candidate
 ///
+/// Repeated object keys are resolved by its [`DuplicateKeyPolicy`].
+///
 /// # Examples
 ///
 /// ```
@@ -33,6 +36,8 @@ const MAX_INPUT_SIZE: usize = 1024 * 1024;
 pub struct HeuristicStrategy {
     /// Maximum number of candidates to try
     max_candidates: usize,
+    /// How repeated object keys are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
 }
 
 impl Default for HeuristicStrategy {
@@ -45,13 +50,23 @@ impl HeuristicStrategy {
     /// Creates a new heuristic strategy with default settings.
     #[inline]
     pub fn new() -> Self {
-        Self { max_candidates: 20 }
+        Self::with_max_candidates(20)
     }
 
     /// Creates a new strategy with custom max candidates.
     #[inline]
     pub const fn with_max_candidates(max_candidates: usize) -> Self {
-        Self { max_candidates }
+        Self {
+            max_candidates,
+            duplicate_keys: DuplicateKeyPolicy::LastWins,
+        }
+    }
+
+    /// Sets how repeated object keys are resolved.
+    #[inline]
+    pub const fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
     }
 
     /// Extracts potential JSON boundaries from text.
@@ -177,14 +192,15 @@ impl ParsingStrategy for HeuristicStrategy {
             let substring = &input[*start..*end];
 
             // Try to parse it
-            if let Ok(value) = serde_json::from_str(substring) {
-                candidates.push(FlexValue::new(
+            let candidate = parse_candidate(substring, self.duplicate_keys, |value| {
+                FlexValue::new(
                     value,
                     Source::Heuristic {
                         pattern: pattern.to_string(),
                     },
-                ));
-            }
+                )
+            })?;
+            candidates.extend(candidate);
         }
 
         Ok(candidates)
@@ -0,0 +1,1319 @@
+//! JavaScript object literal strategy with a tolerant expression grammar.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{
+    literal::{Fail, LiteralSyntax, Step},
+    python::Node,
+    NonFinitePolicy, ParsingStrategy,
+};
+use crate::{
+    datetime::civil::{civil_date, civil_from_days, days_from_civil, CivilDate},
+    error::Result,
+    parser::{
+        duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
+        strategies::MarkdownExtractor,
+        MAX_NESTING_DEPTH,
+    },
+    value::{FlexValue, JsonFix, Transformation},
+};
+
+/// Characters that continue an expression after a value, as in `1 + 2` or
+/// `'a'.toUpperCase()`.
+const OPERATORS: &str = "+-*/%?.([<>=&|!^~";
+
+/// Matches `const result = `, `export default `, `module.exports = ` and
+/// similar, including a TypeScript type annotation.
+static WRAPPER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:export\s+default\s+|(?:module\.)?exports(?:\.[\w$]+)?\s*=\s*|return\s+|(?:export\s+)?(?:const|let|var)\s+[\w$]+(?:\s*:\s*[^=]+?)?\s*=\s*)",
+    )
+    .unwrap()
+});
+
+/// Matches where a literal may start in prose.
+static EMBEDDED_START: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[{\[]|\b(?:export\s+default|module\.exports|exports\.|const|let|var|return)\b")
+        .unwrap()
+});
+
+/// Matches the ISO 8601 strings `Date` parses: a date, optionally with a
+/// time and an offset.
+static ISO_DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(\d{4})-(\d{2})-(\d{2})(?:T(\d{2}):(\d{2})(?::(\d{2})(?:\.\d{1,9})?)?(?:Z|[+-]\d{2}:\d{2})?)?$",
+    )
+    .unwrap()
+});
+
+/// Strategy that reads JavaScript (and TypeScript) object literals, keeping
+/// what has a JSON value and dropping the rest.
+///
+/// On top of the JSON5 syntax, this strategy reads:
+/// - Wrappers: `const result = {...};`, `export default`,
+///   `module.exports =`, `return`, and a trailing `as const`
+/// - Template literals without `${}`, regex literals (as strings), `0o`/`0b`
+///   numbers, `_` separators and BigInt `n` suffixes
+/// - Computed keys with a literal inside (`["a-b"]: 1`), and spreads of
+///   literals (`...{a: 1}`, `...[1, 2]`)
+/// - `new Date("2024-01-15")`, `new Date(2024, 0, 15)` and
+///   `new Date(1705276800000)`, as ISO 8601 strings. Other date strings,
+///   like `new Date("March 3, 2024")`, are dropped, since engines read them
+///   differently.
+///
+/// Like `JSON.stringify`, it leaves out members that are `undefined`,
+/// functions, methods, variable references, shorthand properties or other
+/// expressions (`a + b`, `x.map(...)`), and writes them as `null` in arrays.
+/// Each is recorded on the candidate as a
+/// [`Transformation::UndefinedRemoved`], [`Transformation::DateToString`] or
+/// [`Transformation::ExpressionDropped`] with its path, and syntax rewrites
+/// as [`JsonFix`] entries in [`Source::Fixed`](crate::value::Source::Fixed).
+///
+/// The whole input is tried first, then ```` ```js ```` and
+/// ```` ```ts ```` fences, then literals inside prose. Literals that JSON5
+/// already reads are left to [`Json5Strategy`](super::Json5Strategy), and a
+/// literal whose members were all dropped is rejected.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{JavaScriptStrategy, ParsingStrategy};
+///
+/// let input = "const user = {name: 'Alice', email: undefined, joined: new Date('2024-01-15')};";
+/// let candidates = JavaScriptStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["joined"], "2024-01-15");
+/// assert!(candidates[0].value.get("email").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JavaScriptStrategy {
+    /// Finds ```` ```js ```` fences.
+    fences: MarkdownExtractor,
+    /// How `Infinity` and `NaN` are represented.
+    non_finite: NonFinitePolicy,
+    /// How repeated object keys are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
+}
+
+impl JavaScriptStrategy {
+    /// Creates a new JavaScript strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how `Infinity` and `NaN` are represented.
+    #[must_use]
+    pub const fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Sets how repeated object keys are resolved.
+    #[must_use]
+    pub const fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+}
+
+impl LiteralSyntax for JavaScriptStrategy {
+    type Literal = Literal;
+
+    const FENCE_LANGUAGES: &'static [&'static str] = &[
+        "javascript",
+        "js",
+        "jsx",
+        "mjs",
+        "cjs",
+        "typescript",
+        "ts",
+        "tsx",
+    ];
+
+    fn fences(&self) -> &MarkdownExtractor {
+        &self.fences
+    }
+
+    fn read_document(&self, text: &str) -> Step<Literal> {
+        let mut reader = Reader::new(text, self.non_finite);
+        let node = reader.statement(true)?;
+        Ok(reader.finish(node))
+    }
+
+    fn read_embedded(&self, text: &str) -> Step<(Literal, usize)> {
+        let mut reader = Reader::new(text, self.non_finite);
+        let node = reader.statement(false)?;
+        let len = reader.pos;
+        Ok((reader.finish(node), len))
+    }
+
+    fn next_start(&self, input: &str, from: usize) -> Option<usize> {
+        EMBEDDED_START.find_at(input, from).map(|m| m.start())
+    }
+
+    /// Skips scalars, literals JSON5 reads too, and literals that kept no
+    /// members when some were dropped or the literal sat in prose, as with
+    /// `[the docs]` or `` `arr[i]` ``.
+    fn candidate(
+        &self,
+        literal: Literal,
+        _text: &str,
+        embedded: bool,
+    ) -> Result<Option<FlexValue>> {
+        if matches!(literal.node, Node::Scalar(_)) || !literal.js_only {
+            return Ok(None);
+        }
+        let dropped = literal
+            .transformations
+            .iter()
+            .any(|t| matches!(t, Transformation::ExpressionDropped { .. }));
+        if literal.kept == 0 && (embedded || dropped) {
+            return Ok(None);
+        }
+
+        let mut json = String::new();
+        literal.node.render(&mut json);
+        let candidate = parse_candidate(&json, self.duplicate_keys, |value| {
+            FlexValue::from_fixed_json(value, literal.fixes)
+        })?;
+        Ok(candidate.map(|mut candidate| {
+            for transformation in literal.transformations {
+                candidate.add_transformation(transformation);
+            }
+            candidate
+        }))
+    }
+}
+
+impl ParsingStrategy for JavaScriptStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "javascript"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        self.parse_literals(input)
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        3 // Same as the fixer: syntax beyond plain JSON
+    }
+}
+
+/// A literal and what reading it took.
+pub(super) struct Literal {
+    node: Node,
+    /// Top-level members read as values, not dropped or `undefined`.
+    kept: usize,
+    fixes: Vec<JsonFix>,
+    transformations: Vec<Transformation>,
+    /// Whether anything beyond JSON5 was used.
+    js_only: bool,
+}
+
+/// Reads JavaScript literal syntax.
+struct Reader<'a> {
+    text: &'a str,
+    /// Byte offset of the next character.
+    pos: usize,
+    non_finite: NonFinitePolicy,
+    /// Syntax rewrites used so far, in order of first use.
+    fixes: Vec<JsonFix>,
+    transformations: Vec<Transformation>,
+    /// Whether anything beyond JSON5 was used.
+    js_only: bool,
+    /// Top-level members read as values, not dropped or `undefined`.
+    kept: usize,
+}
+
+/// Where to rewind to when a value turns out to be part of an expression.
+#[derive(Clone, Copy)]
+struct Mark {
+    pos: usize,
+    fixes: usize,
+    transformations: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(text: &'a str, non_finite: NonFinitePolicy) -> Self {
+        Self {
+            text,
+            pos: 0,
+            non_finite,
+            fixes: Vec::new(),
+            transformations: Vec::new(),
+            js_only: false,
+            kept: 0,
+        }
+    }
+
+    /// Returns `node` with the fixes and transformations reading it took.
+    fn finish(self, node: Node) -> Literal {
+        Literal {
+            node,
+            kept: self.kept,
+            fixes: self.fixes,
+            transformations: self.transformations,
+            js_only: self.js_only,
+        }
+    }
+
+    /// Reads an optionally wrapped literal. With `whole`, nothing but a `;`
+    /// may follow it.
+    fn statement(&mut self, whole: bool) -> Step<Node> {
+        self.skip_trivia();
+        if let Some(wrapper) = WRAPPER.find(self.rest()) {
+            self.pos += wrapper.end();
+            self.fix(JsonFix::JavaScriptWrappers);
+            self.js_only = true;
+        }
+
+        let node = self.value("", 0)?.ok_or(Fail::Syntax)?;
+        self.skip_trivia();
+        // TypeScript's `as const` or `satisfies Config`
+        if self.eat_word("as") || self.eat_word("satisfies") {
+            self.js_only = true;
+            while self.peek().is_some_and(|c| c != ';' && c != '\n') {
+                self.bump();
+            }
+            self.skip_trivia();
+        }
+        self.eat(';');
+
+        self.skip_trivia();
+        if whole && self.pos < self.text.len() {
+            return Err(Fail::Syntax);
+        }
+        Ok(node)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.pos += expected.len_utf8();
+        }
+        found
+    }
+
+    /// Eats `word` if it's a whole word here.
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = self.rest().starts_with(word)
+            && !self.rest()[word.len()..]
+                .chars()
+                .next()
+                .is_some_and(is_identifier_part);
+        if found {
+            self.pos += word.len();
+        }
+        found
+    }
+
+    fn fix(&mut self, fix: JsonFix) {
+        if !self.fixes.contains(&fix) {
+            self.fixes.push(fix);
+        }
+    }
+
+    /// Records a transformation; each one is beyond JSON5.
+    fn record(&mut self, transformation: Transformation) {
+        self.js_only = true;
+        self.transformations.push(transformation);
+    }
+
+    /// Counts `members` kept by the container at `depth`, if it's the
+    /// top-level one.
+    fn keep(&mut self, depth: usize, members: usize) {
+        if depth == 0 {
+            self.kept += members;
+        }
+    }
+
+    fn mark(&self) -> Mark {
+        Mark {
+            pos: self.pos,
+            fixes: self.fixes.len(),
+            transformations: self.transformations.len(),
+        }
+    }
+
+    fn rewind(&mut self, mark: Mark) {
+        self.pos = mark.pos;
+        self.fixes.truncate(mark.fixes);
+        self.transformations.truncate(mark.transformations);
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            if rest.starts_with(|c: char| c.is_whitespace() || c == '\u{FEFF}') {
+                self.bump();
+            } else if rest.starts_with("//") {
+                self.fix(JsonFix::Comments);
+                self.pos += rest.find('\n').unwrap_or(rest.len());
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                self.fix(JsonFix::Comments);
+                self.pos += comment.find("*/").map_or(rest.len(), |end| end + 4);
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Reads a value and checks nothing continues it, or drops the whole
+    /// expression if something does.
+    fn item(&mut self, path: &str, depth: usize) -> Step<Option<Node>> {
+        let mark = self.mark();
+        let node = self.value(path, depth)?;
+        self.skip_trivia();
+        match self.peek() {
+            None | Some(',' | '}' | ']' | ')' | ';') => Ok(node),
+            Some(c) if OPERATORS.contains(c) => {
+                self.rewind(mark);
+                self.drop_expression(path)
+            }
+            _ if self.rest().starts_with("instanceof") || self.rest().starts_with("in ") => {
+                self.rewind(mark);
+                self.drop_expression(path)
+            }
+            _ => Err(Fail::Syntax),
+        }
+    }
+
+    /// Reads a value, or returns `None` if it was `undefined` or dropped.
+    fn value(&mut self, path: &str, depth: usize) -> Step<Option<Node>> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(Fail::Syntax);
+        }
+        self.skip_trivia();
+        match self.peek().ok_or(Fail::Syntax)? {
+            '{' => self.object(path, depth).map(Some),
+            '[' => self.array(path, depth).map(Some),
+            quote @ ('"' | '\'' | '`') => match self.string(quote)? {
+                Some(s) => Ok(Some(Node::string(&s))),
+                // A template with `${}` needs evaluating
+                None => self.drop_expression(path),
+            },
+            '/' => self.regex().map(Some),
+            '(' => self.drop_expression(path),
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => self.number().map(Some),
+            c if is_identifier_start(c) => self.word(path, depth),
+            _ => Err(Fail::Syntax),
+        }
+    }
+
+    /// Reads a value that starts with a name: a keyword, `new Date(...)`,
+    /// or an expression to drop.
+    fn word(&mut self, path: &str, depth: usize) -> Step<Option<Node>> {
+        let start = self.pos;
+        if self.eat_word("true") {
+            return Ok(Some(Node::Scalar("true".to_string())));
+        }
+        if self.eat_word("false") {
+            return Ok(Some(Node::Scalar("false".to_string())));
+        }
+        if self.eat_word("null") {
+            return Ok(Some(Node::Scalar("null".to_string())));
+        }
+        if self.eat_word("undefined") {
+            self.record(Transformation::UndefinedRemoved {
+                path: path.to_string(),
+            });
+            return Ok(None);
+        }
+        if self.rest().starts_with("NaN") || self.rest().starts_with("Infinity") {
+            return self.number().map(Some);
+        }
+
+        if self.eat_word("new") {
+            self.skip_trivia();
+        }
+        if self.eat_word("Date") {
+            if let Some(node) = self.date(start, depth)? {
+                self.record(Transformation::DateToString {
+                    path: path.to_string(),
+                    original: self.text[start..self.pos].trim().to_string(),
+                });
+                return Ok(Some(node));
+            }
+        }
+        self.pos = start;
+        self.drop_expression(path)
+    }
+
+    /// Reads the arguments of a `Date` constructor and converts them to an
+    /// ISO 8601 string, or returns `None` for arguments it can't convert.
+    fn date(&mut self, start: usize, depth: usize) -> Step<Option<Node>> {
+        self.skip_trivia();
+        if !self.eat('(') {
+            return Ok(None);
+        }
+
+        let mark = self.mark();
+        let mut args = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.eat(')') {
+                break;
+            }
+            match self.value("", depth + 1) {
+                Ok(Some(Node::Scalar(json))) => args.push(json),
+                _ => {
+                    self.rewind(mark);
+                    return Ok(None);
+                }
+            }
+            self.skip_trivia();
+            if !self.eat(',') && self.peek() != Some(')') {
+                self.rewind(mark);
+                return Ok(None);
+            }
+        }
+
+        let iso = match args.as_slice() {
+            [arg] if arg.starts_with('"') => serde_json::from_str::<String>(arg)
+                .ok()
+                .filter(|s| is_iso_date(s)),
+            [millis] => millis
+                .parse::<f64>()
+                .ok()
+                .and_then(|ms| iso_from_millis(ms as i64)),
+            [_, _, ..] => {
+                let parts: Option<Vec<i64>> = args
+                    .iter()
+                    .map(|a| a.parse::<f64>().ok().map(|n| n as i64))
+                    .collect();
+                parts.and_then(|p| iso_from_parts(&p))
+            }
+            [] => None, // The current time
+        };
+        let Some(iso) = iso else {
+            self.pos = start;
+            return Ok(None);
+        };
+
+        // `new Date(...).toISOString()` is the same string
+        let after = self.pos;
+        self.skip_trivia();
+        if !(self.eat('.') && (self.eat_word("toISOString") || self.eat_word("toJSON")) && {
+            self.skip_trivia();
+            self.eat('(') && {
+                self.skip_trivia();
+                self.eat(')')
+            }
+        }) {
+            self.pos = after;
+        }
+        Ok(Some(Node::string(&iso)))
+    }
+
+    /// Skips the expression here and records it as dropped. Values in
+    /// arrays become `null`; object members are left out.
+    fn drop_expression(&mut self, path: &str) -> Step<Option<Node>> {
+        let start = self.pos;
+        self.skip_expression();
+        let expression = self.text[start..self.pos].trim();
+        if expression.is_empty() {
+            return Err(Fail::Syntax);
+        }
+
+        if expression.starts_with("function")
+            || expression.starts_with("async")
+            || expression.contains("=>")
+        {
+            self.fix(JsonFix::JavaScriptFunctions);
+        }
+        self.record(Transformation::ExpressionDropped {
+            path: path.to_string(),
+            expression: expression.to_string(),
+        });
+        Ok(None)
+    }
+
+    /// Moves past an expression, up to a `,`, `;` or closing bracket outside
+    /// any nesting.
+    fn skip_expression(&mut self) {
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => return,
+                ')' | ']' | '}' => depth -= 1,
+                ',' | ';' if depth == 0 => return,
+                '"' | '\'' | '`' => {
+                    self.skip_quoted(c);
+                    continue;
+                }
+                '/' if self.rest().starts_with("//") || self.rest().starts_with("/*") => {
+                    self.skip_trivia();
+                    continue;
+                }
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
+    fn skip_quoted(&mut self, quote: char) {
+        self.bump();
+        while let Some(c) = self.bump() {
+            if c == '\\' {
+                self.bump();
+            } else if c == quote {
+                return;
+            }
+        }
+    }
+
+    fn object(&mut self, path: &str, depth: usize) -> Step<Node> {
+        self.bump(); // '{'
+        let mut entries = Vec::new();
+
+        loop {
+            self.skip_trivia();
+            if self.eat('}') {
+                break;
+            }
+
+            let member = self.pos;
+            if self.rest().starts_with("...") {
+                self.pos += 3;
+                self.js_only = true;
+                self.skip_trivia();
+                if self.peek() == Some('{') {
+                    let Node::Object(spread) = self.object(path, depth + 1)? else {
+                        return Err(Fail::Syntax);
+                    };
+                    self.keep(depth, spread.len());
+                    entries.extend(spread);
+                } else {
+                    self.pos = member;
+                    self.drop_expression(path)?;
+                }
+            } else if let Some(key) = self.key(depth)? {
+                self.skip_trivia();
+                if self.eat(':') {
+                    let child = child_path(path, &key);
+                    if let Some(value) = self.item(&child, depth + 1)? {
+                        self.keep(depth, 1);
+                        entries.push((key, value));
+                    }
+                } else if matches!(self.peek(), Some(',' | '}' | '(')) {
+                    // A shorthand property or a method
+                    self.pos = member;
+                    self.drop_expression(&child_path(path, &key))?;
+                } else if is_accessor(&key) {
+                    // An accessor, recorded under the name after `get` and co
+                    self.eat('*');
+                    self.skip_trivia();
+                    let name = match self.key(depth) {
+                        Ok(Some(name)) => name,
+                        _ => key,
+                    };
+                    self.pos = member;
+                    self.drop_expression(&child_path(path, &name))?;
+                } else {
+                    return Err(Fail::Syntax);
+                }
+            } else {
+                // A computed key we can't evaluate
+                self.pos = member;
+                self.drop_expression(path)?;
+            }
+
+            self.skip_trivia();
+            if self.eat(',') {
+                self.skip_trivia();
+                if self.eat('}') {
+                    self.fix(JsonFix::TrailingCommas);
+                    break;
+                }
+            } else if self.eat('}') {
+                break;
+            } else {
+                return Err(Fail::Syntax);
+            }
+        }
+        Ok(Node::Object(entries))
+    }
+
+    /// Reads an object key, or returns `None` for a computed key that isn't
+    /// a literal.
+    fn key(&mut self, depth: usize) -> Step<Option<String>> {
+        match self.peek().ok_or(Fail::Syntax)? {
+            quote @ ('"' | '\'' | '`') => Ok(self.string(quote)?),
+            '[' => {
+                self.bump();
+                self.js_only = true;
+                let mark = self.mark();
+                let key = match self.value("", depth + 1) {
+                    Ok(Some(node @ Node::Scalar(_))) => Some(node.into_key()),
+                    _ => None,
+                };
+                self.skip_trivia();
+                if key.is_some() && self.eat(']') {
+                    Ok(key)
+                } else {
+                    self.rewind(mark);
+                    Ok(None)
+                }
+            }
+            c if c.is_ascii_digit() || c == '.' => Ok(Some(self.number()?.into_key())),
+            c if is_identifier_start(c) => {
+                let start = self.pos;
+                while self.peek().is_some_and(is_identifier_part) {
+                    self.bump();
+                }
+                self.fix(JsonFix::UnquotedKeys);
+                Ok(Some(self.text[start..self.pos].to_string()))
+            }
+            _ => Err(Fail::Syntax),
+        }
+    }
+
+    fn array(&mut self, path: &str, depth: usize) -> Step<Node> {
+        self.bump(); // '['
+        let mut items = Vec::new();
+
+        loop {
+            self.skip_trivia();
+            if self.eat(']') {
+                break;
+            }
+
+            let child = format!("{}[{}]", path, items.len());
+            if self.peek() == Some(',') {
+                // A hole, as in `[1, , 3]`
+                self.record(Transformation::UndefinedRemoved { path: child });
+                items.push(Node::Scalar("null".to_string()));
+            } else if self.rest().starts_with("...") {
+                let spread = self.pos;
+                self.pos += 3;
+                self.js_only = true;
+                self.skip_trivia();
+                if self.peek() == Some('[') {
+                    let Node::Array(spread) = self.array(&child, depth + 1)? else {
+                        return Err(Fail::Syntax);
+                    };
+                    self.keep(depth, spread.len());
+                    items.extend(spread);
+                } else {
+                    self.pos = spread;
+                    self.drop_expression(&child)?;
+                }
+            } else {
+                match self.item(&child, depth + 1)? {
+                    Some(item) => {
+                        self.keep(depth, 1);
+                        items.push(item);
+                    }
+                    None => items.push(Node::Scalar("null".to_string())),
+                }
+            }
+
+            self.skip_trivia();
+            if self.eat(',') {
+                self.skip_trivia();
+                if self.eat(']') {
+                    self.fix(JsonFix::TrailingCommas);
+                    break;
+                }
+            } else if self.eat(']') {
+                break;
+            } else {
+                return Err(Fail::Syntax);
+            }
+        }
+        Ok(Node::Array(items))
+    }
+
+    /// Reads a quoted string, or returns `None` for a template literal with
+    /// `${}` substitutions.
+    fn string(&mut self, quote: char) -> Step<Option<String>> {
+        let start = self.pos;
+        self.bump();
+        match quote {
+            '\'' => self.fix(JsonFix::SingleQuotes),
+            '`' => {
+                self.fix(JsonFix::TemplateLiterals);
+                self.js_only = true;
+            }
+            _ => {}
+        }
+
+        let mut s = String::new();
+        loop {
+            match self.bump().ok_or(Fail::Syntax)? {
+                c if c == quote => return Ok(Some(s)),
+                '$' if quote == '`' && self.peek() == Some('{') => {
+                    self.pos = start;
+                    return Ok(None);
+                }
+                '\\' => self.escape(&mut s)?,
+                c @ ('\n' | '\r') if quote != '`' => {
+                    self.fix(JsonFix::UnescapedNewlines);
+                    s.push(c);
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    /// Decodes the escape after a `\` in a string.
+    fn escape(&mut self, s: &mut String) -> Step<()> {
+        let c = self.bump().ok_or(Fail::Syntax)?;
+        match c {
+            '"' | '\'' | '`' | '\\' | '/' | '$' => s.push(c),
+            'b' => s.push('\u{8}'),
+            'f' => s.push('\u{C}'),
+            'n' => s.push('\n'),
+            'r' => s.push('\r'),
+            't' => s.push('\t'),
+            'v' => {
+                self.fix(JsonFix::ExtendedEscapes);
+                s.push('\u{B}');
+            }
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                self.fix(JsonFix::ExtendedEscapes);
+                s.push('\0');
+            }
+            'x' => {
+                self.fix(JsonFix::ExtendedEscapes);
+                let code = self.hex_digits(2)?;
+                s.push(char::from_u32(code).ok_or(Fail::Syntax)?);
+            }
+            'u' if self.eat('{') => {
+                self.js_only = true;
+                let end = self.rest().find('}').ok_or(Fail::Syntax)?;
+                let code =
+                    u32::from_str_radix(&self.rest()[..end], 16).map_err(|_| Fail::Syntax)?;
+                self.pos += end + 1;
+                s.push(char::from_u32(code).ok_or(Fail::Syntax)?);
+            }
+            'u' => s.push(self.unicode_escape()?),
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                self.fix(JsonFix::LineContinuations);
+                if c == '\r' {
+                    self.eat('\n');
+                }
+            }
+            c => {
+                self.fix(JsonFix::ExtendedEscapes);
+                s.push(c);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the `XXXX` of a `\uXXXX` escape, joining surrogate pairs.
+    fn unicode_escape(&mut self) -> Step<char> {
+        let high = self.hex_digits(4)?;
+        if (0xD800..0xDC00).contains(&high) && self.rest().starts_with("\\u") {
+            let saved = self.pos;
+            self.pos += 2;
+            let low = self.hex_digits(4)?;
+            if (0xDC00..0xE000).contains(&low) {
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return char::from_u32(code).ok_or(Fail::Syntax);
+            }
+            self.pos = saved;
+        }
+        Ok(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Step<u32> {
+        let digits = self.rest().get(..count).ok_or(Fail::Syntax)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Fail::Syntax);
+        }
+        self.pos += count;
+        u32::from_str_radix(digits, 16).map_err(|_| Fail::Syntax)
+    }
+
+    /// Reads a regex literal like `/ab+c/gi` as a string.
+    fn regex(&mut self) -> Step<Node> {
+        let start = self.pos;
+        self.bump(); // '/'
+        let mut in_class = false;
+        loop {
+            match self.bump().ok_or(Fail::Syntax)? {
+                '\\' => {
+                    self.bump();
+                }
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '/' if !in_class => break,
+                '\n' => return Err(Fail::Syntax),
+                _ => {}
+            }
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            self.bump();
+        }
+
+        self.fix(JsonFix::RegexLiterals);
+        self.js_only = true;
+        Ok(Node::string(&self.text[start..self.pos]))
+    }
+
+    fn number(&mut self) -> Step<Node> {
+        let negative = match self.peek() {
+            Some('-') => {
+                self.bump();
+                true
+            }
+            Some('+') => {
+                self.bump();
+                self.fix(JsonFix::PlusSigns);
+                false
+            }
+            _ => false,
+        };
+        let sign = if negative { "-" } else { "" };
+
+        for literal in ["Infinity", "NaN"] {
+            if self.eat_word(literal) {
+                self.fix(JsonFix::NonFiniteNumbers);
+                let name = if literal == "NaN" {
+                    literal.to_string()
+                } else {
+                    format!("{}{}", sign, literal)
+                };
+                return match self.non_finite {
+                    NonFinitePolicy::Null => Ok(Node::Scalar("null".to_string())),
+                    NonFinitePolicy::String => Ok(Node::string(&name)),
+                    NonFinitePolicy::Error => Err(Fail::NonFinite(name)),
+                };
+            }
+        }
+
+        let radix = match self.rest().get(..2).map(str::to_ascii_lowercase).as_deref() {
+            Some("0x") => 16,
+            Some("0o") => 8,
+            Some("0b") => 2,
+            _ => 10,
+        };
+        let mut json = sign.to_string();
+        if radix != 10 {
+            self.pos += 2;
+            // The fix covers any radix prefix; `0o` and `0b` are beyond JSON5
+            self.fix(JsonFix::HexNumbers);
+            if radix != 16 {
+                self.js_only = true;
+            }
+            let digits = self.digits(|c| c.is_digit(radix));
+            let value = u128::from_str_radix(&digits, radix).map_err(|_| Fail::Syntax)?;
+            json.push_str(&value.to_string());
+            self.eat('n');
+            return self.finish_number(json);
+        }
+
+        let integer = self.digits(|c| c.is_ascii_digit());
+        let fraction = if self.eat('.') {
+            Some(self.digits(|c| c.is_ascii_digit()))
+        } else {
+            None
+        };
+        if integer.is_empty() && fraction.as_ref().map_or(true, String::is_empty) {
+            return Err(Fail::Syntax);
+        }
+        if integer.len() > 1 && integer.starts_with('0') {
+            return Err(Fail::Syntax);
+        }
+
+        if integer.is_empty() {
+            self.fix(JsonFix::BareDecimalPoints);
+            json.push('0');
+        } else {
+            json.push_str(&integer);
+        }
+        if let Some(fraction) = fraction {
+            json.push('.');
+            if fraction.is_empty() {
+                self.fix(JsonFix::BareDecimalPoints);
+                json.push('0');
+            } else {
+                json.push_str(&fraction);
+            }
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.bump();
+            json.push('e');
+            if let Some(sign @ ('+' | '-')) = self.peek() {
+                self.bump();
+                json.push(sign);
+            }
+            let exponent = self.digits(|c| c.is_ascii_digit());
+            if exponent.is_empty() {
+                return Err(Fail::Syntax);
+            }
+            json.push_str(&exponent);
+        } else if self.eat('n') {
+            self.js_only = true; // BigInt
+        }
+        self.finish_number(json)
+    }
+
+    /// Rejects numbers run into a name, like `1px`.
+    fn finish_number(&self, json: String) -> Step<Node> {
+        if self.peek().is_some_and(is_identifier_part) {
+            Err(Fail::Syntax)
+        } else {
+            Ok(Node::Scalar(json))
+        }
+    }
+
+    /// Reads digits accepted by `is_digit`, dropping `_` separators.
+    fn digits(&mut self, is_digit: impl Fn(char) -> bool) -> String {
+        let mut digits = String::new();
+        while let Some(c) = self.peek().filter(|&c| is_digit(c) || c == '_') {
+            self.bump();
+            if c == '_' {
+                self.js_only = true;
+            } else {
+                digits.push(c);
+            }
+        }
+        digits
+    }
+}
+
+/// Whether `key` starts a `get x() {}`, `set x(v) {}` or `async x() {}`
+/// method rather than naming a property.
+/// Returns the path of member `key` in the object at `path`.
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn is_accessor(key: &str) -> bool {
+    matches!(key, "get" | "set" | "async" | "static")
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '$' || c == '_'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '$' | '_' | '\u{200C}' | '\u{200D}')
+}
+
+/// Latest and earliest `Date` values, in milliseconds either side of the
+/// epoch.
+const MAX_TIME: i64 = 8_640_000_000_000_000;
+
+/// Latest and earliest years `Date` accepts, either side of year 0.
+const MAX_YEAR: i64 = 275_760;
+
+/// Formats milliseconds since the Unix epoch like `Date.toISOString()`.
+fn iso_from_millis(millis: i64) -> Option<String> {
+    const DAY: i64 = 86_400_000;
+    if !(-MAX_TIME..=MAX_TIME).contains(&millis) {
+        return None;
+    }
+    let (days, time) = (millis.div_euclid(DAY), millis.rem_euclid(DAY));
+    let date = civil_from_days(days)?;
+    if !(0..=9999).contains(&date.year) {
+        return None;
+    }
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        date.year,
+        date.month,
+        date.day,
+        time / 3_600_000,
+        time / 60_000 % 60,
+        time / 1000 % 60,
+        time % 1000
+    ))
+}
+
+/// Converts `Date` constructor parts (year, zero-based month, day, hours,
+/// minutes, seconds, milliseconds) to an ISO string, rolling over
+/// out-of-range parts like JavaScript does.
+fn iso_from_parts(parts: &[i64]) -> Option<String> {
+    let part = |i: usize, default: i64| parts.get(i).copied().unwrap_or(default);
+    let year = match part(0, 1970) {
+        year @ 0..=99 => 1900 + year,
+        year if (-MAX_YEAR..=MAX_YEAR).contains(&year) => year,
+        _ => return None,
+    };
+    let months = year.checked_mul(12)?.checked_add(part(1, 0))?;
+    let year = months.div_euclid(12);
+    if !(-MAX_YEAR..=MAX_YEAR).contains(&year) {
+        return None;
+    }
+    let first = CivilDate {
+        year: year as i32,
+        month: months.rem_euclid(12) as u32 + 1,
+        day: 1,
+    };
+    let days = days_from_civil(first).checked_add(part(2, 1).checked_sub(1)?)?;
+    let millis = days
+        .checked_mul(86_400_000)?
+        .checked_add(part(3, 0).checked_mul(3_600_000)?)?
+        .checked_add(part(4, 0).checked_mul(60_000)?)?
+        .checked_add(part(5, 0).checked_mul(1000)?)?
+        .checked_add(part(6, 0))?;
+    iso_from_millis(millis)
+}
+
+/// Whether `text` is an ISO 8601 date or date-time, the one string format
+/// every engine's `Date` reads the same way.
+fn is_iso_date(text: &str) -> bool {
+    let Some(caps) = ISO_DATE.captures(text) else {
+        return false;
+    };
+    let number = |i: usize| {
+        caps.get(i)
+            .map_or(0, |m| m.as_str().parse().unwrap_or(u32::MAX))
+    };
+    civil_date(number(1) as i32, number(2), number(3)).is_some()
+        && number(4) <= 23
+        && number(5) <= 59
+        && number(6) <= 59
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::value::Source;
+
+    fn parse(input: &str) -> Option<FlexValue> {
+        JavaScriptStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .next()
+    }
+
+    #[test]
+    fn test_wrappers_and_undefined() {
+        for input in [
+            "const result = {name: 'Alice', email: undefined};",
+            "export default {name: 'Alice', email: undefined}",
+            "module.exports = {name: 'Alice', email: undefined};",
+            "const result: User = {name: 'Alice', email: undefined} as const;",
+        ] {
+            let candidate = parse(input).unwrap();
+            assert_eq!(candidate.value, json!({"name": "Alice"}), "{}", input);
+        }
+
+        let candidate = parse("let x = {a: [1, undefined, , 4], b: undefined}").unwrap();
+        assert_eq!(candidate.value, json!({"a": [1, null, null, 4]}));
+        let paths: Vec<_> = candidate
+            .transformations()
+            .iter()
+            .filter_map(|t| match t {
+                Transformation::UndefinedRemoved { path } => Some(path.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(paths, vec!["a[1]", "a[2]", "b"]);
+        assert!(matches!(
+            &candidate.source,
+            Source::Fixed { fixes } if fixes.contains(&JsonFix::JavaScriptWrappers)
+        ));
+    }
+
+    #[test]
+    fn test_dates() {
+        let input = "{a: new Date(\"2024-01-15T10:00:00Z\"), b: new Date(2024, 0, 31, 12), \
+                     c: new Date(0), d: new Date(2024, 13, 1).toISOString(), e: new Date()}";
+        let candidate = parse(input).unwrap();
+
+        assert_eq!(
+            candidate.value,
+            json!({
+                "a": "2024-01-15T10:00:00Z",
+                "b": "2024-01-31T12:00:00.000Z",
+                "c": "1970-01-01T00:00:00.000Z",
+                "d": "2025-02-01T00:00:00.000Z",
+            })
+        );
+        assert!(candidate.transformations().iter().any(|t| matches!(
+            t,
+            Transformation::DateToString { path, original }
+                if path == "d" && original == "new Date(2024, 13, 1).toISOString()"
+        )));
+        assert!(candidate.transformations().iter().any(|t| matches!(
+            t,
+            Transformation::ExpressionDropped { path, expression }
+                if path == "e" && expression == "new Date()"
+        )));
+
+        // Strings other than ISO 8601 are read differently by each engine
+        let candidate = parse("{a: new Date('March 3, 2024'), b: 1}").unwrap();
+        assert_eq!(candidate.value, json!({"b": 1}));
+        assert!(candidate.transformations().iter().any(|t| matches!(
+            t,
+            Transformation::ExpressionDropped { path, .. } if path == "a"
+        )));
+
+        // Parts out of `Date`'s range drop the date rather than overflow
+        for input in [
+            "{a: new Date(2024, 0, -1e30), b: 1}",
+            "{a: new Date(1e17, 0), b: 1}",
+        ] {
+            assert_eq!(parse(input).unwrap().value, json!({"b": 1}), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_dropped_expressions() {
+        let input = "{\n  id: 1,\n  name,\n  total: price * qty,\n  label: `Hi ${name}`,\n  \
+                     onClick: () => alert('hi'),\n  handler: function (e) { return e; },\n  \
+                     render() { return null; },\n  get full() { return 'x'; },\n  \
+                     [key]: 2,\n  ...rest,\n  items: [ref, 3, x => x],\n}";
+        let candidate = parse(input).unwrap();
+
+        assert_eq!(candidate.value, json!({"id": 1, "items": [null, 3, null]}));
+        let dropped: Vec<_> = candidate
+            .transformations()
+            .iter()
+            .filter_map(|t| match t {
+                Transformation::ExpressionDropped { path, expression } => {
+                    Some((path.as_str(), expression.as_str()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            dropped,
+            vec![
+                ("name", "name"),
+                ("total", "price * qty"),
+                ("label", "`Hi ${name}`"),
+                ("onClick", "() => alert('hi')"),
+                ("handler", "function (e) { return e; }"),
+                ("render", "render() { return null; }"),
+                ("full", "get full() { return 'x'; }"),
+                ("", "[key]: 2"),
+                ("", "...rest"),
+                ("items[0]", "ref"),
+                ("items[2]", "x => x"),
+            ]
+        );
+        assert!(matches!(
+            &candidate.source,
+            Source::Fixed { fixes } if fixes.contains(&JsonFix::JavaScriptFunctions)
+        ));
+
+        let candidate = parse("{id: 1, meta: {tag, async *load() {}, set size(v) {}}}").unwrap();
+        let paths: Vec<_> = candidate
+            .transformations()
+            .iter()
+            .filter_map(|t| match t {
+                Transformation::ExpressionDropped { path, .. } => Some(path.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(paths, vec!["meta.tag", "meta.load", "meta.size"]);
+    }
+
+    #[test]
+    fn test_literals_beyond_json5() {
+        let input = "{pattern: /^[a-z\\/]+$/gi, ['kebab-key']: `multi\nline`, big: 10n, \
+                     mask: 0b1010, mode: 0o755, million: 1_000_000, ...{extra: true}, \
+                     list: [...[1, 2], 3], emoji: '\\u{1F600}'}";
+        let candidate = parse(input).unwrap();
+
+        assert_eq!(
+            candidate.value,
+            json!({
+                "pattern": "/^[a-z\\/]+$/gi",
+                "kebab-key": "multi\nline",
+                "big": 10,
+                "mask": 10,
+                "mode": 493,
+                "million": 1_000_000,
+                "extra": true,
+                "list": [1, 2, 3],
+                "emoji": "\u{1F600}",
+            })
+        );
+    }
+
+    #[test]
+    fn test_prose_and_fences() {
+        let input = "Here's the config:\n\n```ts\nexport const config = {\n  retries: 3,\n  \
+                     timeout: undefined,\n};\n```";
+        assert_eq!(parse(input).unwrap().value, json!({"retries": 3}));
+
+        let input =
+            "You can use: const opts = {debug: true, level: undefined}; then call init(opts).";
+        assert_eq!(parse(input).unwrap().value, json!({"debug": true}));
+    }
+
+    #[test]
+    fn test_not_javascript() {
+        // JSON and JSON5 are left to their own strategies
+        assert!(parse(r#"{"a": 1}"#).is_none());
+        assert!(parse("{a: 'b', c: .5,}").is_none());
+        // Nothing but dropped members isn't data
+        assert!(parse("const a = {name, age};").is_none());
+        assert!(parse("Use {placeholder} here").is_none());
+        assert!(parse("{is some text}").is_none());
+        // Brackets in prose that keep no members
+        for input in [
+            "See [the docs] for details.",
+            "Use `arr[i]` to index",
+            "Options: [fast] or [slow]",
+        ] {
+            assert!(parse(input).is_none(), "{}", input);
+        }
+        // Prose with a literal that keeps a member is still read
+        let candidate = parse("The ids are [first, 2] for now.").unwrap();
+        assert_eq!(candidate.value, json!([null, 2]));
+    }
+
+    #[test]
+    fn test_iso_helpers() {
+        assert_eq!(
+            iso_from_millis(1_705_276_800_000).as_deref(),
+            Some("2024-01-15T00:00:00.000Z")
+        );
+        assert_eq!(
+            iso_from_parts(&[2024, 1, 29]).as_deref(),
+            Some("2024-02-29T00:00:00.000Z")
+        );
+        assert_eq!(
+            iso_from_parts(&[99, 0]).as_deref(),
+            Some("1999-01-01T00:00:00.000Z")
+        );
+        // Out of `Date`'s range, and no overflow on the way
+        assert_eq!(iso_from_parts(&[2024, 0, i64::MIN]), None);
+        assert_eq!(iso_from_parts(&[i64::MAX, 0]), None);
+        assert_eq!(iso_from_parts(&[275_761, 0]), None);
+        assert_eq!(iso_from_parts(&[2024, i64::MAX]), None);
+        assert_eq!(iso_from_millis(i64::MIN), None);
+
+        assert!(is_iso_date("2024-03-01"));
+        assert!(is_iso_date("2024-03-01T09:00:00.000+02:00"));
+        assert!(!is_iso_date("2024-02-30"));
+        assert!(!is_iso_date("2024-03-01T25:00"));
+        assert!(!is_iso_date("March 3, 2024"));
+    }
+}
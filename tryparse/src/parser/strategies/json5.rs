@@ -0,0 +1,727 @@
+//! JSON5 strategy that reads the whole JSON5 grammar in one pass.
+
+use super::{
+    literal::{Fail, LiteralSyntax, Step},
+    ParsingStrategy,
+};
+use crate::{
+    error::Result,
+    parser::{
+        duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
+        strategies::MarkdownExtractor,
+        MAX_NESTING_DEPTH,
+    },
+    value::{FlexValue, JsonFix},
+};
+
+/// How `Infinity`, `-Infinity` and `NaN` are represented, since JSON has no
+/// non-finite numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NonFinitePolicy {
+    /// Replace them with `null`.
+    #[default]
+    Null,
+    /// Keep them as the strings `"Infinity"`, `"-Infinity"` and `"NaN"`.
+    String,
+    /// Fail with [`ParseError::NonFiniteNumber`](crate::error::ParseError).
+    /// [`FlexibleParser`](crate::parser::FlexibleParser) drops the candidate
+    /// and fails with this error if no other candidate is found.
+    Error,
+}
+
+/// Strategy that parses JSON5 and records each extension it used.
+///
+/// Unlike [`JsonFixerStrategy`](super::JsonFixerStrategy), which retries
+/// combinations of text fixes, this reads the whole
+/// [JSON5 grammar](https://spec.json5.org/) in one pass:
+///
+/// - `//` and `/* */` comments, and trailing commas
+/// - Single-quoted strings and unquoted (identifier) keys
+/// - `\` line continuations and the `\v`, `\0`, `\xHH` and identity escapes
+/// - Hex numbers, a leading `+`, and bare decimal points (`.5`, `5.`)
+/// - `Infinity` and `NaN`, handled by a [`NonFinitePolicy`]
+///
+/// Raw newlines in strings are accepted too. The result is re-read as JSON,
+/// so repeated keys follow the strategy's [`DuplicateKeyPolicy`].
+///
+/// The whole input is tried first, then ```` ```json5 ````, ```` ```jsonc ````
+/// and ```` ```json ```` fences, then objects and arrays inside prose.
+/// Candidates get [`Source::Fixed`](crate::value::Source::Fixed) with one
+/// [`JsonFix`] per extension used; text that is already plain JSON is left
+/// to the other strategies.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{Json5Strategy, ParsingStrategy};
+///
+/// let input = "{ratio: .5, count: +3, limit: Infinity, // cap\n}";
+/// let candidates = Json5Strategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["ratio"], 0.5);
+/// assert!(candidates[0].value["limit"].is_null());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Json5Strategy {
+    /// Finds ```` ```json5 ```` fences.
+    fences: MarkdownExtractor,
+    /// How `Infinity` and `NaN` are represented.
+    non_finite: NonFinitePolicy,
+    /// How repeated object keys are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
+}
+
+impl Json5Strategy {
+    /// Creates a new JSON5 strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how `Infinity` and `NaN` are represented.
+    #[must_use]
+    pub const fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Sets how repeated object keys are resolved.
+    #[must_use]
+    pub const fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+}
+
+impl LiteralSyntax for Json5Strategy {
+    /// The JSON text and the extensions used.
+    type Literal = (String, Vec<JsonFix>);
+
+    const FENCE_LANGUAGES: &'static [&'static str] = &["json5", "jsonc", "json"];
+
+    fn fences(&self) -> &MarkdownExtractor {
+        &self.fences
+    }
+
+    fn read_document(&self, text: &str) -> Step<Self::Literal> {
+        let mut reader = Reader::new(text, self.non_finite);
+        let json = reader.document()?;
+        Ok((json, reader.fixes))
+    }
+
+    fn read_embedded(&self, text: &str) -> Step<(Self::Literal, usize)> {
+        let mut reader = Reader::new(text, self.non_finite);
+        let json = reader.embedded_value()?;
+        Ok(((json, reader.fixes), reader.pos))
+    }
+
+    fn next_start(&self, input: &str, from: usize) -> Option<usize> {
+        input[from..].find(['{', '[']).map(|offset| from + offset)
+    }
+
+    /// Skips text that isn't JSON5 but plain JSON.
+    fn candidate(
+        &self,
+        (json, fixes): Self::Literal,
+        _text: &str,
+        _embedded: bool,
+    ) -> Result<Option<FlexValue>> {
+        if fixes.is_empty() {
+            return Ok(None);
+        }
+        parse_candidate(&json, self.duplicate_keys, |value| {
+            FlexValue::from_fixed_json(value, fixes)
+        })
+    }
+}
+
+impl ParsingStrategy for Json5Strategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "json5"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        self.parse_literals(input)
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        3 // Same as the fixer: syntax beyond plain JSON
+    }
+}
+
+/// Reads JSON5 and writes the equivalent JSON.
+struct Reader<'a> {
+    text: &'a str,
+    /// Byte offset of the next character.
+    pos: usize,
+    non_finite: NonFinitePolicy,
+    /// Extensions used so far, in order of first use.
+    fixes: Vec<JsonFix>,
+    out: String,
+}
+
+impl<'a> Reader<'a> {
+    fn new(text: &'a str, non_finite: NonFinitePolicy) -> Self {
+        Self {
+            text,
+            pos: 0,
+            non_finite,
+            fixes: Vec::new(),
+            out: String::with_capacity(text.len()),
+        }
+    }
+
+    /// Reads one value that must make up all of the text.
+    fn document(&mut self) -> Step<String> {
+        self.value(0)?;
+        self.skip_trivia()?;
+        if self.pos < self.text.len() {
+            return Err(Fail::Syntax);
+        }
+        Ok(std::mem::take(&mut self.out))
+    }
+
+    /// Reads one object or array, ignoring whatever follows it.
+    fn embedded_value(&mut self) -> Step<String> {
+        self.value(0)?;
+        Ok(std::mem::take(&mut self.out))
+    }
+
+    fn fix(&mut self, fix: JsonFix) {
+        if !self.fixes.contains(&fix) {
+            self.fixes.push(fix);
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.text[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.pos += expected.len_utf8();
+        }
+        found
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        let found = self.text[self.pos..].starts_with(expected);
+        if found {
+            self.pos += expected.len();
+        }
+        found
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) -> Step<()> {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() || c == '\u{FEFF}' => {
+                    self.bump();
+                }
+                Some('/') if self.peek_second() == Some('/') => {
+                    self.fix(JsonFix::Comments);
+                    while self.peek().is_some_and(|c| !is_line_terminator(c)) {
+                        self.bump();
+                    }
+                }
+                Some('/') if self.peek_second() == Some('*') => {
+                    self.fix(JsonFix::Comments);
+                    let end = self.text[self.pos + 2..].find("*/").ok_or(Fail::Syntax)?;
+                    self.pos += 2 + end + 2;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Step<()> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(Fail::Syntax);
+        }
+        self.skip_trivia()?;
+        match self.peek().ok_or(Fail::Syntax)? {
+            '{' => self.object(depth),
+            '[' => self.array(depth),
+            quote @ ('"' | '\'') => {
+                let s = self.string(quote)?;
+                self.write_string(&s);
+                Ok(())
+            }
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'I' | 'N') => self.number(),
+            _ => {
+                for literal in ["true", "false", "null"] {
+                    if self.eat_str(literal) && !self.peek().is_some_and(is_identifier_part) {
+                        self.out.push_str(literal);
+                        return Ok(());
+                    }
+                }
+                Err(Fail::Syntax)
+            }
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Step<()> {
+        self.bump(); // '{'
+        self.out.push('{');
+        let mut first = true;
+
+        loop {
+            self.skip_trivia()?;
+            if self.eat('}') {
+                break;
+            }
+
+            if !first {
+                self.out.push(',');
+            }
+            first = false;
+
+            let key = match self.peek().ok_or(Fail::Syntax)? {
+                quote @ ('"' | '\'') => self.string(quote)?,
+                _ => {
+                    let key = self.identifier()?;
+                    self.fix(JsonFix::UnquotedKeys);
+                    key
+                }
+            };
+            self.write_string(&key);
+
+            self.skip_trivia()?;
+            if !self.eat(':') {
+                return Err(Fail::Syntax);
+            }
+            self.out.push(':');
+            self.value(depth + 1)?;
+
+            self.skip_trivia()?;
+            if self.eat(',') {
+                self.skip_trivia()?;
+                if self.eat('}') {
+                    self.fix(JsonFix::TrailingCommas);
+                    break;
+                }
+            } else if self.eat('}') {
+                break;
+            } else {
+                return Err(Fail::Syntax);
+            }
+        }
+
+        self.out.push('}');
+        Ok(())
+    }
+
+    fn array(&mut self, depth: usize) -> Step<()> {
+        self.bump(); // '['
+        self.out.push('[');
+        let mut first = true;
+
+        loop {
+            self.skip_trivia()?;
+            if self.eat(']') {
+                break;
+            }
+
+            if !first {
+                self.out.push(',');
+            }
+            first = false;
+            self.value(depth + 1)?;
+
+            self.skip_trivia()?;
+            if self.eat(',') {
+                self.skip_trivia()?;
+                if self.eat(']') {
+                    self.fix(JsonFix::TrailingCommas);
+                    break;
+                }
+            } else if self.eat(']') {
+                break;
+            } else {
+                return Err(Fail::Syntax);
+            }
+        }
+
+        self.out.push(']');
+        Ok(())
+    }
+
+    /// Reads a quoted string, decoding its escapes.
+    fn string(&mut self, quote: char) -> Step<String> {
+        self.bump();
+        if quote == '\'' {
+            self.fix(JsonFix::SingleQuotes);
+        }
+
+        let mut s = String::new();
+        loop {
+            match self.bump().ok_or(Fail::Syntax)? {
+                c if c == quote => return Ok(s),
+                '\\' => self.escape(&mut s)?,
+                c @ ('\n' | '\r') => {
+                    self.fix(JsonFix::UnescapedNewlines);
+                    s.push(c);
+                }
+                c => s.push(c),
+            }
+        }
+    }
+
+    /// Decodes the escape after a `\` in a string.
+    fn escape(&mut self, s: &mut String) -> Step<()> {
+        let c = self.bump().ok_or(Fail::Syntax)?;
+        match c {
+            '"' | '\'' | '\\' | '/' => s.push(c),
+            'b' => s.push('\u{8}'),
+            'f' => s.push('\u{C}'),
+            'n' => s.push('\n'),
+            'r' => s.push('\r'),
+            't' => s.push('\t'),
+            'u' => s.push(self.unicode_escape()?),
+            'v' => {
+                self.fix(JsonFix::ExtendedEscapes);
+                s.push('\u{B}');
+            }
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                self.fix(JsonFix::ExtendedEscapes);
+                s.push('\0');
+            }
+            'x' => {
+                self.fix(JsonFix::ExtendedEscapes);
+                let code = self.hex_digits(2)?;
+                s.push(char::from_u32(code).ok_or(Fail::Syntax)?);
+            }
+            // JSON5 reserves `\1`-`\9`
+            '1'..='9' => return Err(Fail::Syntax),
+            c if is_line_terminator(c) => {
+                self.fix(JsonFix::LineContinuations);
+                if c == '\r' {
+                    self.eat('\n');
+                }
+            }
+            c => {
+                self.fix(JsonFix::ExtendedEscapes);
+                s.push(c);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the `XXXX` of a `\uXXXX` escape, joining surrogate pairs.
+    fn unicode_escape(&mut self) -> Step<char> {
+        let high = self.hex_digits(4)?;
+        if (0xD800..0xDC00).contains(&high) && self.text[self.pos..].starts_with("\\u") {
+            let saved = self.pos;
+            self.pos += 2;
+            let low = self.hex_digits(4)?;
+            if (0xDC00..0xE000).contains(&low) {
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return char::from_u32(code).ok_or(Fail::Syntax);
+            }
+            self.pos = saved;
+        }
+        Ok(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Step<u32> {
+        let digits = self.text[self.pos..].get(..count).ok_or(Fail::Syntax)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Fail::Syntax);
+        }
+        self.pos += count;
+        u32::from_str_radix(digits, 16).map_err(|_| Fail::Syntax)
+    }
+
+    /// Reads an unquoted key: an ECMAScript identifier, with `\uXXXX`
+    /// escapes allowed.
+    fn identifier(&mut self) -> Step<String> {
+        let mut name = String::new();
+        loop {
+            let c = match self.peek() {
+                Some('\\') => {
+                    self.bump();
+                    if !self.eat('u') {
+                        return Err(Fail::Syntax);
+                    }
+                    self.unicode_escape()?
+                }
+                Some(c) if is_identifier_part(c) => {
+                    self.bump();
+                    c
+                }
+                _ => break,
+            };
+            if name.is_empty() && c.is_ascii_digit() {
+                return Err(Fail::Syntax);
+            }
+            name.push(c);
+        }
+
+        if name.is_empty() {
+            Err(Fail::Syntax)
+        } else {
+            Ok(name)
+        }
+    }
+
+    fn number(&mut self) -> Step<()> {
+        let negative = match self.peek() {
+            Some('-') => {
+                self.bump();
+                true
+            }
+            Some('+') => {
+                self.bump();
+                self.fix(JsonFix::PlusSigns);
+                false
+            }
+            _ => false,
+        };
+        let sign = if negative { "-" } else { "" };
+
+        for (literal, name) in [("Infinity", "Infinity"), ("NaN", "NaN")] {
+            if self.eat_str(literal) {
+                if self.peek().is_some_and(is_identifier_part) {
+                    return Err(Fail::Syntax);
+                }
+                self.fix(JsonFix::NonFiniteNumbers);
+                let name = if literal == "NaN" {
+                    name.to_string()
+                } else {
+                    format!("{}{}", sign, name)
+                };
+                match self.non_finite {
+                    NonFinitePolicy::Null => self.out.push_str("null"),
+                    NonFinitePolicy::String => self.write_string(&name),
+                    NonFinitePolicy::Error => return Err(Fail::NonFinite(name)),
+                }
+                return Ok(());
+            }
+        }
+
+        if self.eat_str("0x") || self.eat_str("0X") {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                self.bump();
+            }
+            let value =
+                u128::from_str_radix(&self.text[start..self.pos], 16).map_err(|_| Fail::Syntax)?;
+            self.fix(JsonFix::HexNumbers);
+            self.out.push_str(sign);
+            self.out.push_str(&value.to_string());
+            return Ok(());
+        }
+
+        let integer = self.digits();
+        let fraction = if self.eat('.') {
+            Some(self.digits())
+        } else {
+            None
+        };
+        if integer.is_empty() && fraction.map_or(true, str::is_empty) {
+            return Err(Fail::Syntax);
+        }
+        // JSON5 forbids leading zeros, like JSON
+        if integer.len() > 1 && integer.starts_with('0') {
+            return Err(Fail::Syntax);
+        }
+
+        self.out.push_str(sign);
+        if integer.is_empty() {
+            self.fix(JsonFix::BareDecimalPoints);
+            self.out.push('0');
+        } else {
+            self.out.push_str(integer);
+        }
+        if let Some(fraction) = fraction {
+            self.out.push('.');
+            if fraction.is_empty() {
+                self.fix(JsonFix::BareDecimalPoints);
+                self.out.push('0');
+            } else {
+                self.out.push_str(fraction);
+            }
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.bump();
+            self.out.push('e');
+            if let Some(sign @ ('+' | '-')) = self.peek() {
+                self.bump();
+                self.out.push(sign);
+            }
+            let exponent = self.digits();
+            if exponent.is_empty() {
+                return Err(Fail::Syntax);
+            }
+            self.out.push_str(exponent);
+        }
+
+        if self.peek().is_some_and(is_identifier_part) {
+            return Err(Fail::Syntax);
+        }
+        Ok(())
+    }
+
+    fn digits(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        &self.text[start..self.pos]
+    }
+
+    fn write_string(&mut self, s: &str) {
+        // Serializing a `str` can't fail
+        self.out
+            .push_str(&serde_json::to_string(s).unwrap_or_default());
+    }
+}
+
+fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '$' | '_' | '\u{200C}' | '\u{200D}')
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{error::ParseError, value::Source};
+
+    fn parse(strategy: &Json5Strategy, input: &str) -> Option<FlexValue> {
+        strategy.parse(input).unwrap().into_iter().next()
+    }
+
+    fn fixes(candidate: &FlexValue) -> Vec<JsonFix> {
+        match &candidate.source {
+            Source::Fixed { fixes } => fixes.clone(),
+            other => panic!("unexpected source {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_full_grammar() {
+        let input = "// settings\n{\n  unquoted: 'and you can quote me on that',\n  \
+                     singleQuotes: 'I can use \"double quotes\" here',\n  \
+                     lineBreaks: \"Look, Mom! \\\nNo \\\\n's!\",\n  \
+                     hexadecimal: 0xdecaf,\n  leadingDecimalPoint: .8675309, andTrailing: 8675309.,\n  \
+                     positiveSign: +1,\n  trailingComma: 'in objects', andIn: ['arrays',],\n  \
+                     \"backwardsCompatible\": \"with JSON\", /* block */\n}";
+        let candidate = parse(&Json5Strategy::new(), input).unwrap();
+
+        assert_eq!(
+            candidate.value,
+            json!({
+                "unquoted": "and you can quote me on that",
+                "singleQuotes": "I can use \"double quotes\" here",
+                "lineBreaks": "Look, Mom! No \\n's!",
+                "hexadecimal": 912_559,
+                "leadingDecimalPoint": 0.867_530_9,
+                "andTrailing": 8_675_309.0,
+                "positiveSign": 1,
+                "trailingComma": "in objects",
+                "andIn": ["arrays"],
+                "backwardsCompatible": "with JSON",
+            })
+        );
+        assert_eq!(
+            fixes(&candidate),
+            vec![
+                JsonFix::Comments,
+                JsonFix::UnquotedKeys,
+                JsonFix::SingleQuotes,
+                JsonFix::LineContinuations,
+                JsonFix::HexNumbers,
+                JsonFix::BareDecimalPoints,
+                JsonFix::PlusSigns,
+                JsonFix::TrailingCommas,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escapes_and_identifiers() {
+        let input = r#"{$id: 'a\x41\v\0\qé😀', café: -0x10, _n2: -.5e3}"#;
+        let candidate = parse(&Json5Strategy::new(), input).unwrap();
+
+        assert_eq!(candidate.value["$id"], "aA\u{B}\0q\u{e9}\u{1F600}");
+        assert_eq!(candidate.value["café"], -16);
+        assert_eq!(candidate.value["_n2"].as_f64(), Some(-500.0));
+        assert!(fixes(&candidate).contains(&JsonFix::ExtendedEscapes));
+    }
+
+    #[test]
+    fn test_non_finite_policies() {
+        let input = "[Infinity, -Infinity, +NaN]";
+
+        let candidate = parse(&Json5Strategy::new(), input).unwrap();
+        assert_eq!(candidate.value, json!([null, null, null]));
+        assert!(fixes(&candidate).contains(&JsonFix::NonFiniteNumbers));
+
+        let strings = Json5Strategy::new().with_non_finite_policy(NonFinitePolicy::String);
+        assert_eq!(
+            parse(&strings, input).unwrap().value,
+            json!(["Infinity", "-Infinity", "NaN"])
+        );
+
+        let error = Json5Strategy::new().with_non_finite_policy(NonFinitePolicy::Error);
+        assert!(matches!(
+            error.parse(input),
+            Err(ParseError::NonFiniteNumber { literal }) if literal == "Infinity"
+        ));
+    }
+
+    #[test]
+    fn test_fences_and_prose() {
+        let input = "Config:\n```json5\n{retries: 3,}\n```";
+        assert_eq!(
+            parse(&Json5Strategy::new(), input).unwrap().value,
+            json!({"retries": 3})
+        );
+
+        let input = "See [1] above. Result: {score: .9, tags: ['a']} Done.";
+        let candidates = Json5Strategy::new().parse(input).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].value, json!({"score": 0.9, "tags": ["a"]}));
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let strategy =
+            Json5Strategy::new().with_duplicate_key_policy(DuplicateKeyPolicy::MergeIntoArray);
+        let candidate = parse(&strategy, "{tag: 'a', tag: 'b'}").unwrap();
+        assert_eq!(candidate.value, json!({"tag": ["a", "b"]}));
+
+        let strategy = Json5Strategy::new().with_duplicate_key_policy(DuplicateKeyPolicy::Error);
+        assert!(strategy.parse("{a: 1, a: 2}").is_err());
+    }
+
+    #[test]
+    fn test_rejects_plain_json_and_invalid_json5() {
+        let strategy = Json5Strategy::new();
+        assert!(parse(&strategy, r#"{"a": 1}"#).is_none());
+        assert!(parse(&strategy, "{a: 01}").is_none());
+        assert!(parse(&strategy, "{a: 1 b: 2}").is_none());
+        assert!(parse(&strategy, "{a: undefined}").is_none());
+        assert!(parse(&strategy, "{'a': 'unterminated}").is_none());
+        assert!(parse(&strategy, "[1, 2,,]").is_none());
+    }
+}
@@ -1,8 +1,9 @@
 //! JSON repair/fixing strategy.
 
-use super::ParsingStrategy;
+use super::{NonFinitePolicy, ParsingStrategy};
 use crate::{
     error::Result,
+    parser::duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
     value::{FlexValue, JsonFix},
 };
 
@@ -27,11 +28,15 @@ use crate::{
 pub struct JsonFixerStrategy {
     /// Maximum number of different fix combinations to try.
     max_attempts: usize,
+    /// How repeated object keys in the repaired JSON are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
+    /// Whether bare `NaN` and `Infinity` values may be quoted into strings.
+    non_finite: NonFinitePolicy,
 }
 
 impl Default for JsonFixerStrategy {
     fn default() -> Self {
-        Self { max_attempts: 10 }
+        Self::new(10)
     }
 }
 
@@ -39,7 +44,43 @@ impl JsonFixerStrategy {
     /// Creates a new JSON fixer strategy with custom settings.
     #[inline]
     pub const fn new(max_attempts: usize) -> Self {
-        Self { max_attempts }
+        Self {
+            max_attempts,
+            duplicate_keys: DuplicateKeyPolicy::LastWins,
+            non_finite: NonFinitePolicy::Null,
+        }
+    }
+
+    /// Sets how repeated object keys in the repaired JSON are resolved.
+    #[inline]
+    pub const fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Sets how bare `NaN` and `Infinity` values are treated.
+    ///
+    /// Unquoted values are repaired into strings; with
+    /// [`NonFinitePolicy::Error`] these two are left bare instead, so the
+    /// repair fails rather than bring back a number the policy forbids.
+    #[inline]
+    pub const fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Parses repaired JSON into a candidate, if it is valid now.
+    fn push_fixed(
+        &self,
+        fixed: &str,
+        fixes: Vec<JsonFix>,
+        candidates: &mut Vec<FlexValue>,
+    ) -> Result<()> {
+        let candidate = parse_candidate(fixed, self.duplicate_keys, |value| {
+            FlexValue::from_fixed_json(value, fixes)
+        })?;
+        candidates.extend(candidate);
+        Ok(())
     }
 
     /// Attempts to fix unquoted object keys.
@@ -899,7 +940,9 @@ impl JsonFixerStrategy {
                             }
 
                             let value = value_chars.iter().collect::<String>().trim().to_string();
-                            if !value.is_empty() && !value.starts_with('"') {
+                            let forbidden = self.non_finite == NonFinitePolicy::Error
+                                && matches!(value.as_str(), "NaN" | "Infinity" | "-Infinity");
+                            if !value.is_empty() && !value.starts_with('"') && !forbidden {
                                 result.push('"');
                                 result.push_str(&value);
                                 result.push('"');
@@ -933,9 +976,9 @@ impl JsonFixerStrategy {
         candidates: &mut Vec<FlexValue>,
         attempts: &mut usize,
         fixes: &[&dyn Fn(&str) -> Option<(String, JsonFix)>],
-    ) {
+    ) -> Result<()> {
         if *attempts >= self.max_attempts {
-            return;
+            return Ok(());
         }
 
         let mut current = input.to_string();
@@ -950,10 +993,9 @@ impl JsonFixerStrategy {
 
         if !applied_fixes.is_empty() {
             *attempts += 1;
-            if let Ok(value) = serde_json::from_str(&current) {
-                candidates.push(FlexValue::from_fixed_json(value, applied_fixes));
-            }
+            self.push_fixed(&current, applied_fixes, candidates)?;
         }
+        Ok(())
     }
 }
 
@@ -992,9 +1034,7 @@ impl ParsingStrategy for JsonFixerStrategy {
             }
             attempts += 1;
 
-            if let Ok(value) = serde_json::from_str(&fix.0) {
-                candidates.push(FlexValue::from_fixed_json(value, vec![fix.1]));
-            }
+            self.push_fixed(&fix.0, vec![fix.1], &mut candidates)?;
         }
 
         // Try common two-fix combinations
@@ -1006,7 +1046,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_smart_quotes(s), &|s: &str| {
                     self.fix_single_quotes(s)
                 }],
-            );
+            )?;
         }
 
         if attempts < self.max_attempts {
@@ -1017,7 +1057,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_trailing_commas(s), &|s: &str| {
                     self.fix_single_quotes(s)
                 }],
-            );
+            )?;
         }
 
         if attempts < self.max_attempts {
@@ -1028,7 +1068,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_unquoted_keys(s), &|s: &str| {
                     self.fix_trailing_commas(s)
                 }],
-            );
+            )?;
         }
 
         if attempts < self.max_attempts {
@@ -1039,7 +1079,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_comments(s), &|s: &str| {
                     self.fix_trailing_commas(s)
                 }],
-            );
+            )?;
         }
 
         if attempts < self.max_attempts {
@@ -1050,7 +1090,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_missing_commas(s), &|s: &str| {
                     self.fix_unquoted_keys(s)
                 }],
-            );
+            )?;
         }
 
         if attempts < self.max_attempts {
@@ -1061,7 +1101,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_unquoted_keys(s), &|s: &str| {
                     self.fix_unquoted_values(s)
                 }],
-            );
+            )?;
         }
 
         // CRITICAL: unquoted keys + single quotes (common pattern from LLMs)
@@ -1074,7 +1114,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                 &[&|s: &str| self.fix_unquoted_keys(s), &|s: &str| {
                     self.fix_single_quotes(s)
                 }],
-            );
+            )?;
         }
 
         // Try three-fix combination for really messy JSON
@@ -1088,7 +1128,7 @@ impl ParsingStrategy for JsonFixerStrategy {
                     &|s: &str| self.fix_unquoted_keys(s),
                     &|s: &str| self.fix_trailing_commas(s),
                 ],
-            );
+            )?;
         }
 
         // Final attempt: Apply all fixes in sequence
@@ -1157,9 +1197,7 @@ impl ParsingStrategy for JsonFixerStrategy {
             }
 
             if !applied_fixes.is_empty() {
-                if let Ok(value) = serde_json::from_str(&fixed) {
-                    candidates.push(FlexValue::from_fixed_json(value, applied_fixes));
-                }
+                self.push_fixed(&fixed, applied_fixes, &mut candidates)?;
             }
         }
 
@@ -1225,6 +1263,17 @@ mod tests {
         assert!(!result.is_empty());
     }
 
+    #[test]
+    fn test_non_finite_values_stay_bare_under_error() {
+        let input = r#"{"value": NaN}"#;
+
+        let result = JsonFixerStrategy::default().parse(input).unwrap();
+        assert_eq!(result[0].value["value"], "NaN");
+
+        let strategy = JsonFixerStrategy::default().with_non_finite_policy(NonFinitePolicy::Error);
+        assert!(strategy.parse(input).unwrap().is_empty());
+    }
+
     #[test]
     fn test_no_fix_needed() {
         let strategy = JsonFixerStrategy::default();
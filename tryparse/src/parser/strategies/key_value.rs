@@ -0,0 +1,362 @@
+//! Key-value prose strategy for answers like `Name: Alice` / `Age = 30`.
+
+use serde_json::{Map, Value};
+
+use crate::{
+    error::Result,
+    parser::strategies::ParsingStrategy,
+    value::{FlexValue, Source},
+};
+
+/// Fewest key-value lines that make an answer rather than a sentence with a
+/// colon in it.
+const MIN_PAIRS: usize = 2;
+
+/// Longest key, in characters, that still reads like a label.
+const MAX_KEY_LEN: usize = 50;
+
+/// Strategy that turns line-oriented `key: value` text into an object.
+///
+/// Each line is `key: value` or `key = value`, optionally bulleted
+/// (`-`, `*`, `+`, `1.`) and with the key in bold (`**Age**: 30` or
+/// `**Age:** 30`). Other lines are ignored as prose.
+///
+/// - A key with no value starts a section: the more deeply indented (or
+///   bulleted) lines right under it become a nested object, or an array of
+///   strings if they are plain bullets
+/// - A key with neither a value nor lines under it is left out
+/// - Repeated keys collect their values into an array
+/// - Values are strings; the coercer converts them for the target type
+///
+/// Input containing `{` or `[` is left to the JSON extractors. Candidates get
+/// [`Source::KeyValue`], which ranks below every JSON source.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{KeyValueStrategy, ParsingStrategy};
+///
+/// let input = "Sure!\n- **Name**: Alice\n- **Age**: 30\nTags:\n- rust\n- json";
+/// let candidates = KeyValueStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["Age"], "30");
+/// assert_eq!(candidates[0].value["Tags"][1], "json");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KeyValueStrategy;
+
+impl KeyValueStrategy {
+    /// Creates a new key-value strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ParsingStrategy for KeyValueStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "key_value"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        let trimmed = input.trim();
+
+        // Leave embedded JSON to the extractors (see `RawPrimitiveStrategy`)
+        if trimmed.contains('{') || trimmed.contains('[') {
+            return Ok(Vec::new());
+        }
+
+        let mut after_blank = false;
+        let lines: Vec<Line> = trimmed
+            .lines()
+            .filter_map(|raw| {
+                let line = Line::parse(raw, after_blank);
+                after_blank = raw.trim().is_empty();
+                line
+            })
+            .collect();
+        let pairs = lines
+            .iter()
+            .filter(|l| matches!(l.kind, LineKind::Pair { .. }))
+            .count();
+        if pairs < MIN_PAIRS {
+            return Ok(Vec::new());
+        }
+
+        let mut pos = 0;
+        let value = section(&lines, &mut pos, None);
+        Ok(vec![FlexValue::new(value, Source::KeyValue)])
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        5 // Same as raw primitives: a fallback when there is no JSON
+    }
+}
+
+/// A line that takes part in the structure.
+#[derive(Debug)]
+struct Line {
+    /// Indentation, with a bullet counting as one more level.
+    level: usize,
+    /// Whether a blank line comes right before this one.
+    after_blank: bool,
+    kind: LineKind,
+}
+
+#[derive(Debug)]
+enum LineKind {
+    /// `key: value`, or `key:` opening a section.
+    Pair { key: String, value: Option<String> },
+    /// A bullet without a key.
+    Item(String),
+}
+
+impl Line {
+    /// Parses one line, or returns `None` for blank lines and prose.
+    fn parse(raw: &str, after_blank: bool) -> Option<Self> {
+        let indent: usize = raw
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        let text = raw.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        let (text, bulleted) = match strip_bullet(text) {
+            Some(rest) => (rest, true),
+            None => (text, false),
+        };
+        let level = indent + usize::from(bulleted);
+
+        let kind = match split_pair(text) {
+            Some((key, value)) => LineKind::Pair { key, value },
+            None if bulleted => LineKind::Item(unwrap_markup(text).to_string()),
+            None => return None,
+        };
+        Some(Self {
+            level,
+            after_blank,
+            kind,
+        })
+    }
+}
+
+/// Builds the object (or list) made of the lines deeper than `parent`.
+fn section(lines: &[Line], pos: &mut usize, parent: Option<usize>) -> Value {
+    let mut entries: Vec<(String, Vec<Value>)> = Vec::new();
+    let mut items = Vec::new();
+
+    while let Some(line) = lines.get(*pos) {
+        if parent.is_some_and(|p| line.level <= p) {
+            break;
+        }
+        *pos += 1;
+
+        match &line.kind {
+            LineKind::Pair { key, value } => {
+                let value = match value {
+                    Some(v) => Value::String(v.clone()),
+                    None if lines
+                        .get(*pos)
+                        .is_some_and(|next| next.level > line.level && !next.after_blank) =>
+                    {
+                        section(lines, pos, Some(line.level))
+                    }
+                    // An empty section, or a lead-in like `Here's what I found:`
+                    None => continue,
+                };
+                match entries.iter_mut().find(|(k, _)| k == key) {
+                    Some((_, values)) => values.push(value),
+                    None => entries.push((key.clone(), vec![value])),
+                }
+            }
+            LineKind::Item(text) => items.push(Value::String(text.clone())),
+        }
+    }
+
+    if entries.is_empty() && !items.is_empty() {
+        return Value::Array(items);
+    }
+
+    let map: Map<String, Value> = entries
+        .into_iter()
+        .map(|(key, mut values)| {
+            let value = if values.len() == 1 {
+                values.remove(0)
+            } else {
+                Value::Array(values)
+            };
+            (key, value)
+        })
+        .collect();
+    Value::Object(map)
+}
+
+/// Strips a list marker (`- `, `* `, `+ `, `1. `, `1) `).
+pub(super) fn strip_bullet(text: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "+ ", "• "] {
+        if let Some(rest) = text.strip_prefix(marker) {
+            return Some(rest.trim_start());
+        }
+    }
+
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        let rest = &text[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some(rest.trim_start());
+        }
+    }
+    None
+}
+
+/// Splits `key: value` or `key = value`, returning `None` if the part before
+/// the separator doesn't read like a label.
+fn split_pair(text: &str) -> Option<(String, Option<String>)> {
+    // `**Age:** 30` → `**Age**: 30`
+    let text = ["**", "__"]
+        .iter()
+        .find(|m| text.starts_with(*m) && text.contains(&format!(":{}", m)))
+        .map_or_else(
+            || text.to_string(),
+            |m| text.replacen(&format!(":{}", m), &format!("{}:", m), 1),
+        );
+
+    let sep = text.find([':', '='])?;
+    let key = unwrap_markup(text[..sep].trim());
+    let value = text[sep + 1..].trim();
+
+    let label_like = !key.is_empty()
+        && key.chars().count() <= MAX_KEY_LEN
+        && key.chars().next().is_some_and(char::is_alphabetic)
+        && key
+            .chars()
+            .all(|c| c.is_alphanumeric() || " _-.'()/".contains(c));
+    // `https://...` is a link and `10:30` a time, not keys
+    let time_like = text[..sep].ends_with(|c: char| c.is_ascii_digit())
+        && value.starts_with(|c: char| c.is_ascii_digit());
+    if !label_like || value.starts_with("//") || time_like {
+        return None;
+    }
+
+    let value = (!value.is_empty()).then(|| unwrap_markup(value).to_string());
+    Some((key.to_string(), value))
+}
+
+/// Trims `text` and removes bold, italic or code markers wrapping all of it.
+///
+/// Code is literal, so nothing inside backticks is unwrapped
+/// (`` `__init__` `` → `__init__`).
+pub(super) fn unwrap_markup(text: &str) -> &str {
+    let mut text = text.trim();
+    for marker in ["**", "__", "`", "*", "_"] {
+        if let Some(inner) = text
+            .strip_prefix(marker)
+            .and_then(|rest| rest.strip_suffix(marker))
+        {
+            if !inner.is_empty() {
+                text = inner.trim();
+                if marker == "`" {
+                    break;
+                }
+            }
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse(input: &str) -> Option<Value> {
+        KeyValueStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .next()
+            .map(|c| c.value)
+    }
+
+    #[test]
+    fn test_plain_and_equals_lines() {
+        let input = "Here's what I found:\nName: Alice\nAge = 30\nWebsite: https://example.com";
+        assert_eq!(
+            parse(input),
+            Some(json!({"Name": "Alice", "Age": "30", "Website": "https://example.com"}))
+        );
+    }
+
+    #[test]
+    fn test_bullets_and_bold_keys() {
+        let input = "- **Name**: Alice\n* **Age:** 30\n1. __City__: `Paris`";
+        assert_eq!(
+            parse(input),
+            Some(json!({"Name": "Alice", "Age": "30", "City": "Paris"}))
+        );
+    }
+
+    #[test]
+    fn test_nested_sections() {
+        let input = "\
+Name: Alice
+Address:
+  Street: 1 Main St
+  Geo:
+    Lat: 48.8
+City: Paris
+Contact:
+- Email: a@example.com
+- Phone: 555";
+        assert_eq!(
+            parse(input),
+            Some(json!({
+                "Name": "Alice",
+                "Address": {"Street": "1 Main St", "Geo": {"Lat": "48.8"}},
+                "City": "Paris",
+                "Contact": {"Email": "a@example.com", "Phone": "555"},
+            }))
+        );
+    }
+
+    #[test]
+    fn test_lead_in_before_blank_line_is_not_a_section() {
+        let input = "Here's the profile:\n\n- Name: Alice\n- Age: 30";
+        assert_eq!(parse(input), Some(json!({"Name": "Alice", "Age": "30"})));
+    }
+
+    #[test]
+    fn test_repeated_keys_and_bullet_lists() {
+        let input = "Tag: rust\nTag: json\nSkills:\n- parsing\n- coercion\nNotes:";
+        assert_eq!(
+            parse(input),
+            Some(json!({
+                "Tag": ["rust", "json"],
+                "Skills": ["parsing", "coercion"],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_unwrap_markup() {
+        assert_eq!(unwrap_markup(" **Name** "), "Name");
+        assert_eq!(unwrap_markup("**`id`**"), "id");
+        assert_eq!(unwrap_markup("`__init__`"), "__init__");
+        assert_eq!(unwrap_markup("`*`"), "*");
+        assert_eq!(unwrap_markup("__"), "__");
+    }
+
+    #[test]
+    fn test_not_key_value() {
+        assert_eq!(parse("{\"name\": \"Alice\"}"), None);
+        assert_eq!(parse("Name: Alice\nResult: {\"age\": 30}"), None);
+        assert_eq!(parse("Note: this is a single remark."), None);
+        assert_eq!(parse("See https://a.example and https://b.example"), None);
+        assert_eq!(parse("At 10:30 we met.\nThen at 11:45 we left."), None);
+    }
+}
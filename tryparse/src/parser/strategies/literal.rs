@@ -0,0 +1,169 @@
+//! Shared driver for the strategies that read a literal syntax beyond JSON
+//! (JSON5, Python and JavaScript) and convert it to JSON.
+
+use crate::{
+    error::{ParseError, Result},
+    parser::{
+        strategies::{Extractor, MarkdownExtractor},
+        CandidateSource,
+    },
+    value::FlexValue,
+};
+
+/// Most start positions tried when looking for a literal inside prose.
+pub(super) const MAX_EMBEDDED_STARTS: usize = 32;
+
+/// Why reading stopped.
+#[derive(Debug)]
+pub(super) enum Fail {
+    /// The text isn't in the syntax being read.
+    Syntax,
+    /// A non-finite number under
+    /// [`NonFinitePolicy::Error`](super::NonFinitePolicy::Error).
+    NonFinite(String),
+}
+
+pub(super) type Step<T> = std::result::Result<T, Fail>;
+
+/// A literal syntax and how its reads become candidates.
+///
+/// [`LiteralSyntax::parse_literals`] tries the whole input first, then
+/// fences tagged with one of [`LiteralSyntax::FENCE_LANGUAGES`], then
+/// literals inside prose. A non-finite number under
+/// [`NonFinitePolicy::Error`](super::NonFinitePolicy::Error) fails the
+/// strategy, so it contributes no candidate.
+pub(super) trait LiteralSyntax {
+    /// What reading one literal produces.
+    type Literal;
+
+    /// Fence languages whose content may be in this syntax.
+    const FENCE_LANGUAGES: &'static [&'static str];
+
+    /// Finds the fences.
+    fn fences(&self) -> &MarkdownExtractor;
+
+    /// Reads all of `text` as one literal.
+    fn read_document(&self, text: &str) -> Step<Self::Literal>;
+
+    /// Reads one literal at the start of `text`, ignoring whatever follows
+    /// it, and returns it with the number of bytes it took.
+    fn read_embedded(&self, text: &str) -> Step<(Self::Literal, usize)>;
+
+    /// Returns where a literal may start in `input`, at or after `from`.
+    fn next_start(&self, input: &str, from: usize) -> Option<usize>;
+
+    /// Converts a literal read from `text` to a candidate, or `None` if it
+    /// isn't worth one. `embedded` says whether it was found inside prose.
+    fn candidate(
+        &self,
+        literal: Self::Literal,
+        text: &str,
+        embedded: bool,
+    ) -> Result<Option<FlexValue>>;
+
+    /// Parses the whole input, else its fences, else the literals in prose.
+    fn parse_literals(&self, input: &str) -> Result<Vec<FlexValue>> {
+        if let Some(candidate) = document(self, input.trim())? {
+            return Ok(vec![candidate]);
+        }
+
+        let mut candidates = Vec::new();
+        for block in fenced_blocks(self.fences(), input, Self::FENCE_LANGUAGES) {
+            candidates.extend(document(self, block.trim())?);
+        }
+        if candidates.is_empty() {
+            candidates = embedded(self, input)?;
+        }
+        Ok(candidates)
+    }
+}
+
+/// Returns the content of the fences in `input` tagged with one of
+/// `languages`.
+fn fenced_blocks(fences: &MarkdownExtractor, input: &str, languages: &[&str]) -> Vec<String> {
+    fences
+        .extract(input)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| {
+            matches!(
+                &c.source,
+                CandidateSource::Markdown { language: Some(lang) }
+                    if languages.iter().any(|l| lang.eq_ignore_ascii_case(l))
+            )
+        })
+        .map(|c| c.content)
+        .collect()
+}
+
+/// Reads all of `text` as one literal.
+fn document<S: LiteralSyntax + ?Sized>(syntax: &S, text: &str) -> Result<Option<FlexValue>> {
+    match syntax.read_document(text) {
+        Ok(literal) => syntax.candidate(literal, text, false),
+        Err(Fail::NonFinite(literal)) => Err(ParseError::NonFiniteNumber { literal }),
+        Err(Fail::Syntax) => Ok(None),
+    }
+}
+
+/// Reads the literals in prose, skipping text that isn't one.
+fn embedded<S: LiteralSyntax + ?Sized>(syntax: &S, input: &str) -> Result<Vec<FlexValue>> {
+    let mut candidates = Vec::new();
+    let mut from = 0;
+
+    for _ in 0..MAX_EMBEDDED_STARTS {
+        let Some(start) = syntax.next_start(input, from) else {
+            break;
+        };
+
+        match syntax.read_embedded(&input[start..]) {
+            Ok((literal, len)) => {
+                let end = start + len;
+                candidates.extend(syntax.candidate(literal, &input[start..end], true)?);
+                from = end;
+            }
+            Err(Fail::NonFinite(literal)) => return Err(ParseError::NonFiniteNumber { literal }),
+            // Skip the whole bracket so a literal nested in one that failed,
+            // like the list in `{'a': [1, 2], 'ok': true}`, isn't read alone
+            Err(Fail::Syntax) => {
+                from = bracket_len(&input[start..]).map_or(start + 1, |len| start + len)
+            }
+        }
+    }
+    Ok(candidates)
+}
+
+/// Returns the length of the bracket that opens `text`, directly or after a
+/// call name like `User(`, up to its matching close, skipping quoted strings.
+fn bracket_len(text: &str) -> Option<usize> {
+    let open = text.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))?;
+    if !text[open..].starts_with(['{', '[', '(']) {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip_while(|&(i, _)| i < open) {
+        if let Some(q) = quote {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                _ if c == q => quote = None,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' | '`' => quote = Some(c),
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
@@ -2,9 +2,10 @@
 
 use regex::Regex;
 
-use super::ParsingStrategy;
+use super::{JsonFixerStrategy, NonFinitePolicy, ParsingStrategy};
 use crate::{
-    error::Result,
+    error::{ParseError, Result},
+    parser::duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
     value::{FlexValue, Source},
 };
 
@@ -14,6 +15,8 @@ use crate::{
 /// - ` ```json ... ``` `
 /// - ` ``` ... ``` ` (generic code blocks)
 ///
+/// Repeated object keys are resolved by its [`DuplicateKeyPolicy`].
+///
 /// # Examples
 ///
 /// ```
@@ -29,6 +32,10 @@ use crate::{
 pub struct MarkdownStrategy {
     /// Regex for extracting code blocks.
     code_block_regex: Regex,
+    /// How repeated object keys are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
+    /// Whether repair may quote bare `NaN` and `Infinity` values.
+    non_finite: NonFinitePolicy,
 }
 
 impl Default for MarkdownStrategy {
@@ -45,7 +52,52 @@ impl MarkdownStrategy {
         // Captures: (language tag, content)
         let code_block_regex = Regex::new(r"(?s)```(\w*)\n(.*?)```").unwrap();
 
-        Self { code_block_regex }
+        Self {
+            code_block_regex,
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            non_finite: NonFinitePolicy::default(),
+        }
+    }
+
+    /// Sets how repeated object keys are resolved.
+    #[inline]
+    pub fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Sets how bare `NaN` and `Infinity` values are treated when a block is
+    /// repaired; see [`JsonFixerStrategy::with_non_finite_policy`].
+    #[inline]
+    pub fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Parses a block directly, or with the JSON fixer if that fails.
+    fn parse_block(&self, content: &str, lang: &Option<String>) -> Result<Vec<FlexValue>> {
+        let source = Source::Markdown { lang: lang.clone() };
+        if let Some(candidate) = parse_candidate(content, self.duplicate_keys, |value| {
+            FlexValue::new(value, source.clone())
+        })? {
+            return Ok(vec![candidate]);
+        }
+
+        // Direct parsing failed, try JSON fixer strategies
+        // This handles unquoted keys, triple-quoted strings, etc.
+        let fixer = JsonFixerStrategy::default()
+            .with_duplicate_key_policy(self.duplicate_keys)
+            .with_non_finite_policy(self.non_finite);
+        let mut candidates = match fixer.parse(content) {
+            Ok(candidates) => candidates,
+            Err(e @ ParseError::DuplicateKey { .. }) => return Err(e),
+            Err(_) => Vec::new(),
+        };
+        for candidate in &mut candidates {
+            // Preserve markdown source (overwrite fixer source)
+            candidate.source = source.clone();
+        }
+        Ok(candidates)
     }
 
     /// Removes trailing commas from JSON.
@@ -212,6 +264,8 @@ impl ParsingStrategy for MarkdownStrategy {
 
         // Collect valid JSON blocks with their scores
         let mut scored_candidates: Vec<(i32, FlexValue)> = Vec::new();
+        // A block that repeats a key under DuplicateKeyPolicy::Error is skipped
+        let mut policy_error = None;
 
         // First, try blocks explicitly marked as JSON
         let json_blocks: Vec<_> = blocks
@@ -225,31 +279,16 @@ impl ParsingStrategy for MarkdownStrategy {
                 // Apply trailing comma removal before parsing
                 let fixed_content = self.remove_trailing_commas(content);
 
-                // Try direct parsing first
-                if let Ok(value) = serde_json::from_str(&fixed_content) {
-                    let score = self.score_block(input, content, *index, json_blocks.len());
-                    scored_candidates.push((
-                        score,
-                        FlexValue::new(value, Source::Markdown { lang: lang.clone() }),
-                    ));
-                } else {
-                    // Direct parsing failed, try JSON fixer strategies
-                    // This handles unquoted keys, triple-quoted strings, etc.
-                    use super::JsonFixerStrategy;
-                    let fixer = JsonFixerStrategy::default();
-                    if let Ok(fixed_candidates) = fixer.parse(&fixed_content) {
-                        for flex_val in fixed_candidates {
+                match self.parse_block(&fixed_content, lang) {
+                    Ok(candidates) => {
+                        for candidate in candidates {
                             let score = self.score_block(input, content, *index, json_blocks.len());
-                            // Preserve markdown source (overwrite fixer source)
-                            scored_candidates.push((
-                                score,
-                                FlexValue::new(
-                                    flex_val.value,
-                                    Source::Markdown { lang: lang.clone() },
-                                ),
-                            ));
+                            scored_candidates.push((score, candidate));
                         }
                     }
+                    Err(e) => {
+                        policy_error.get_or_insert(e);
+                    }
                 }
             }
         } else {
@@ -267,37 +306,30 @@ impl ParsingStrategy for MarkdownStrategy {
                     // Apply trailing comma removal before parsing
                     let fixed_content = self.remove_trailing_commas(trimmed);
 
-                    // Try direct parsing first
-                    if let Ok(value) = serde_json::from_str(&fixed_content) {
-                        let score = self.score_block(input, content, *index, unmarked_blocks.len());
-                        scored_candidates.push((
-                            score,
-                            FlexValue::new(value, Source::Markdown { lang: lang.clone() }),
-                        ));
-                    } else {
-                        // Direct parsing failed, try JSON fixer strategies
-                        use super::JsonFixerStrategy;
-                        let fixer = JsonFixerStrategy::default();
-                        if let Ok(fixed_candidates) = fixer.parse(&fixed_content) {
-                            for flex_val in fixed_candidates {
+                    match self.parse_block(&fixed_content, lang) {
+                        Ok(candidates) => {
+                            for candidate in candidates {
                                 let score =
                                     self.score_block(input, content, *index, unmarked_blocks.len());
-                                scored_candidates.push((
-                                    score,
-                                    FlexValue::new(
-                                        flex_val.value,
-                                        Source::Markdown { lang: lang.clone() },
-                                    ),
-                                ));
+                                scored_candidates.push((score, candidate));
                             }
                         }
+                        Err(e) => {
+                            policy_error.get_or_insert(e);
+                        }
                     }
                 }
             }
         }
 
+        if scored_candidates.is_empty() {
+            if let Some(e) = policy_error {
+                return Err(e);
+            }
+        }
+
         // Sort by score (descending) and return candidates
-        scored_candidates.sort_by(|a, b| b.0.cmp(&a.0));
+        scored_candidates.sort_by_key(|c| std::cmp::Reverse(c.0));
 
         // Return all candidates in score order (best first)
         // The parser framework will use the best one
@@ -0,0 +1,233 @@
+//! Markdown table strategy that converts pipe tables to arrays of objects.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use super::key_value::unwrap_markup;
+use crate::{
+    error::Result,
+    parser::strategies::ParsingStrategy,
+    value::{FlexValue, Source},
+};
+
+/// Matches a delimiter row like `|---|:--:|--:|` (the pipes are optional at
+/// the edges, required between cells).
+static DELIMITER_ROW: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\|?\s*:?-+:?\s*(?:\|\s*:?-+:?\s*)*\|?$").unwrap());
+
+/// Strategy that converts GitHub-flavored markdown tables to JSON.
+///
+/// Each table (a header row, a delimiter row with optional alignment colons,
+/// then body rows) becomes an array with one object per body row, keyed by
+/// the header cells:
+///
+/// - Cells are strings; the coercer turns `"30"` into a number when the
+///   target wants one
+/// - `\|` is a literal pipe inside a cell
+/// - Ragged rows are fine: extra cells are dropped, and missing or empty
+///   cells leave the key out, so `Option` fields become `None`
+/// - Emphasis or code wrapping a whole cell (`**Name**`, `` `id` ``) is
+///   removed
+/// - Empty headers are named `column_1`, `column_2`, ...
+///
+/// Every table in the input yields a candidate, in document order.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{MarkdownTableStrategy, ParsingStrategy};
+///
+/// let input = "| Name | Age |\n|:-----|----:|\n| Alice | 30 |\n| Bob |";
+/// let candidates = MarkdownTableStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value[0]["Age"], "30");
+/// assert!(candidates[0].value[1].get("Age").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownTableStrategy;
+
+impl MarkdownTableStrategy {
+    /// Creates a new markdown table strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Finds the tables in `input` and converts each to an array of objects.
+    fn tables(input: &str) -> Vec<Value> {
+        let lines: Vec<&str> = input.lines().map(str::trim).collect();
+        let mut tables = Vec::new();
+        let mut i = 0;
+
+        while i + 1 < lines.len() {
+            let (header, delimiter) = (lines[i], lines[i + 1]);
+            if !header.contains('|') || !DELIMITER_ROW.is_match(delimiter) {
+                i += 1;
+                continue;
+            }
+
+            let headers: Vec<String> = split_row(header)
+                .into_iter()
+                .enumerate()
+                .map(|(n, cell)| {
+                    if cell.is_empty() {
+                        format!("column_{}", n + 1)
+                    } else {
+                        cell
+                    }
+                })
+                .collect();
+            // The delimiter row decides how many columns the table has
+            let columns = split_row(delimiter).len();
+            if headers.len() != columns {
+                i += 1;
+                continue;
+            }
+
+            i += 2;
+            let mut rows = Vec::new();
+            while i < lines.len() && lines[i].contains('|') {
+                let row: Map<String, Value> = headers
+                    .iter()
+                    .zip(split_row(lines[i]))
+                    .filter(|(_, cell)| !cell.is_empty())
+                    .map(|(key, cell)| (key.clone(), Value::String(cell)))
+                    .collect();
+                rows.push(Value::Object(row));
+                i += 1;
+            }
+            tables.push(Value::Array(rows));
+        }
+
+        tables
+    }
+}
+
+impl ParsingStrategy for MarkdownTableStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "markdown_table"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        // Quick check: a table needs a pipe and a delimiter row
+        if !input.contains('|') || !input.contains('-') {
+            return Ok(Vec::new());
+        }
+
+        Ok(Self::tables(input)
+            .into_iter()
+            .map(|table| FlexValue::new(table, Source::MarkdownTable))
+            .collect())
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        3 // Pipe tables rarely occur by accident, so they're tried with YAML
+    }
+}
+
+/// Splits a table row into trimmed cells, honoring `\|` escapes.
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => line,
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(unwrap_markup(&std::mem::take(&mut cell)).to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(unwrap_markup(&cell).to_string());
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn tables(input: &str) -> Vec<Value> {
+        MarkdownTableStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_simple_table() {
+        let input = "Here are the users:\n\n\
+                     | Name | Age | City |\n\
+                     |------|-----|------|\n\
+                     | Alice | 30 | Paris |\n\
+                     | Bob | 25 | Lyon |\n\n\
+                     Anything else?";
+        assert_eq!(
+            tables(input),
+            vec![json!([
+                {"Name": "Alice", "Age": "30", "City": "Paris"},
+                {"Name": "Bob", "Age": "25", "City": "Lyon"},
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_alignment_and_no_edge_pipes() {
+        let input = "id | score\n:-- | --:\n1 | 9.5\n2 | 7";
+        assert_eq!(
+            tables(input),
+            vec![json!([{"id": "1", "score": "9.5"}, {"id": "2", "score": "7"}])]
+        );
+    }
+
+    #[test]
+    fn test_escaped_pipes_and_formatting() {
+        let input =
+            "| **Command** | `Output` |\n|---|---|\n| `a \\| b` | **ok** |\n| `__init__` | _x_ |";
+        assert_eq!(
+            tables(input),
+            vec![json!([
+                {"Command": "a | b", "Output": "ok"},
+                {"Command": "__init__", "Output": "x"},
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let input = "| a | b | c |\n|---|---|---|\n| 1 | 2 | 3 | 4 |\n| 5 |\n| | 6 |";
+        assert_eq!(
+            tables(input),
+            vec![json!([{"a": "1", "b": "2", "c": "3"}, {"a": "5"}, {"b": "6"}])]
+        );
+    }
+
+    #[test]
+    fn test_empty_headers_and_multiple_tables() {
+        let input = "| | x |\n|-|-|\n| r1 | 1 |\n\ntext\n\n| y |\n|---|\n| 2 |";
+        assert_eq!(
+            tables(input),
+            vec![json!([{"column_1": "r1", "x": "1"}]), json!([{"y": "2"}])]
+        );
+    }
+
+    #[test]
+    fn test_not_a_table() {
+        assert!(tables("a | b\nc | d").is_empty());
+        assert!(tables("---\ntitle: x\n---").is_empty());
+        assert!(tables("| a | b |\n|---|").is_empty()); // Column count mismatch
+    }
+}
@@ -1,26 +1,49 @@
 //! Parsing strategies for extracting JSON from messy LLM responses.
 
+mod csv;
 mod direct_json;
 mod markdown;
+mod markdown_table;
 mod multiple_objects;
+mod python;
 
 #[cfg(feature = "yaml")]
 mod yaml;
 
 mod extractor;
+mod headings;
 mod heuristic;
+mod javascript;
+mod json5;
 mod json_fixer;
+mod key_value;
+mod literal;
 mod raw_primitive;
 mod state_machine_strategy;
+#[cfg(feature = "toml")]
+mod toml;
+mod xml;
+mod xml_tags;
 
+pub use csv::{CsvStrategy, CSV_DELIMITERS};
 pub use direct_json::DirectJsonStrategy;
 pub use extractor::{DirectExtractor, Extractor, HeuristicExtractor, MarkdownExtractor};
+pub use headings::HeadingSectionStrategy;
 pub use heuristic::HeuristicStrategy;
+pub use javascript::JavaScriptStrategy;
+pub use json5::{Json5Strategy, NonFinitePolicy};
 pub use json_fixer::JsonFixerStrategy;
+pub use key_value::KeyValueStrategy;
 pub use markdown::MarkdownStrategy;
+pub use markdown_table::MarkdownTableStrategy;
 pub use multiple_objects::MultipleObjectsStrategy;
+pub use python::PythonLiteralStrategy;
 pub use raw_primitive::RawPrimitiveStrategy;
 pub use state_machine_strategy::StateMachineStrategy;
+#[cfg(feature = "toml")]
+pub use toml::TomlStrategy;
+pub use xml::{XmlStrategy, XML_TEXT_KEY};
+pub use xml_tags::{XmlTagExtractor, XmlTagStrategy, DEFAULT_TAGS};
 #[cfg(feature = "yaml")]
 pub use yaml::YamlStrategy;
 
@@ -10,8 +10,9 @@ use serde_json::Value;
 
 use super::ParsingStrategy;
 use crate::{
-    error::Result,
-    value::{FlexValue, Source},
+    error::{ParseError, Result},
+    parser::duplicate_keys::{parse_json, DuplicateKeyPolicy, ParseWithDuplicateKeys},
+    value::{FlexValue, Source, Transformation},
 };
 
 #[derive(Debug, Default)]
@@ -100,12 +101,12 @@ impl MultipleObjectsStrategy {
     }
 }
 
-impl ParsingStrategy for MultipleObjectsStrategy {
-    fn name(&self) -> &'static str {
-        "multiple_objects"
-    }
-
-    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+impl ParseWithDuplicateKeys for MultipleObjectsStrategy {
+    fn parse_with_duplicate_keys(
+        &self,
+        input: &str,
+        policy: DuplicateKeyPolicy,
+    ) -> Result<Vec<FlexValue>> {
         let json_values = self.find_all_json_values(input);
 
         // Only create a candidate if we found 2+ values
@@ -114,11 +115,30 @@ impl ParsingStrategy for MultipleObjectsStrategy {
             return Ok(Vec::new());
         }
 
-        // Parse each JSON value
+        // Parse each JSON value, recording repeated keys at their array index
         let mut parsed_values = Vec::new();
+        let mut repeated = Vec::new();
         for json_str in &json_values {
-            if let Ok(value) = serde_json::from_str::<Value>(json_str) {
-                parsed_values.push(value);
+            let index = parsed_values.len();
+            match parse_json(json_str, policy) {
+                Ok((value, transformations)) => {
+                    parsed_values.push(value);
+                    repeated.extend(transformations.into_iter().map(|t| match t {
+                        Transformation::DuplicateKey { path, policy } => {
+                            Transformation::DuplicateKey {
+                                path: format!("[{}].{}", index, path),
+                                policy,
+                            }
+                        }
+                        other => other,
+                    }));
+                }
+                Err(ParseError::DuplicateKey { path }) => {
+                    return Err(ParseError::DuplicateKey {
+                        path: format!("[{}].{}", index, path),
+                    })
+                }
+                Err(_) => {}
             }
         }
 
@@ -127,12 +147,25 @@ impl ParsingStrategy for MultipleObjectsStrategy {
             let array = Value::Array(parsed_values);
             // Use Source::MultiJsonArray to indicate this is a collected array
             // (Don't use Source::Direct as it would prevent other strategies from running)
-            let flex = FlexValue::new(array, Source::MultiJsonArray);
+            let mut flex = FlexValue::new(array, Source::MultiJsonArray);
+            for transformation in repeated {
+                flex.add_transformation(transformation);
+            }
             Ok(vec![flex])
         } else {
             Ok(Vec::new())
         }
     }
+}
+
+impl ParsingStrategy for MultipleObjectsStrategy {
+    fn name(&self) -> &'static str {
+        "multiple_objects"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        self.parse_with_duplicate_keys(input, DuplicateKeyPolicy::default())
+    }
 
     fn priority(&self) -> u8 {
         // Highest priority - MUST run before DirectJsonStrategy to detect multiple objects
@@ -0,0 +1,991 @@
+//! Python literal strategy for `repr()`-style dicts, lists and dataclasses.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::de::IgnoredAny;
+use serde_json::Number;
+
+use super::{
+    literal::{Fail, LiteralSyntax, Step},
+    NonFinitePolicy, ParsingStrategy,
+};
+use crate::{
+    error::Result,
+    parser::{
+        duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
+        strategies::MarkdownExtractor,
+        MAX_NESTING_DEPTH,
+    },
+    value::{FlexValue, JsonFix, Source, Transformation},
+};
+
+/// Matches where a literal may start in prose: a bracket, or a constructor
+/// call like `User(`, `models.User(` or `dict(`. Other lowercase calls like
+/// `print(x, end='')` are code, not printed values.
+static EMBEDDED_START: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[{\[]|\b(?:[A-Za-z_][A-Za-z0-9_]*\.)*(?:[A-Z][A-Za-z0-9_]*|dict)\(").unwrap()
+});
+
+/// Strategy that parses Python literal syntax, as printed by `repr()` or
+/// `print()`, into JSON.
+///
+/// This strategy reads:
+/// - Dicts, lists, tuples and sets; tuples and sets become arrays
+/// - `True`, `False` and `None`
+/// - Strings with any quotes (including triple quotes), `r`/`b`/`u`
+///   prefixes, Python escapes and implicit concatenation (`'a' 'b'`)
+/// - Ints with `_` separators or `0x`/`0o`/`0b` prefixes, and floats
+/// - `Decimal('1.2')`, `float('inf')` and `int('3')` as numbers
+/// - `inf`, `-inf`, `nan` and `math.inf` as printed, which like
+///   `float('inf')` follow a [`NonFinitePolicy`] and are recorded as a
+///   [`JsonFix::NonFiniteNumbers`] repair
+/// - `set()`, `tuple([...])`, `dict(a=1)` and `OrderedDict([('a', 1)])`
+/// - Dataclass and namedtuple reprs (`User(name='Alice', age=30)`) as
+///   objects of their keyword arguments, and enum reprs
+///   (`<Color.RED: 'red'>`) as their value
+/// - `#` comments
+///
+/// Dict keys that aren't strings become their JSON text (`1` → `"1"`), and
+/// repeated keys follow the strategy's [`DuplicateKeyPolicy`].
+///
+/// The whole input is tried first, then ```` ```python ```` fences, then
+/// literals inside prose. Only dicts, lists and objects become candidates,
+/// and text that is already plain JSON is left to the JSON strategies.
+/// Candidates get [`Source::Python`].
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{ParsingStrategy, PythonLiteralStrategy};
+///
+/// let input = "{'name': 'Alice', 'active': True, 'tags': ('a', 'b'), 'score': None}";
+/// let candidates = PythonLiteralStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["active"], true);
+/// assert_eq!(candidates[0].value["tags"][1], "b");
+/// assert!(candidates[0].value["score"].is_null());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PythonLiteralStrategy {
+    /// Finds ```` ```python ```` fences.
+    fences: MarkdownExtractor,
+    /// How `float('inf')` and `float('nan')` are represented.
+    non_finite: NonFinitePolicy,
+    /// How repeated dict keys are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
+}
+
+impl PythonLiteralStrategy {
+    /// Creates a new Python literal strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how `float('inf')` and `float('nan')` are represented.
+    #[must_use]
+    pub const fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Sets how repeated dict keys are resolved.
+    #[must_use]
+    pub const fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+}
+
+impl LiteralSyntax for PythonLiteralStrategy {
+    /// The literal, and whether it held `inf` or `nan`.
+    type Literal = (Node, bool);
+
+    const FENCE_LANGUAGES: &'static [&'static str] = &["python", "py", "python3", "pycon"];
+
+    fn fences(&self) -> &MarkdownExtractor {
+        &self.fences
+    }
+
+    fn read_document(&self, text: &str) -> Step<Self::Literal> {
+        let mut reader = Reader::new(text, self.non_finite);
+        let node = reader.document()?;
+        Ok((node, reader.non_finite_read))
+    }
+
+    fn read_embedded(&self, text: &str) -> Step<(Self::Literal, usize)> {
+        let mut reader = Reader::new(text, self.non_finite);
+        let node = reader.value(0)?;
+        Ok(((node, reader.non_finite_read), reader.pos))
+    }
+
+    fn next_start(&self, input: &str, from: usize) -> Option<usize> {
+        EMBEDDED_START.find_at(input, from).map(|m| m.start())
+    }
+
+    /// Skips scalars, and `text` that is already plain JSON.
+    fn candidate(
+        &self,
+        (node, non_finite): Self::Literal,
+        text: &str,
+        _embedded: bool,
+    ) -> Result<Option<FlexValue>> {
+        if !matches!(node, Node::Array(_) | Node::Object(_))
+            || serde_json::from_str::<IgnoredAny>(text).is_ok()
+        {
+            return Ok(None);
+        }
+        let mut json = String::new();
+        node.render(&mut json);
+        let candidate = parse_candidate(&json, self.duplicate_keys, |value| {
+            FlexValue::new(value, Source::Python)
+        })?;
+        Ok(candidate.map(|mut candidate| {
+            if non_finite {
+                candidate.add_transformation(Transformation::JsonRepaired {
+                    fixes: vec![JsonFix::NonFiniteNumbers],
+                });
+            }
+            candidate
+        }))
+    }
+}
+
+impl ParsingStrategy for PythonLiteralStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "python_literal"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        self.parse_literals(input)
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        3 // Same as the fixer: syntax beyond plain JSON
+    }
+}
+
+/// A parsed literal.
+///
+/// Objects keep repeated keys so the [`DuplicateKeyPolicy`] sees them.
+#[derive(Debug)]
+pub(super) enum Node {
+    /// A string, number, bool or null, as JSON text.
+    Scalar(String),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+impl Node {
+    pub(super) fn string(s: &str) -> Self {
+        // Serializing a `str` can't fail
+        Self::Scalar(serde_json::to_string(s).unwrap_or_default())
+    }
+
+    pub(super) fn render(&self, out: &mut String) {
+        match self {
+            Self::Scalar(json) => out.push_str(json),
+            Self::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.render(out);
+                }
+                out.push(']');
+            }
+            Self::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push_str(&serde_json::to_string(key).unwrap_or_default());
+                    out.push(':');
+                    value.render(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    /// Returns the string this node holds, if it's a string.
+    pub(super) fn as_str(&self) -> Option<String> {
+        match self {
+            Self::Scalar(json) if json.starts_with('"') => serde_json::from_str(json).ok(),
+            _ => None,
+        }
+    }
+
+    /// Converts a dict key to a string: strings as-is, anything else as its
+    /// JSON text.
+    pub(super) fn into_key(self) -> String {
+        self.as_str().unwrap_or_else(|| {
+            let mut text = String::new();
+            self.render(&mut text);
+            text
+        })
+    }
+}
+
+/// A call argument, with its keyword if it has one.
+type Arg = (Option<String>, Node);
+
+/// Reads Python literal syntax.
+struct Reader<'a> {
+    text: &'a str,
+    /// Byte offset of the next character.
+    pos: usize,
+    non_finite: NonFinitePolicy,
+    /// Whether an `inf` or `nan` was read.
+    non_finite_read: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn new(text: &'a str, non_finite: NonFinitePolicy) -> Self {
+        Self {
+            text,
+            pos: 0,
+            non_finite,
+            non_finite_read: false,
+        }
+    }
+
+    /// Reads one literal that must make up all of the text.
+    fn document(&mut self) -> Step<Node> {
+        let node = self.value(0)?;
+        self.skip_trivia();
+        if self.pos < self.text.len() {
+            return Err(Fail::Syntax);
+        }
+        Ok(node)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.pos += expected.len_utf8();
+        }
+        found
+    }
+
+    /// Skips whitespace and `#` comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some('#') => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.bump();
+                    }
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Step<Node> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(Fail::Syntax);
+        }
+        self.skip_trivia();
+        match self.peek().ok_or(Fail::Syntax)? {
+            '{' => self.dict_or_set(depth),
+            '[' => {
+                self.bump();
+                Ok(Node::Array(self.items(']', depth)?))
+            }
+            '(' => self.tuple(depth),
+            '<' => self.enum_repr(depth),
+            '\'' | '"' => self.strings(),
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => self.number(),
+            c if is_identifier_start(c) => {
+                if let Some(node) = self.string_with_prefix()? {
+                    return Ok(node);
+                }
+                self.name_or_call(depth)
+            }
+            _ => Err(Fail::Syntax),
+        }
+    }
+
+    /// Reads comma-separated values up to `close`, allowing a trailing comma.
+    fn items(&mut self, close: char, depth: usize) -> Step<Vec<Node>> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.eat(close) {
+                return Ok(items);
+            }
+            items.push(self.value(depth + 1)?);
+            self.skip_trivia();
+            if !self.eat(',') && self.peek() != Some(close) {
+                return Err(Fail::Syntax);
+            }
+        }
+    }
+
+    fn dict_or_set(&mut self, depth: usize) -> Step<Node> {
+        self.bump(); // '{'
+        self.skip_trivia();
+        if self.eat('}') {
+            return Ok(Node::Object(Vec::new()));
+        }
+
+        let first = self.value(depth + 1)?;
+        self.skip_trivia();
+        if !self.eat(':') {
+            // A set: `{1, 2}`
+            if !self.eat(',') && self.peek() != Some('}') {
+                return Err(Fail::Syntax);
+            }
+            let mut items = vec![first];
+            items.extend(self.items('}', depth)?);
+            return Ok(Node::Array(items));
+        }
+
+        let mut entries = vec![(first.into_key(), self.value(depth + 1)?)];
+        loop {
+            self.skip_trivia();
+            if self.eat('}') {
+                return Ok(Node::Object(entries));
+            }
+            if !self.eat(',') {
+                return Err(Fail::Syntax);
+            }
+            self.skip_trivia();
+            if self.eat('}') {
+                return Ok(Node::Object(entries));
+            }
+
+            let key = self.value(depth + 1)?.into_key();
+            self.skip_trivia();
+            if !self.eat(':') {
+                return Err(Fail::Syntax);
+            }
+            entries.push((key, self.value(depth + 1)?));
+        }
+    }
+
+    /// Reads a tuple, or a value in parentheses like `(1)`.
+    fn tuple(&mut self, depth: usize) -> Step<Node> {
+        self.bump(); // '('
+        self.skip_trivia();
+        if self.eat(')') {
+            return Ok(Node::Array(Vec::new()));
+        }
+
+        let first = self.value(depth + 1)?;
+        self.skip_trivia();
+        if self.eat(')') {
+            return Ok(first);
+        }
+        if !self.eat(',') {
+            return Err(Fail::Syntax);
+        }
+        let mut items = vec![first];
+        items.extend(self.items(')', depth)?);
+        Ok(Node::Array(items))
+    }
+
+    /// Reads an enum repr like `<Color.RED: 'red'>` as its value.
+    fn enum_repr(&mut self, depth: usize) -> Step<Node> {
+        self.bump(); // '<'
+        self.dotted_name()?;
+        self.skip_trivia();
+        if !self.eat(':') {
+            return Err(Fail::Syntax);
+        }
+        let value = self.value(depth + 1)?;
+        self.skip_trivia();
+        if !self.eat('>') {
+            return Err(Fail::Syntax);
+        }
+        Ok(value)
+    }
+
+    fn identifier(&mut self) -> Step<&'a str> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_part) {
+            self.bump();
+        }
+        let name = &self.text[start..self.pos];
+        if name.starts_with(is_identifier_start) {
+            Ok(name)
+        } else {
+            self.pos = start;
+            Err(Fail::Syntax)
+        }
+    }
+
+    fn dotted_name(&mut self) -> Step<&'a str> {
+        let start = self.pos;
+        self.identifier()?;
+        while self.peek() == Some('.') {
+            self.bump();
+            self.identifier()?;
+        }
+        Ok(&self.text[start..self.pos])
+    }
+
+    fn name_or_call(&mut self, depth: usize) -> Step<Node> {
+        let name = self.dotted_name()?;
+        match name {
+            "True" => return Ok(Node::Scalar("true".to_string())),
+            "False" => return Ok(Node::Scalar("false".to_string())),
+            "None" => return Ok(Node::Scalar("null".to_string())),
+            _ => {}
+        }
+
+        self.skip_trivia();
+        if !self.eat('(') {
+            // `repr(float('inf'))` is `inf`; `math.inf` and `np.nan` are common too
+            return match name.rsplit('.').next() {
+                Some("inf") => self.non_finite_number("Infinity"),
+                Some("nan") => self.non_finite_number("NaN"),
+                _ => Err(Fail::Syntax),
+            };
+        }
+        let args = self.args(depth)?;
+        let function = name.rsplit('.').next().unwrap_or(name);
+        self.call(function, args)
+    }
+
+    /// Reads call arguments after the `(`.
+    fn args(&mut self, depth: usize) -> Step<Vec<Arg>> {
+        let mut args = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.eat(')') {
+                return Ok(args);
+            }
+
+            let start = self.pos;
+            let keyword = match self.identifier() {
+                Ok(name) => {
+                    self.skip_trivia();
+                    if self.eat('=') && self.peek() != Some('=') {
+                        Some(name.to_string())
+                    } else {
+                        self.pos = start;
+                        None
+                    }
+                }
+                Err(_) => None,
+            };
+            args.push((keyword, self.value(depth + 1)?));
+
+            self.skip_trivia();
+            if !self.eat(',') && self.peek() != Some(')') {
+                return Err(Fail::Syntax);
+            }
+        }
+    }
+
+    /// Evaluates the calls that build literals.
+    fn call(&mut self, function: &str, mut args: Vec<Arg>) -> Step<Node> {
+        let positional = args.iter().filter(|(k, _)| k.is_none()).count();
+        match function {
+            "Decimal" | "float" | "int" if args.len() == 1 && positional == 1 => {
+                let (_, arg) = args.remove(0);
+                self.numeric(arg)
+            }
+            "set" | "frozenset" | "tuple" | "list" | "deque" if args.len() <= 1 => {
+                match args.pop() {
+                    None => Ok(Node::Array(Vec::new())),
+                    Some((None, node @ Node::Array(_))) => Ok(node),
+                    _ => Err(Fail::Syntax),
+                }
+            }
+            "dict" | "OrderedDict" if positional <= 1 => {
+                let mut entries = Vec::new();
+                for (keyword, node) in args {
+                    match (keyword, node) {
+                        (Some(key), value) => entries.push((key, value)),
+                        (None, Node::Object(pairs)) => entries.extend(pairs),
+                        (None, Node::Array(pairs)) => {
+                            for pair in pairs {
+                                let Node::Array(mut pair) = pair else {
+                                    return Err(Fail::Syntax);
+                                };
+                                if pair.len() != 2 {
+                                    return Err(Fail::Syntax);
+                                }
+                                let value = pair.remove(1);
+                                entries.push((pair.remove(0).into_key(), value));
+                            }
+                        }
+                        _ => return Err(Fail::Syntax),
+                    }
+                }
+                Ok(Node::Object(entries))
+            }
+            // A dataclass or namedtuple repr: `User(name='Alice', age=30)`
+            _ if positional == 0 => Ok(Node::Object(
+                args.into_iter()
+                    .filter_map(|(k, v)| k.map(|k| (k, v)))
+                    .collect(),
+            )),
+            _ => Err(Fail::Syntax),
+        }
+    }
+
+    /// Converts the argument of `Decimal(...)`, `float(...)` or `int(...)`.
+    fn numeric(&mut self, arg: Node) -> Step<Node> {
+        let Some(text) = arg.as_str() else {
+            return match arg {
+                Node::Scalar(json) if json.parse::<Number>().is_ok() => Ok(Node::Scalar(json)),
+                _ => Err(Fail::Syntax),
+            };
+        };
+
+        let text = text.trim().replace('_', "");
+        let unsigned = text.trim_start_matches(['+', '-']).to_ascii_lowercase();
+        if matches!(unsigned.as_str(), "inf" | "infinity" | "nan") {
+            let name = match (unsigned.as_str(), text.starts_with('-')) {
+                ("nan", _) => "NaN",
+                (_, true) => "-Infinity",
+                (_, false) => "Infinity",
+            };
+            return self.non_finite_number(name);
+        }
+
+        let mut reader = Reader::new(&text, self.non_finite);
+        match reader.number() {
+            Ok(node) if reader.pos == text.len() => Ok(node),
+            _ => Err(Fail::Syntax),
+        }
+    }
+
+    /// Represents `Infinity`, `-Infinity` or `NaN` by the [`NonFinitePolicy`].
+    fn non_finite_number(&mut self, name: &str) -> Step<Node> {
+        self.non_finite_read = true;
+        match self.non_finite {
+            NonFinitePolicy::Null => Ok(Node::Scalar("null".to_string())),
+            NonFinitePolicy::String => Ok(Node::string(name)),
+            NonFinitePolicy::Error => Err(Fail::NonFinite(name.to_string())),
+        }
+    }
+
+    /// Reads an int or float, with an optional sign.
+    fn number(&mut self) -> Step<Node> {
+        let mut json = String::new();
+        while let Some(sign @ ('-' | '+')) = self.peek() {
+            self.bump();
+            if sign == '-' {
+                json = if json.is_empty() {
+                    "-".to_string()
+                } else {
+                    String::new()
+                };
+            }
+            self.skip_trivia();
+        }
+
+        // `-inf`, `+math.inf`
+        if self.peek().is_some_and(is_identifier_start) {
+            return match self.dotted_name()?.rsplit('.').next() {
+                Some("inf") if json == "-" => self.non_finite_number("-Infinity"),
+                Some("inf") => self.non_finite_number("Infinity"),
+                Some("nan") => self.non_finite_number("NaN"),
+                _ => Err(Fail::Syntax),
+            };
+        }
+
+        let radix = match self.rest().get(..2).map(str::to_ascii_lowercase).as_deref() {
+            Some("0x") => 16,
+            Some("0o") => 8,
+            Some("0b") => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            self.pos += 2;
+            let digits = self.digits(|c| c.is_digit(radix));
+            let value = u128::from_str_radix(&digits, radix).map_err(|_| Fail::Syntax)?;
+            json.push_str(&value.to_string());
+            return self.finish_number(json);
+        }
+
+        let integer = self.digits(|c| c.is_ascii_digit());
+        let fraction = if self.eat('.') {
+            Some(self.digits(|c| c.is_ascii_digit()))
+        } else {
+            None
+        };
+        if integer.is_empty() && fraction.as_ref().map_or(true, String::is_empty) {
+            return Err(Fail::Syntax);
+        }
+
+        // JSON wants digits on both sides of the point and no leading zeros
+        let integer = integer.trim_start_matches('0');
+        json.push_str(if integer.is_empty() { "0" } else { integer });
+        if let Some(fraction) = fraction {
+            json.push('.');
+            json.push_str(if fraction.is_empty() { "0" } else { &fraction });
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.bump();
+            json.push('e');
+            if let Some(sign @ ('+' | '-')) = self.peek() {
+                self.bump();
+                json.push(sign);
+            }
+            let exponent = self.digits(|c| c.is_ascii_digit());
+            if exponent.is_empty() {
+                return Err(Fail::Syntax);
+            }
+            json.push_str(&exponent);
+        }
+        self.finish_number(json)
+    }
+
+    /// Rejects numbers run into a name, like `1j` or `1abc`.
+    fn finish_number(&self, json: String) -> Step<Node> {
+        if self.peek().is_some_and(is_identifier_part) {
+            Err(Fail::Syntax)
+        } else {
+            Ok(Node::Scalar(json))
+        }
+    }
+
+    /// Reads digits accepted by `is_digit`, dropping `_` separators.
+    fn digits(&mut self, is_digit: impl Fn(char) -> bool) -> String {
+        let mut digits = String::new();
+        while let Some(c) = self.peek().filter(|&c| is_digit(c) || c == '_') {
+            self.bump();
+            if c != '_' {
+                digits.push(c);
+            }
+        }
+        digits
+    }
+
+    /// Reads a string with a prefix like `r'...'` or `b"..."`, or returns
+    /// `None` if the name here isn't one.
+    fn string_with_prefix(&mut self) -> Step<Option<Node>> {
+        let prefix: String = self
+            .rest()
+            .chars()
+            .take_while(char::is_ascii_alphabetic)
+            .collect();
+        let quoted = self.rest()[prefix.len()..].starts_with(['\'', '"']);
+        let valid = matches!(
+            prefix.to_ascii_lowercase().as_str(),
+            "r" | "u" | "b" | "br" | "rb"
+        );
+        if quoted && valid {
+            self.strings().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads one or more adjacent string literals, which Python joins.
+    fn strings(&mut self) -> Step<Node> {
+        let mut joined = Vec::new();
+        loop {
+            self.literal(&mut joined)?;
+
+            let after = self.pos;
+            self.skip_trivia();
+            let prefix_len = self.rest().find(['\'', '"']).filter(|&n| {
+                let prefix = self.rest()[..n].to_ascii_lowercase();
+                matches!(prefix.as_str(), "" | "r" | "u" | "b" | "br" | "rb")
+            });
+            if prefix_len.is_none() {
+                self.pos = after;
+                return Ok(Node::string(&String::from_utf8_lossy(&joined)));
+            }
+        }
+    }
+
+    /// Reads one string literal, appending its UTF-8 bytes to `out`.
+    fn literal(&mut self, out: &mut Vec<u8>) -> Step<()> {
+        let mut raw = false;
+        let mut bytes = false;
+        while let Some(c) = self.peek().filter(char::is_ascii_alphabetic) {
+            match c.to_ascii_lowercase() {
+                'r' => raw = true,
+                'b' => bytes = true,
+                'u' => {}
+                _ => return Err(Fail::Syntax),
+            }
+            self.bump();
+        }
+
+        let quote = self.bump().ok_or(Fail::Syntax)?;
+        let pair = format!("{q}{q}", q = quote);
+        let triple = self.rest().starts_with(&pair);
+        if triple {
+            self.pos += 2;
+        }
+
+        loop {
+            let c = self.bump().ok_or(Fail::Syntax)?;
+            if c == quote {
+                if !triple {
+                    return Ok(());
+                }
+                if self.rest().starts_with(&pair) {
+                    self.pos += 2;
+                    return Ok(());
+                }
+            } else if c == '\\' {
+                if raw {
+                    // Raw strings keep the backslash, and it still escapes a quote
+                    out.push(b'\\');
+                    if let Some(next) = self.bump() {
+                        push_char(out, next);
+                    }
+                    continue;
+                }
+                self.escape(out, bytes)?;
+                continue;
+            } else if !triple && c == '\n' {
+                return Err(Fail::Syntax);
+            }
+            push_char(out, c);
+        }
+    }
+
+    /// Decodes the escape after a `\` in a string.
+    fn escape(&mut self, out: &mut Vec<u8>, bytes: bool) -> Step<()> {
+        let c = self.bump().ok_or(Fail::Syntax)?;
+        let decoded = match c {
+            '\n' => return Ok(()), // Line continuation
+            '\\' | '\'' | '"' => c,
+            'a' => '\u{7}',
+            'b' => '\u{8}',
+            'f' => '\u{C}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\u{B}',
+            '0'..='7' => {
+                let mut code = c.to_digit(8).unwrap_or(0);
+                for _ in 0..2 {
+                    match self.peek().and_then(|c| c.to_digit(8)) {
+                        Some(d) => {
+                            self.bump();
+                            code = code * 8 + d;
+                        }
+                        None => break,
+                    }
+                }
+                return self.push_code(out, code, bytes);
+            }
+            'x' => {
+                let code = self.hex_digits(2)?;
+                return self.push_code(out, code, bytes);
+            }
+            'u' if !bytes => char::from_u32(self.hex_digits(4)?).ok_or(Fail::Syntax)?,
+            'U' if !bytes => char::from_u32(self.hex_digits(8)?).ok_or(Fail::Syntax)?,
+            // Unknown escapes keep their backslash
+            _ => {
+                out.push(b'\\');
+                c
+            }
+        };
+        push_char(out, decoded);
+        Ok(())
+    }
+
+    /// Appends an octal or `\x` escape: a byte in bytes, a code point in str.
+    fn push_code(&self, out: &mut Vec<u8>, code: u32, bytes: bool) -> Step<()> {
+        if bytes {
+            out.push(u8::try_from(code).map_err(|_| Fail::Syntax)?);
+        } else {
+            push_char(out, char::from_u32(code).ok_or(Fail::Syntax)?);
+        }
+        Ok(())
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Step<u32> {
+        let digits = self.rest().get(..count).ok_or(Fail::Syntax)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Fail::Syntax);
+        }
+        self.pos += count;
+        u32::from_str_radix(digits, 16).map_err(|_| Fail::Syntax)
+    }
+}
+
+fn push_char(out: &mut Vec<u8>, c: char) {
+    let mut buf = [0; 4];
+    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::value::Source;
+
+    fn values(input: &str) -> Vec<serde_json::Value> {
+        PythonLiteralStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_dict_repr() {
+        let input = "{'name': 'Alice', 'active': True, 'tags': ('a', 'b'), 'score': None, \
+                     'ids': {1, 2}, 'nums': [0x1F, 1_000, -2.5, 1e3, .5, 3.], 1: ()}";
+        let candidates = PythonLiteralStrategy::new().parse(input).unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert!(matches!(candidates[0].source, Source::Python));
+        let value = &candidates[0].value;
+        assert_eq!(value["name"], "Alice");
+        assert_eq!(value["active"], true);
+        assert_eq!(value["tags"], json!(["a", "b"]));
+        assert!(value["score"].is_null());
+        assert_eq!(value["ids"], json!([1, 2]));
+        assert_eq!(value["nums"][0], 31);
+        assert_eq!(value["nums"][1], 1000);
+        assert_eq!(value["nums"][2].as_f64(), Some(-2.5));
+        assert_eq!(value["nums"][3].as_f64(), Some(1000.0));
+        assert_eq!(value["nums"][4].as_f64(), Some(0.5));
+        assert_eq!(value["nums"][5].as_f64(), Some(3.0));
+        assert_eq!(value["1"], json!([]));
+    }
+
+    #[test]
+    fn test_strings() {
+        let input = r#"['it\'s', "say \"hi\"", r'C:\new', b'\x41\xc3\xa9', u'\u00e9\N',
+                       '''multi
+line''', 'joined ' "text", '\101\t']"#;
+        assert_eq!(
+            values(input),
+            vec![json!([
+                "it's",
+                "say \"hi\"",
+                "C:\\new",
+                "Aé",
+                "é\\N",
+                "multi\nline",
+                "joined text",
+                "A\t",
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_calls_and_reprs() {
+        let input = "Order(id=7, total=Decimal('19.90'), rate=float('inf'), \
+                     items=[Item(sku='A1', qty=2)], tags=set(), meta=OrderedDict([('k', 'v')]), \
+                     extra=dict(a=1), status=<Status.PAID: 'paid'>)";
+        let value = &values(input)[0];
+
+        assert_eq!(value["id"], 7);
+        assert_eq!(value["total"].as_f64(), Some(19.9));
+        assert!(value["rate"].is_null());
+        assert_eq!(value["items"], json!([{"sku": "A1", "qty": 2}]));
+        assert_eq!(value["tags"], json!([]));
+        assert_eq!(value["meta"], json!({"k": "v"}));
+        assert_eq!(value["extra"], json!({"a": 1}));
+        assert_eq!(value["status"], "paid");
+    }
+
+    #[test]
+    fn test_printed_non_finite_numbers() {
+        let strategy = PythonLiteralStrategy::new().with_non_finite_policy(NonFinitePolicy::String);
+        let candidates = strategy
+            .parse("{'hi': inf, 'lo': -inf, 'avg': nan, 'max': math.inf, 'n': 1}")
+            .unwrap();
+        assert_eq!(
+            candidates[0].value,
+            json!({"hi": "Infinity", "lo": "-Infinity", "avg": "NaN", "max": "Infinity", "n": 1})
+        );
+        assert!(candidates[0].transformations().iter().any(|t| matches!(
+            t,
+            Transformation::JsonRepaired { fixes } if fixes == &[JsonFix::NonFiniteNumbers]
+        )));
+
+        let candidates = PythonLiteralStrategy::new()
+            .parse("[1, float('nan')]")
+            .unwrap();
+        assert_eq!(candidates[0].value, json!([1, null]));
+        assert_eq!(candidates[0].transformations().len(), 1);
+
+        let candidates = PythonLiteralStrategy::new().parse("{'x': (1, 2)}").unwrap();
+        assert!(candidates[0].transformations().is_empty());
+        assert!(values("{'x': infinity}").is_empty());
+    }
+
+    #[test]
+    fn test_prose_fences_and_comments() {
+        let input = "Here it is:\n```python\n{\n    'a': 1,  # first\n    'b': [2,],\n}\n```";
+        assert_eq!(values(input), vec![json!({"a": 1, "b": [2]})]);
+
+        let input = "I printed the result: User(name='Bo', admin=False) and that's it.";
+        assert_eq!(values(input), vec![json!({"name": "Bo", "admin": false})]);
+
+        let input = "It came from models.User(name='Bo') after dict(a=1) was built.";
+        assert_eq!(values(input), vec![json!({"name": "Bo"}), json!({"a": 1})]);
+    }
+
+    #[test]
+    fn test_calls_in_prose_are_not_literals() {
+        assert!(values("To stay on one line, call print(x, end='') instead.").is_empty());
+        assert!(values("Then call print(sep=', ', end='') to join them.").is_empty());
+        assert!(values("Set it with configure(debug=True, retries=3) first.").is_empty());
+    }
+
+    #[test]
+    fn test_policies() {
+        let strategy = PythonLiteralStrategy::new().with_non_finite_policy(NonFinitePolicy::String);
+        let value = &strategy.parse("[float('-inf'), Decimal('NaN')]").unwrap()[0].value;
+        assert_eq!(value, &json!(["-Infinity", "NaN"]));
+
+        let strategy = PythonLiteralStrategy::new().with_non_finite_policy(NonFinitePolicy::Error);
+        assert!(strategy.parse("[float('nan')]").is_err());
+        assert!(strategy.parse("{'x': nan}").is_err());
+
+        let strategy = PythonLiteralStrategy::new()
+            .with_duplicate_key_policy(DuplicateKeyPolicy::MergeIntoArray);
+        let value = &strategy.parse("{'a': 1, 'a': 2}").unwrap()[0].value;
+        assert_eq!(value, &json!({"a": [1, 2]}));
+    }
+
+    #[test]
+    fn test_not_python() {
+        assert!(values(r#"{"a": 1, "b": [true, null]}"#).is_empty());
+        assert!(values("{'a': true}").is_empty());
+        assert!(values("'just a string'").is_empty());
+        assert!(values("print(user)").is_empty());
+        assert!(values("{a: 1}").is_empty());
+        assert!(values("[1j]").is_empty());
+    }
+}
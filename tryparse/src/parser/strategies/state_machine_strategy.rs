@@ -4,7 +4,11 @@
 //! a token-by-token state machine instead of regex-based approaches.
 
 use super::ParsingStrategy;
-use crate::{error::Result, parser::state_machine::StateMachineParser, value::FlexValue};
+use crate::{
+    error::Result,
+    parser::{duplicate_keys::DuplicateKeyPolicy, state_machine::StateMachineParser},
+    value::FlexValue,
+};
 
 /// Strategy that uses the state machine parser for robust JSON parsing.
 ///
@@ -31,6 +35,12 @@ impl StateMachineStrategy {
             parser: StateMachineParser::new(),
         }
     }
+
+    /// Sets how repeated object keys are resolved.
+    pub fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.parser = self.parser.with_duplicate_key_policy(policy);
+        self
+    }
 }
 
 impl ParsingStrategy for StateMachineStrategy {
@@ -0,0 +1,218 @@
+//! TOML parsing strategy that converts TOML to JSON.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{Map, Number, Value as JsonValue};
+use toml::{Table, Value as TomlValue};
+
+use crate::{
+    error::Result,
+    parser::{
+        strategies::{Extractor, MarkdownExtractor, ParsingStrategy},
+        CandidateSource,
+    },
+    value::{FlexValue, Source},
+};
+
+/// Matches `key = value` lines, with bare, dotted or quoted keys.
+static KEY_VALUE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"^(?:[A-Za-z0-9_-]+|"[^"]*"|'[^']*')(?:\s*\.\s*(?:[A-Za-z0-9_-]+|"[^"]*"|'[^']*'))*\s*="#,
+    )
+    .unwrap()
+});
+
+/// Matches `[table]` and `[[array.of.tables]]` headers.
+static TABLE_HEADER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\[\[?\s*[A-Za-z0-9_."' -]+\s*\]\]?\s*(?:#.*)?$"#).unwrap());
+
+/// Strategy that parses TOML content and converts it to JSON.
+///
+/// This strategy:
+/// 1. Looks for ```` ```toml ```` code fences, or else TOML-like input
+///    (`key = value` lines and `[table]` headers)
+/// 2. Parses it with the `toml` crate
+/// 3. Converts tables to objects and arrays of tables to arrays of objects;
+///    datetimes become strings
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{ParsingStrategy, TomlStrategy};
+///
+/// let input = "name = \"api\"\n\n[[servers]]\nhost = \"a\"\n\n[[servers]]\nhost = \"b\"";
+/// let candidates = TomlStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["servers"][1]["host"], "b");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TomlStrategy {
+    /// Finds ```` ```toml ```` fences.
+    fences: MarkdownExtractor,
+}
+
+impl TomlStrategy {
+    /// Creates a new TOML strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the content of ```` ```toml ```` fences in `input`.
+    fn fenced_blocks(&self, input: &str) -> Vec<String> {
+        self.fences
+            .extract(input)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|c| {
+                matches!(
+                    &c.source,
+                    CandidateSource::Markdown { language: Some(lang) }
+                        if lang.eq_ignore_ascii_case("toml")
+                )
+            })
+            .map(|c| c.content)
+            .collect()
+    }
+
+    /// Checks if the input looks like TOML.
+    ///
+    /// Returns true if the input has at least two lines that are
+    /// `key = value` pairs or table headers, and no JSON-like braces at the
+    /// start.
+    fn looks_like_toml(input: &str) -> bool {
+        let trimmed = input.trim();
+
+        // Don't treat JSON objects as TOML; `[table]` headers are fine
+        if trimmed.starts_with('{') {
+            return false;
+        }
+
+        let toml_line_count = trimmed
+            .lines()
+            .map(str::trim)
+            .filter(|line| KEY_VALUE.is_match(line) || TABLE_HEADER.is_match(line))
+            .count();
+
+        toml_line_count >= 2
+    }
+
+    /// Converts a TOML value to [`serde_json::Value`].
+    ///
+    /// Tables keep their document order with the `preserve_order` feature.
+    fn toml_to_json(toml: TomlValue) -> Option<JsonValue> {
+        match toml {
+            TomlValue::String(s) => Some(JsonValue::String(s)),
+            TomlValue::Integer(i) => Some(JsonValue::Number(Number::from(i))),
+            TomlValue::Float(f) => Number::from_f64(f).map(JsonValue::Number),
+            TomlValue::Boolean(b) => Some(JsonValue::Bool(b)),
+            TomlValue::Datetime(dt) => Some(JsonValue::String(dt.to_string())),
+            TomlValue::Array(items) => Some(JsonValue::Array(
+                items.into_iter().filter_map(Self::toml_to_json).collect(),
+            )),
+            TomlValue::Table(table) => Some(Self::table_to_json(table)),
+        }
+    }
+
+    fn table_to_json(table: Table) -> JsonValue {
+        let map: Map<String, JsonValue> = table
+            .into_iter()
+            .filter_map(|(k, v)| Self::toml_to_json(v).map(|v| (k, v)))
+            .collect();
+        JsonValue::Object(map)
+    }
+}
+
+impl ParsingStrategy for TomlStrategy {
+    fn name(&self) -> &'static str {
+        "toml"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        let mut documents = self.fenced_blocks(input);
+        if documents.is_empty() && Self::looks_like_toml(input) {
+            documents.push(input.to_string());
+        }
+
+        Ok(documents
+            .iter()
+            .filter_map(|doc| doc.parse::<Table>().ok())
+            .map(|table| FlexValue::new(Self::table_to_json(table), Source::Toml))
+            .collect())
+    }
+
+    fn priority(&self) -> u8 {
+        // Same as YAML: after direct JSON (1) and markdown (2)
+        3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_looks_like_toml() {
+        assert!(TomlStrategy::looks_like_toml("name = \"Alice\"\nage = 30"));
+        assert!(TomlStrategy::looks_like_toml(
+            "[server]\nhost = \"localhost\""
+        ));
+        assert!(TomlStrategy::looks_like_toml(
+            "# config\nserver.port = 80\n\"quoted key\" = true"
+        ));
+
+        assert!(!TomlStrategy::looks_like_toml("{\"name\": \"Alice\"}"));
+        assert!(!TomlStrategy::looks_like_toml("[1, 2, 3]"));
+        assert!(!TomlStrategy::looks_like_toml("name: Alice\nage: 30"));
+        assert!(!TomlStrategy::looks_like_toml("name = \"Alice\"")); // Only 1 line
+    }
+
+    #[test]
+    fn test_tables_and_arrays_of_tables() {
+        let input = r#"
+title = "deploy"
+started = 2024-05-01T10:00:00Z
+
+[database]
+port = 5432
+ratio = 0.5
+tags = ["a", "b"]
+
+[[servers]]
+name = "alpha"
+
+[[servers]]
+name = "beta"
+"#;
+        let result = TomlStrategy::new().parse(input).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].value,
+            json!({
+                "title": "deploy",
+                "started": "2024-05-01T10:00:00Z",
+                "database": {"port": 5432, "ratio": 0.5, "tags": ["a", "b"]},
+                "servers": [{"name": "alpha"}, {"name": "beta"}],
+            })
+        );
+        assert!(matches!(result[0].source, Source::Toml));
+    }
+
+    #[test]
+    fn test_fenced_toml() {
+        let input = "Here is the config:\n\n```toml\n[app]\nname = \"x\"\n```\n\nDone.";
+        let result = TomlStrategy::new().parse(input).unwrap();
+
+        assert_eq!(result[0].value, json!({"app": {"name": "x"}}));
+    }
+
+    #[test]
+    fn test_invalid_toml() {
+        let result = TomlStrategy::new()
+            .parse("name = \"unterminated\nage = 30")
+            .unwrap();
+        assert!(result.is_empty());
+    }
+}
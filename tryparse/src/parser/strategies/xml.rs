@@ -0,0 +1,482 @@
+//! XML parsing strategy that converts XML-ish markup to JSON.
+//!
+//! Models prompted with XML examples often answer in kind:
+//! `<user><name>Alice</name><age>30</age></user>`. The markup is rarely
+//! well-formed, so [`XmlStrategy`] uses a tolerant parser instead of a strict
+//! XML one and turns the elements into an object the struct coercer can match.
+
+use serde_json::{Map, Value};
+
+use crate::{
+    error::Result,
+    parser::{strategies::ParsingStrategy, MAX_NESTING_DEPTH},
+    value::{FlexValue, Source},
+};
+
+/// Key holding an element's own text when it also has child elements or
+/// attributes.
+pub const XML_TEXT_KEY: &str = "#text";
+
+/// Strategy that parses XML-ish markup into a JSON object.
+///
+/// Conversion rules:
+/// - An element with only text becomes a string (`<age>30</age>` → `"30"`);
+///   the coercer turns it into a number or bool when the target wants one
+/// - Child elements and attributes become keys, namespace prefixes dropped
+/// - Repeated elements become an array
+/// - Text next to child elements or attributes goes under [`XML_TEXT_KEY`]
+///
+/// The parser tolerates unclosed tags, stray closing tags, unescaped `&` and
+/// `<`, and unquoted or valueless attributes. Comments, processing
+/// instructions and doctypes are skipped; CDATA is kept as text. An unclosed
+/// element with text before its children is read as a leaf missing its
+/// closing tag (`<name>Alice<age>30</age>` → `name` and `age` are siblings).
+///
+/// A single root element yields two candidates: its content
+/// (`{"name": "Alice", ...}`) and the content wrapped in the root name
+/// (`{"user": {...}}`). Several top-level elements yield one object. Input
+/// with text outside its elements (`<b>bold</b> text`) is prose, not data,
+/// and yields nothing; so does markup nested deeper than
+/// [`MAX_NESTING_DEPTH`].
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{ParsingStrategy, XmlStrategy};
+///
+/// let input = "<user><name>Alice</name><age>30</age></user>";
+/// let candidates = XmlStrategy::default().parse(input).unwrap();
+/// assert_eq!(candidates[0].value["name"], "Alice");
+/// assert_eq!(candidates[1].value["user"]["age"], "30");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct XmlStrategy;
+
+impl XmlStrategy {
+    /// Creates a new XML strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ParsingStrategy for XmlStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "xml"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        let trimmed = input.trim();
+        if !starts_markup(trimmed) {
+            return Ok(Vec::new());
+        }
+
+        let Some(document) = parse_elements(trimmed) else {
+            return Ok(Vec::new());
+        };
+        if !document.text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut elements = document.children;
+        let values = match elements.len() {
+            0 => Vec::new(),
+            1 => {
+                let root = elements.remove(0);
+                let content = to_json(&root);
+                let mut wrapped = Map::new();
+                wrapped.insert(root.name, content.clone());
+                vec![content, Value::Object(wrapped)]
+            }
+            _ => {
+                let mut map = Map::new();
+                for element in &elements {
+                    insert_repeated(&mut map, element.name.clone(), to_json(element));
+                }
+                vec![Value::Object(map)]
+            }
+        };
+
+        Ok(values
+            .into_iter()
+            .map(|v| FlexValue::new(v, Source::Xml))
+            .collect())
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        3 // After markdown: an XML document is parsed whole, like YAML
+    }
+}
+
+/// A parsed element.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+/// Parses `input` into a nameless document node holding the top-level
+/// elements and any text between them.
+///
+/// Returns `None` if elements nest deeper than [`MAX_NESTING_DEPTH`].
+fn parse_elements(input: &str) -> Option<Element> {
+    // The bottom of the stack is a nameless document node
+    let mut stack = vec![Element::default()];
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+        } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").unwrap_or(after.len());
+            top(&mut stack).text.push_str(&after[..end]);
+            rest = after.get(end + 3..).unwrap_or("");
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after.find('>').unwrap_or(after.len());
+            close(&mut stack, &local_name(after[..end].trim()));
+            rest = after.get(end + 1..).unwrap_or("");
+        } else if starts_tag(rest) {
+            let end = rest.find('>').unwrap_or(rest.len());
+            let (element, self_closing) = open_tag(&rest[1..end]);
+            rest = rest.get(end + 1..).unwrap_or("");
+
+            if self_closing {
+                top(&mut stack).children.push(element);
+            } else if stack.len() > MAX_NESTING_DEPTH {
+                return None;
+            } else {
+                stack.push(element);
+            }
+        } else {
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(i, _)| starts_markup(&rest[i..]))
+                .map_or(rest.len(), |(i, _)| i);
+            top(&mut stack)
+                .text
+                .push_str(&decode_entities(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    // Unclosed elements run to the end of the input
+    while stack.len() > 1 {
+        close_top(&mut stack, false);
+    }
+    stack.pop()
+}
+
+/// Parses the inside of an opening tag (`name attr="v"/`).
+///
+/// Returns the element and whether the tag closed itself.
+fn open_tag(inner: &str) -> (Element, bool) {
+    let inner = inner.trim();
+    let (inner, self_closing) = match inner.strip_suffix('/') {
+        Some(inner) => (inner, true),
+        None => (inner, false),
+    };
+
+    let name_end = inner
+        .find(|c: char| c.is_whitespace())
+        .unwrap_or(inner.len());
+    let mut element = Element {
+        name: local_name(&inner[..name_end]),
+        ..Element::default()
+    };
+
+    let mut rest = inner[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+
+        let value = match rest.strip_prefix('=').map(str::trim_start) {
+            Some(after) => match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    rest = body.get(end + 1..).unwrap_or("");
+                    &body[..end]
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    rest = &after[end..];
+                    &after[..end]
+                }
+            },
+            // Valueless attribute, as in HTML
+            None => "true",
+        };
+
+        if !key.is_empty() && key != "xmlns" && !key.starts_with("xmlns:") {
+            element
+                .attributes
+                .push((local_name(key), decode_entities(value)));
+        }
+        rest = rest.trim_start();
+    }
+
+    (element, self_closing)
+}
+
+/// Closes the innermost open element named `name` and everything opened
+/// inside it. A closing tag that was never opened is ignored.
+fn close(stack: &mut Vec<Element>, name: &str) {
+    if let Some(pos) = stack
+        .iter()
+        .rposition(|e| e.name.eq_ignore_ascii_case(name))
+    {
+        if pos > 0 {
+            while stack.len() > pos + 1 {
+                close_top(stack, false);
+            }
+            close_top(stack, true);
+        }
+    }
+}
+
+/// Pops the innermost open element into its parent.
+///
+/// An element that was never closed itself but has text before child
+/// elements was most likely a leaf missing its closing tag
+/// (`<name>Alice<age>30</age>`), so its children become its siblings.
+fn close_top(stack: &mut Vec<Element>, explicit: bool) {
+    let Some(mut element) = stack.pop() else {
+        return;
+    };
+    let orphans = if !explicit && !element.text.trim().is_empty() {
+        std::mem::take(&mut element.children)
+    } else {
+        Vec::new()
+    };
+
+    let parent = top(stack);
+    parent.children.push(element);
+    parent.children.extend(orphans);
+}
+
+fn top(stack: &mut [Element]) -> &mut Element {
+    stack.last_mut().expect("document node is never popped")
+}
+
+/// Converts an element's content to JSON.
+fn to_json(element: &Element) -> Value {
+    let text = element.text.trim();
+    if element.attributes.is_empty() && element.children.is_empty() {
+        return Value::String(text.to_string());
+    }
+
+    let mut map = Map::new();
+    for (key, value) in &element.attributes {
+        insert_repeated(&mut map, key.clone(), Value::String(value.clone()));
+    }
+    for child in &element.children {
+        insert_repeated(&mut map, child.name.clone(), to_json(child));
+    }
+    if !text.is_empty() {
+        insert_repeated(
+            &mut map,
+            XML_TEXT_KEY.to_string(),
+            Value::String(text.to_string()),
+        );
+    }
+    Value::Object(map)
+}
+
+/// Inserts `value` under `key`, turning the entry into an array if `key`
+/// repeats.
+///
+/// Element content is never an array itself, so an existing array always
+/// comes from an earlier repeat.
+fn insert_repeated(map: &mut Map<String, Value>, key: String, value: Value) {
+    match map.get_mut(&key) {
+        Some(Value::Array(items)) => items.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+        None => {
+            map.insert(key, value);
+        }
+    }
+}
+
+/// Drops a namespace prefix (`ns:user` → `user`).
+fn local_name(name: &str) -> String {
+    name.rsplit(':').next().unwrap_or(name).to_string()
+}
+
+/// Returns true if `s` starts with an opening tag.
+fn starts_tag(s: &str) -> bool {
+    s.strip_prefix('<')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+}
+
+/// Returns true if `s` starts with a tag, comment, declaration or CDATA.
+fn starts_markup(s: &str) -> bool {
+    starts_tag(s) || s.starts_with("</") || s.starts_with("<!") || s.starts_with("<?")
+}
+
+/// Decodes the predefined and numeric entities, leaving anything else
+/// (like a bare `&`) as written.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.get(1..).and_then(|after| {
+            let end = after.find(';').filter(|&end| end <= 10)?;
+            let entity = &after[..end];
+            let c = match entity {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                _ => {
+                    let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 2))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn values(input: &str) -> Vec<Value> {
+        XmlStrategy::new()
+            .parse(input)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_single_root_yields_content_and_wrapped() {
+        let input = "<user><name>Alice</name><age>30</age></user>";
+        assert_eq!(
+            values(input),
+            vec![
+                json!({"name": "Alice", "age": "30"}),
+                json!({"user": {"name": "Alice", "age": "30"}}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repeated_elements_become_arrays() {
+        let input = "<order><item>a</item><item>b</item><item>c</item><id>7</id></order>";
+        assert_eq!(
+            values(input)[0],
+            json!({"item": ["a", "b", "c"], "id": "7"})
+        );
+    }
+
+    #[test]
+    fn test_attributes_and_mixed_text() {
+        let input = r#"<item id="1" kind='tool' used>Hammer<note>heavy</note></item>"#;
+        assert_eq!(
+            values(input)[0],
+            json!({"id": "1", "kind": "tool", "used": "true", "note": "heavy", "#text": "Hammer"})
+        );
+
+        let input = "<list><x n=2/><x n=3 /></list>";
+        assert_eq!(values(input)[0], json!({"x": [{"n": "2"}, {"n": "3"}]}));
+    }
+
+    #[test]
+    fn test_unclosed_tags() {
+        // Leaf followed by a sibling, and the root never closed
+        let input = "<user><name>Alice<age>30</age>";
+        assert_eq!(values(input)[0], json!({"name": "Alice", "age": "30"}));
+
+        // Closing the parent closes the unclosed child
+        let input = "<user><address><city>Paris</user>";
+        assert_eq!(values(input)[0], json!({"address": {"city": "Paris"}}));
+    }
+
+    #[test]
+    fn test_entities_and_bare_ampersands() {
+        let input =
+            "<company><name>Smith & Sons</name><motto>1 &lt; 2 &amp;&#32;&#x41;</motto></company>";
+        assert_eq!(
+            values(input)[0],
+            json!({"name": "Smith & Sons", "motto": "1 < 2 & A"})
+        );
+    }
+
+    #[test]
+    fn test_skips_declarations_and_keeps_cdata() {
+        let input = "<?xml version=\"1.0\"?>\n<!-- user -->\n<ns:user xmlns:ns=\"x\">\
+                     <ns:bio><![CDATA[<b>bold</b>]]></ns:bio></ns:user>";
+        assert_eq!(values(input)[1], json!({"user": {"bio": "<b>bold</b>"}}));
+    }
+
+    #[test]
+    fn test_multiple_roots_become_one_object() {
+        let input = "<name>Alice</name>\n<age>30</age>";
+        assert_eq!(values(input), vec![json!({"name": "Alice", "age": "30"})]);
+    }
+
+    #[test]
+    fn test_text_outside_elements_is_not_xml() {
+        assert!(values("<b>bold</b> text").is_empty());
+        assert!(values("<name>Alice</name> and <age>30</age>").is_empty());
+        assert_eq!(values("\n<name>Alice</name>\n\n<age>30</age>\n").len(), 1);
+    }
+
+    #[test]
+    fn test_deep_nesting_is_not_a_candidate() {
+        assert!(values(&"<a>".repeat(5000)).is_empty());
+        assert_eq!(
+            values(&format!("{}x", "<a>".repeat(MAX_NESTING_DEPTH))).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_not_markup() {
+        assert!(values("{\"a\": 1}").is_empty());
+        assert!(values("a < b and c > d").is_empty());
+        assert!(values("< not a tag >").is_empty());
+    }
+}
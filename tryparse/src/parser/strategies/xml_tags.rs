@@ -0,0 +1,364 @@
+//! XML-style tag extraction strategy.
+//!
+//! Prompts often ask for the answer inside `<answer>...</answer>` or
+//! `<json>...</json>` instead of a code fence, since fences collide with code
+//! in the answer itself. [`XmlTagExtractor`] finds the tagged content and
+//! [`XmlTagStrategy`] cleans, repairs and parses it.
+
+use std::cmp::Reverse;
+
+use regex::Regex;
+
+use super::{Extractor, JsonFixerStrategy, NonFinitePolicy, ParsingStrategy};
+use crate::{
+    error::Result,
+    parser::{
+        duplicate_keys::{parse_candidate, DuplicateKeyPolicy},
+        Candidate, CandidateSource, Cleaner, GarbageCleaner,
+    },
+    value::{FlexValue, Source},
+};
+
+/// Tag names looked for by default.
+pub const DEFAULT_TAGS: &[&str] = &["answer", "json", "output"];
+
+/// Extractor for content wrapped in XML-style tags like `<answer>...</answer>`.
+///
+/// Tag names match case-insensitively and may carry attributes
+/// (`<answer format="json">`). When tags nest, only the innermost content is
+/// extracted. A tag that is never closed runs to the end of the input, and a
+/// closing tag also closes any unclosed tags opened inside it.
+///
+/// Candidates come out in preference order: the preferred tag first (see
+/// [`Self::prefer_tag`]), then the rest, each from last to first, because
+/// models put the final answer after their reasoning.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{Extractor, XmlTagExtractor};
+///
+/// let input = "<json>{\"draft\": 1}</json> Let me fix that. <json>{\"final\": 2}</json>";
+/// let candidates = XmlTagExtractor::default().extract(input).unwrap();
+/// assert_eq!(candidates[0].content, r#"{"final": 2}"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct XmlTagExtractor {
+    /// Lowercased tag names to look for.
+    tags: Vec<String>,
+    /// Lowercased tag whose content comes first.
+    preferred: Option<String>,
+    /// Matches opening and closing tags with one of the names.
+    tag_regex: Regex,
+}
+
+impl Default for XmlTagExtractor {
+    fn default() -> Self {
+        Self::new(DEFAULT_TAGS.iter().copied())
+    }
+}
+
+impl XmlTagExtractor {
+    /// Creates an extractor for the given tag names.
+    pub fn new<I, S>(tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let tags: Vec<String> = tags
+            .into_iter()
+            .map(|t| t.as_ref().to_lowercase())
+            .collect();
+        let names = tags
+            .iter()
+            .map(|t| regex::escape(t))
+            .collect::<Vec<_>>()
+            .join("|");
+        // Captures: (closing slash, tag name)
+        let tag_regex = Regex::new(&format!(r"(?i)<(/?)({})(?:\s[^>]*)?>", names)).unwrap();
+
+        Self {
+            tags,
+            preferred: None,
+            tag_regex,
+        }
+    }
+
+    /// Puts the content of `tag` ahead of every other tag.
+    pub fn prefer_tag(mut self, tag: impl AsRef<str>) -> Self {
+        self.preferred = Some(tag.as_ref().to_lowercase());
+        self
+    }
+
+    /// Returns the tag names this extractor looks for.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Finds the innermost tagged regions, in preference order.
+    ///
+    /// Returns `(tag, content start, content end)` byte offsets.
+    fn regions(&self, input: &str) -> Vec<(String, usize, usize)> {
+        if self.tags.is_empty() {
+            return Vec::new();
+        }
+
+        let mut open: Vec<(String, usize)> = Vec::new();
+        let mut regions = Vec::new();
+
+        for cap in self.tag_regex.captures_iter(input) {
+            let tag = cap.get(0).unwrap();
+            let name = cap[2].to_lowercase();
+            if cap[1].is_empty() {
+                open.push((name, tag.end()));
+            } else if let Some(pos) = open.iter().rposition(|(n, _)| *n == name) {
+                // Unclosed tags opened inside this one end here too
+                for (name, start) in open.drain(pos..) {
+                    regions.push((name, start, tag.start()));
+                }
+            }
+            // A closing tag that was never opened is ignored
+        }
+
+        // Unclosed tags run to the end of the input
+        for (name, start) in open {
+            regions.push((name, start, input.len()));
+        }
+
+        // Keep only the innermost regions
+        let mut innermost: Vec<_> = regions
+            .iter()
+            .filter(|(_, start, end)| {
+                !regions
+                    .iter()
+                    .any(|(_, s, e)| (s, e) != (start, end) && start <= s && e <= end)
+            })
+            .cloned()
+            .collect();
+
+        innermost.sort_by_key(|(name, start, _)| {
+            (
+                self.preferred.as_deref() != Some(name.as_str()),
+                Reverse(*start),
+            )
+        });
+        innermost
+    }
+}
+
+impl Extractor for XmlTagExtractor {
+    fn name(&self) -> &'static str {
+        "xml_tag"
+    }
+
+    fn extract(&self, input: &str) -> Result<Vec<Candidate>> {
+        Ok(self
+            .regions(input)
+            .into_iter()
+            .filter_map(|(tag, start, end)| {
+                let content = input[start..end].trim();
+                (!content.is_empty()).then(|| Candidate::tagged(content, tag))
+            })
+            .collect())
+    }
+
+    fn priority(&self) -> u8 {
+        2 // Same as markdown
+    }
+}
+
+/// Strategy that parses content wrapped in XML-style tags.
+///
+/// Content found by its [`XmlTagExtractor`] is cleaned, then parsed directly
+/// or repaired by [`JsonFixerStrategy`]. Candidates get [`Source::Tagged`],
+/// which ranks above markdown code blocks and loose JSON found in prose.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::parser::strategies::{ParsingStrategy, XmlTagStrategy};
+///
+/// let input = "<thinking>Maybe {\"a\": 1}?</thinking>\n<answer>{a: 2}</answer>";
+/// let result = XmlTagStrategy::default().parse(input).unwrap();
+/// assert_eq!(result[0].value["a"], 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct XmlTagStrategy {
+    /// Finds the tagged content.
+    extractor: XmlTagExtractor,
+    /// How repeated object keys are resolved.
+    duplicate_keys: DuplicateKeyPolicy,
+    /// Whether repair may quote bare `NaN` and `Infinity` values.
+    non_finite: NonFinitePolicy,
+}
+
+impl XmlTagStrategy {
+    /// Creates a strategy that parses what `extractor` finds.
+    pub fn new(extractor: XmlTagExtractor) -> Self {
+        Self {
+            extractor,
+            duplicate_keys: DuplicateKeyPolicy::default(),
+            non_finite: NonFinitePolicy::default(),
+        }
+    }
+
+    /// Sets how repeated object keys are resolved.
+    pub fn with_duplicate_key_policy(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+
+    /// Sets how bare `NaN` and `Infinity` values are treated when a block is
+    /// repaired; see [`JsonFixerStrategy::with_non_finite_policy`].
+    pub fn with_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+}
+
+impl ParsingStrategy for XmlTagStrategy {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "xml_tag"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<FlexValue>> {
+        let cleaner = GarbageCleaner::new();
+        let fixer = JsonFixerStrategy::default()
+            .with_duplicate_key_policy(self.duplicate_keys)
+            .with_non_finite_policy(self.non_finite);
+        let mut values = Vec::new();
+        // A tag that repeats a key under DuplicateKeyPolicy::Error is skipped
+        let mut policy_error = None;
+
+        for candidate in self.extractor.extract(input)? {
+            let candidate = cleaner.clean(&candidate)?.unwrap_or(candidate);
+            let CandidateSource::Tagged { tag } = &candidate.source else {
+                continue;
+            };
+
+            let source = candidate.to_source();
+            let fixed = match parse_candidate(&candidate.content, self.duplicate_keys, |v| {
+                FlexValue::new(v, source)
+            }) {
+                Ok(Some(value)) => {
+                    values.push(value);
+                    continue;
+                }
+                Ok(None) => fixer.parse(&candidate.content),
+                Err(e) => Err(e),
+            };
+            let fixed = match fixed {
+                Ok(fixed) => fixed,
+                Err(e) => {
+                    policy_error.get_or_insert(e);
+                    continue;
+                }
+            };
+
+            // Keep the repairs on the tagged source
+            for mut fixed in fixed {
+                if let Source::Fixed { fixes } = fixed.source {
+                    fixed.source = Source::Tagged {
+                        tag: tag.clone(),
+                        fixes,
+                    };
+                }
+                values.push(fixed);
+            }
+        }
+
+        match policy_error {
+            Some(e) if values.is_empty() => Err(e),
+            _ => Ok(values),
+        }
+    }
+
+    #[inline]
+    fn priority(&self) -> u8 {
+        2 // Same as markdown: after direct JSON, before repair of the whole input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn contents(extractor: &XmlTagExtractor, input: &str) -> Vec<String> {
+        extractor
+            .extract(input)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.content)
+            .collect()
+    }
+
+    #[test]
+    fn test_extracts_last_tag_first() {
+        let input = "<answer>1</answer> wait <ANSWER format=\"json\">2</ANSWER>";
+        assert_eq!(contents(&XmlTagExtractor::default(), input), vec!["2", "1"]);
+    }
+
+    #[test]
+    fn test_preferred_tag_comes_first() {
+        let input = "<answer>1</answer> <output>2</output>";
+        let extractor = XmlTagExtractor::default().prefer_tag("answer");
+        assert_eq!(contents(&extractor, input), vec!["1", "2"]);
+    }
+
+    #[test]
+    fn test_nested_tags_yield_innermost() {
+        let input = "<answer>Here: <json>{\"a\": 1}</json> done</answer>";
+        assert_eq!(
+            contents(&XmlTagExtractor::default(), input),
+            vec![r#"{"a": 1}"#]
+        );
+
+        // Same name nested
+        let input = "<output>x <output>{\"a\": 1}</output> y</output>";
+        assert_eq!(
+            contents(&XmlTagExtractor::default(), input),
+            vec![r#"{"a": 1}"#]
+        );
+    }
+
+    #[test]
+    fn test_unclosed_tags() {
+        let input = "<answer>{\"a\": 1}";
+        assert_eq!(
+            contents(&XmlTagExtractor::default(), input),
+            vec![r#"{"a": 1}"#]
+        );
+
+        // The closing answer tag also ends the unclosed json tag
+        let input = "<answer><json>{\"a\": 1}</answer>";
+        assert_eq!(
+            contents(&XmlTagExtractor::default(), input),
+            vec![r#"{"a": 1}"#]
+        );
+    }
+
+    #[test]
+    fn test_custom_tags_only() {
+        let input = "<answer>1</answer><result>2</result>";
+        assert_eq!(
+            contents(&XmlTagExtractor::new(["result"]), input),
+            vec!["2"]
+        );
+        assert!(contents(&XmlTagExtractor::new(["answers"]), input).is_empty());
+    }
+
+    #[test]
+    fn test_strategy_repairs_and_tags_source() {
+        let input = "<thinking>{\"draft\": true}</thinking><json>{'name': 'Alice',}</json>";
+        let result = XmlTagStrategy::default().parse(input).unwrap();
+
+        assert_eq!(result[0].value, json!({"name": "Alice"}));
+        assert!(matches!(
+            &result[0].source,
+            Source::Tagged { tag, fixes } if tag == "json" && !fixes.is_empty()
+        ));
+    }
+}
@@ -36,6 +36,8 @@ impl YamlStrategy {
     }
 
     /// Converts a saphyr Yaml value to [`serde_json::Value`].
+    ///
+    /// Mappings keep their document order with the `preserve_order` feature.
     fn yaml_to_json(yaml: &Yaml) -> Option<JsonValue> {
         match yaml {
             Yaml::Value(scalar) => match scalar {
@@ -1,6 +1,9 @@
 //! Scoring system for ranking parsing candidates.
 
-use crate::value::{FlexValue, Source};
+use crate::{
+    deserializer::CoercionPolicy,
+    value::{FlexValue, Source, Transformation},
+};
 
 /// Multiplier applied per depth level for recursive scoring.
 /// Transformations at depth N are penalized by (DEPTH_SCORE_MULTIPLIER ^ N).
@@ -38,14 +41,41 @@ pub fn score_candidate(candidate: &FlexValue) -> u32 {
 /// When `use_recursive` is true, applies BAML's recursive scoring algorithm
 /// where transformations are multiplied by 10^depth.
 pub fn score_candidate_recursive(candidate: &FlexValue, use_recursive: bool) -> u32 {
+    score_with(candidate, use_recursive, Transformation::penalty)
+}
+
+/// Scores a candidate like [`score_candidate`], but with the penalty of each
+/// transformation taken from `policy`.
+///
+/// # Examples
+///
+/// ```
+/// use tryparse::deserializer::CoercionPolicy;
+/// use tryparse::scoring::{score_candidate, score_candidate_with_policy};
+/// use tryparse::value::{FlexValue, Source, Transformation, TransformationKind};
+/// use serde_json::json;
+///
+/// let mut value = FlexValue::new(json!(42), Source::Direct);
+/// value.add_transformation(Transformation::StringToNumber {
+///     original: "42".to_string(),
+/// });
+///
+/// let policy = CoercionPolicy::new().penalize(TransformationKind::StringToNumber, 100);
+/// assert_eq!(score_candidate_with_policy(&value, &policy), score_candidate(&value) + 98);
+/// ```
+pub fn score_candidate_with_policy(candidate: &FlexValue, policy: &CoercionPolicy) -> u32 {
+    score_with(candidate, false, |t| policy.penalty(t))
+}
+
+fn score_with(
+    candidate: &FlexValue,
+    use_recursive: bool,
+    penalty: impl Fn(&Transformation) -> u32,
+) -> u32 {
     let mut score = source_base_score(&candidate.source);
 
     // Add transformation penalties
-    let transformation_score: u32 = candidate
-        .transformations()
-        .iter()
-        .map(|t| t.penalty())
-        .sum();
+    let transformation_score: u32 = candidate.transformations().iter().map(penalty).sum();
 
     if use_recursive && candidate.max_transformation_depth() > 0 {
         // Apply recursive multiplier: DEPTH_SCORE_MULTIPLIER per depth level
@@ -71,8 +101,15 @@ pub fn score_candidate_recursive(candidate: &FlexValue, use_recursive: bool) ->
 fn source_base_score(source: &Source) -> u32 {
     match source {
         Source::Direct => 0,
+        // The prompt asked for this tag, so it beats code fences and prose
+        Source::Tagged { fixes, .. } => 5 + fixes.iter().map(|f| f.penalty()).sum::<u32>(),
         Source::Markdown { .. } => 10,
-        Source::Yaml => 15,
+        Source::Yaml
+        | Source::Xml
+        | Source::Toml
+        | Source::MarkdownTable
+        | Source::Csv
+        | Source::Python => 15,
         Source::Fixed { fixes } => {
             // Sum the penalty of each fix type (different fixes have different reliability)
             20 + fixes.iter().map(|f| f.penalty()).sum::<u32>()
@@ -82,6 +119,8 @@ fn source_base_score(source: &Source) -> u32 {
         // - For T: Both work, but MultiJson picks first which is common pattern
         Source::MultiJsonArray => 25, // Lower score = higher priority
         Source::MultiJson { .. } => 30,
+        // Structure guessed from prose, so any real JSON should win
+        Source::KeyValue | Source::Sections => 40,
         Source::Heuristic { .. } => 50,
     }
 }
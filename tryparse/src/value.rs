@@ -33,9 +33,36 @@ pub struct FlexValue {
 // Implement Hash and Eq based on the value only (for circular detection)
 impl Hash for FlexValue {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // Hash the JSON value by converting to string
-        // This is not perfect but works for circular detection
-        self.value.to_string().hash(state);
+        hash_value(&self.value, state);
+    }
+}
+
+/// Hashes a JSON value consistently with its `PartialEq`.
+///
+/// Object equality ignores key order, which is insertion order with the
+/// `preserve_order` feature, so entries are hashed in sorted key order.
+fn hash_value<H: Hasher>(value: &Value, state: &mut H) {
+    std::mem::discriminant(value).hash(state);
+    match value {
+        Value::Null => {}
+        Value::Bool(b) => b.hash(state),
+        Value::Number(n) => n.to_string().hash(state),
+        Value::String(s) => s.hash(state),
+        Value::Array(items) => {
+            items.len().hash(state);
+            for item in items {
+                hash_value(item, state);
+            }
+        }
+        Value::Object(obj) => {
+            let mut entries: Vec<_> = obj.iter().collect();
+            entries.sort_unstable_by_key(|(k, _)| *k);
+            entries.len().hash(state);
+            for (k, v) in entries {
+                k.hash(state);
+                hash_value(v, state);
+            }
+        }
     }
 }
 
@@ -171,6 +198,18 @@ impl FlexValue {
                 "pattern": pattern,
             }),
             Source::Yaml => json!({"type": "yaml"}),
+            Source::Xml => json!({"type": "xml"}),
+            Source::Toml => json!({"type": "toml"}),
+            Source::MarkdownTable => json!({"type": "markdown_table"}),
+            Source::Csv => json!({"type": "csv"}),
+            Source::Python => json!({"type": "python"}),
+            Source::KeyValue => json!({"type": "key_value"}),
+            Source::Sections => json!({"type": "sections"}),
+            Source::Tagged { tag, fixes } => json!({
+                "type": "tagged",
+                "tag": tag,
+                "fixes": fixes.iter().map(|f| f.description()).collect::<Vec<_>>(),
+            }),
         };
 
         // Convert transformations to JSON
@@ -254,6 +293,19 @@ fn transformation_to_json(t: &Transformation) -> Value {
             "error": error,
             "penalty": t.penalty(),
         }),
+        Transformation::MapEntryParseError {
+            key,
+            error,
+            raw,
+            kept_raw,
+        } => json!({
+            "type": "map_entry_parse_error",
+            "key": key,
+            "error": error,
+            "raw": raw,
+            "kept_raw": kept_raw,
+            "penalty": t.penalty(),
+        }),
         Transformation::JsonToString { original } => json!({
             "type": "json_to_string",
             "original": original,
@@ -299,6 +351,108 @@ fn transformation_to_json(t: &Transformation) -> Value {
             "candidates": candidates,
             "penalty": t.penalty(),
         }),
+        Transformation::StringNormalized {
+            original,
+            normalized,
+        } => json!({
+            "type": "string_normalized",
+            "original": original,
+            "normalized": normalized,
+            "penalty": t.penalty(),
+        }),
+        Transformation::DateTimeParsed { original, format } => json!({
+            "type": "date_time_parsed",
+            "original": original,
+            "format": format,
+            "penalty": t.penalty(),
+        }),
+        Transformation::DurationParsed { original, format } => json!({
+            "type": "duration_parsed",
+            "original": original,
+            "format": format,
+            "penalty": t.penalty(),
+        }),
+        Transformation::ApproximateValue { original, marker } => json!({
+            "type": "approximate_value",
+            "original": original,
+            "marker": marker,
+            "penalty": t.penalty(),
+        }),
+        Transformation::AmbiguousNumberFormat { original, assumed } => json!({
+            "type": "ambiguous_number_format",
+            "original": original,
+            "assumed": assumed,
+            "penalty": t.penalty(),
+        }),
+        Transformation::MagnitudeSuffix {
+            original,
+            multiplier,
+        } => json!({
+            "type": "magnitude_suffix",
+            "original": original,
+            "multiplier": multiplier,
+            "penalty": t.penalty(),
+        }),
+        Transformation::NumberWords { original } => json!({
+            "type": "number_words",
+            "original": original,
+            "penalty": t.penalty(),
+        }),
+        Transformation::RangeResolved {
+            original,
+            low,
+            high,
+            policy,
+        } => json!({
+            "type": "range_resolved",
+            "original": original,
+            "low": low,
+            "high": high,
+            "policy": policy,
+            "penalty": t.penalty(),
+        }),
+        Transformation::NullSentinel { original } => json!({
+            "type": "null_sentinel",
+            "original": original,
+            "penalty": t.penalty(),
+        }),
+        Transformation::SplitToArray {
+            original,
+            separator,
+        } => json!({
+            "type": "split_to_array",
+            "original": original,
+            "separator": separator,
+            "penalty": t.penalty(),
+        }),
+        Transformation::ArrayToMap { shape } => json!({
+            "type": "array_to_map",
+            "shape": shape,
+            "penalty": t.penalty(),
+        }),
+        Transformation::DuplicateKey { path, policy } => json!({
+            "type": "duplicate_key",
+            "path": path,
+            "policy": policy,
+            "penalty": t.penalty(),
+        }),
+        Transformation::UndefinedRemoved { path } => json!({
+            "type": "undefined_removed",
+            "path": path,
+            "penalty": t.penalty(),
+        }),
+        Transformation::DateToString { path, original } => json!({
+            "type": "date_to_string",
+            "path": path,
+            "original": original,
+            "penalty": t.penalty(),
+        }),
+        Transformation::ExpressionDropped { path, expression } => json!({
+            "type": "expression_dropped",
+            "path": path,
+            "expression": expression,
+            "penalty": t.penalty(),
+        }),
         Transformation::FirstMatch { index, total } => json!({
             "type": "first_match",
             "index": index,
@@ -343,6 +497,35 @@ pub enum Source {
 
     /// Parsed from YAML and converted to JSON.
     Yaml,
+
+    /// Parsed from XML-ish markup and converted to JSON.
+    Xml,
+
+    /// Parsed from TOML and converted to JSON.
+    Toml,
+
+    /// Converted from a markdown pipe table to an array of objects.
+    MarkdownTable,
+
+    /// Converted from CSV or TSV rows to an array of objects.
+    Csv,
+
+    /// Parsed from Python literal syntax, like a `repr()` of a dict.
+    Python,
+
+    /// Built from `key: value` lines in prose.
+    KeyValue,
+
+    /// Built from markdown heading sections.
+    Sections,
+
+    /// Extracted from an XML-style tag like `<answer>...</answer>`.
+    Tagged {
+        /// The tag name, lowercased.
+        tag: String,
+        /// Fixes applied to the tagged content, if it needed repair.
+        fixes: Vec<JsonFix>,
+    },
 }
 
 /// Types of JSON fixes that can be applied.
@@ -378,6 +561,20 @@ pub enum JsonFix {
     TripleQuotedStrings,
     /// Added quotes around unquoted values.
     UnquotedValues,
+    /// Removed a leading `+` from numbers (JSON5).
+    PlusSigns,
+    /// Added a zero before or after a bare decimal point, as in `.5` or `5.` (JSON5).
+    BareDecimalPoints,
+    /// Replaced `Infinity` or `NaN`, which JSON can't represent (JSON5).
+    NonFiniteNumbers,
+    /// Joined strings continued with a `\` at the end of a line (JSON5).
+    LineContinuations,
+    /// Decoded `\v`, `\0`, `\xHH` or identity escapes in strings (JSON5).
+    ExtendedEscapes,
+    /// Removed a JavaScript wrapper like `const result = ...;` or `export default`.
+    JavaScriptWrappers,
+    /// Converted regex literals like `/ab+c/i` to strings.
+    RegexLiterals,
 }
 
 impl JsonFix {
@@ -394,11 +591,18 @@ impl JsonFix {
             Self::TemplateLiterals => 1,
             Self::UnescapedNewlines => 1,
             Self::JavaScriptFunctions => 1,
+            Self::PlusSigns => 1,
+            Self::BareDecimalPoints => 1,
+            Self::LineContinuations => 1,
+            Self::ExtendedEscapes => 1,
+            Self::JavaScriptWrappers => 1,
 
             // Medium-risk fixes (usually correct)
             Self::SingleQuotes => 2,
             Self::UnquotedKeys => 2,
             Self::HexNumbers => 2,
+            Self::NonFiniteNumbers => 2, // Infinity and NaN lose their value
+            Self::RegexLiterals => 2,
             Self::TripleQuotedStrings => 2,
             Self::MissingCommas => 3,
             Self::UnclosedBraces => 3,
@@ -427,6 +631,13 @@ impl JsonFix {
             Self::JavaScriptFunctions => "removed JavaScript function definitions",
             Self::TripleQuotedStrings => "converted triple-quoted strings to regular quotes",
             Self::UnquotedValues => "added quotes around unquoted values",
+            Self::PlusSigns => "removed leading plus signs from numbers",
+            Self::BareDecimalPoints => "added zeros around bare decimal points",
+            Self::NonFiniteNumbers => "replaced Infinity and NaN",
+            Self::LineContinuations => "joined line-continued strings",
+            Self::ExtendedEscapes => "decoded JSON5 string escapes",
+            Self::JavaScriptWrappers => "removed JavaScript variable or export wrappers",
+            Self::RegexLiterals => "converted regex literals to strings",
         }
     }
 }
@@ -504,6 +715,20 @@ pub enum Transformation {
         error: String,
     },
 
+    /// A map entry's key or value didn't coerce.
+    ///
+    /// See [`MapEntryPolicy`](crate::deserializer::MapEntryPolicy).
+    MapEntryParseError {
+        /// The entry's key.
+        key: String,
+        /// Error message describing the failure.
+        error: String,
+        /// The entry's value as JSON text.
+        raw: String,
+        /// True if the value was kept as its raw text, false if the entry was dropped.
+        kept_raw: bool,
+    },
+
     /// Object/Array was converted to a string representation.
     ///
     /// This transformation indicates that a composite type (object or array)
@@ -571,6 +796,141 @@ pub enum Transformation {
         candidates: Vec<String>,
     },
 
+    /// String was normalized into the canonical form of the target type.
+    ///
+    /// Used for lenient parsing of formatted values, e.g. a braced UUID
+    /// or a URL that was missing its scheme.
+    StringNormalized {
+        /// The original string.
+        original: String,
+        /// The normalized string that was parsed.
+        normalized: String,
+    },
+
+    /// Date or time was parsed from a non-canonical format.
+    ///
+    /// Canonical ISO 8601 input doesn't record this.
+    DateTimeParsed {
+        /// The original value as text.
+        original: String,
+        /// Name of the format that matched, e.g. `"MM/DD/YYYY"` or `"relative (yesterday)"`.
+        format: String,
+    },
+
+    /// Duration was parsed from text or a bare number.
+    ///
+    /// ISO 8601 durations without calendar units don't record this.
+    DurationParsed {
+        /// The original value as text.
+        original: String,
+        /// Name of the format that matched, e.g. `"compact (1h30m)"` or `"number (seconds)"`.
+        format: String,
+    },
+
+    /// An approximate marker ("about", "~", "or so") was dropped from the value.
+    ApproximateValue {
+        /// The original value as text.
+        original: String,
+        /// The marker that was removed.
+        marker: String,
+    },
+
+    /// A number's separators could be read two ways and one was assumed.
+    ///
+    /// "1,234" is one thousand two hundred thirty-four in English but
+    /// one point two three four in German.
+    AmbiguousNumberFormat {
+        /// The original string.
+        original: String,
+        /// The reading that was used, e.g. `"',' as thousands separator"`.
+        assumed: String,
+    },
+
+    /// A magnitude suffix was expanded ("1.2k" → 1200, "3 million" → 3000000).
+    MagnitudeSuffix {
+        /// The original string.
+        original: String,
+        /// The multiplier the suffix stood for.
+        multiplier: f64,
+    },
+
+    /// A number was read from English words ("twenty-three" → 23).
+    NumberWords {
+        /// The original string.
+        original: String,
+    },
+
+    /// A range ("10 to 20", "between 10 and 20") was collapsed to one number.
+    RangeResolved {
+        /// The original string.
+        original: String,
+        /// Lower bound of the range.
+        low: f64,
+        /// Upper bound of the range.
+        high: f64,
+        /// Which policy picked the value: "low", "high" or "midpoint".
+        policy: String,
+    },
+
+    /// A placeholder like "N/A", "none" or "-" was read as a missing value.
+    NullSentinel {
+        /// The original string.
+        original: String,
+    },
+
+    /// A string was split into array items ("a, b and c", "- a\n- b").
+    SplitToArray {
+        /// The original string.
+        original: String,
+        /// What separated the items: "bullet", "numbered", "semicolon",
+        /// "comma" or "conjunction".
+        separator: String,
+    },
+
+    /// An array of entries was read as a map
+    /// (`[["a", 1]]`, `[{"key": "a", "value": 1}]`, `[{"a": 1}]`).
+    ArrayToMap {
+        /// Which shape the entries had: "pairs", "key_value_objects" or
+        /// "single_entry_objects".
+        shape: String,
+    },
+
+    /// An object or a map read from an array of entries repeated a key.
+    ///
+    /// See [`DuplicateKeyPolicy`](crate::parser::DuplicateKeyPolicy); entries
+    /// read as a map always keep the last value.
+    DuplicateKey {
+        /// Path to the repeated key, like `tags` or `items[0].name`.
+        path: String,
+        /// How the values were resolved: "first_wins", "last_wins" or "merge_into_array".
+        policy: String,
+    },
+
+    /// A JavaScript `undefined` was left out, like `JSON.stringify` does
+    /// (or written as `null` in an array).
+    UndefinedRemoved {
+        /// Path to the value, like `user.email` or `tags[2]`.
+        path: String,
+    },
+
+    /// A JavaScript `Date` constructor was converted to a string.
+    DateToString {
+        /// Path to the value, like `createdAt`.
+        path: String,
+        /// The constructor as written, like `new Date(2024, 0, 15)`.
+        original: String,
+    },
+
+    /// A JavaScript expression that has no JSON value, like a function or
+    /// a variable reference, was dropped.
+    ExpressionDropped {
+        /// Path to the value or member. Spreads and computed keys, which
+        /// have no name, are recorded at the object holding them.
+        path: String,
+        /// The expression as written.
+        expression: String,
+    },
+
     /// First match was selected from multiple options.
     ///
     /// When multiple candidates succeeded, the first one was chosen.
@@ -583,7 +943,173 @@ pub enum Transformation {
     },
 }
 
+/// The kind of a [`Transformation`], without its data.
+///
+/// Used as the key for per-kind rules such as
+/// [`CoercionPolicy`](crate::deserializer::CoercionPolicy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransformationKind {
+    /// [`Transformation::ExtractedFromMarkdown`]
+    ExtractedFromMarkdown,
+    /// [`Transformation::JsonRepaired`]
+    JsonRepaired,
+    /// [`Transformation::StringToNumber`]
+    StringToNumber,
+    /// [`Transformation::FloatToInt`]
+    FloatToInt,
+    /// [`Transformation::SingleToArray`]
+    SingleToArray,
+    /// [`Transformation::FieldNameCaseChanged`]
+    FieldNameCaseChanged,
+    /// [`Transformation::DefaultValueInserted`]
+    DefaultValueInserted,
+    /// [`Transformation::ExtraKey`]
+    ExtraKey,
+    /// [`Transformation::ImpliedKey`]
+    ImpliedKey,
+    /// [`Transformation::ObjectFromMarkdown`]
+    ObjectFromMarkdown,
+    /// [`Transformation::ArrayItemParseError`]
+    ArrayItemParseError,
+    /// [`Transformation::MapEntryParseError`]
+    MapEntryParseError,
+    /// [`Transformation::JsonToString`]
+    JsonToString,
+    /// [`Transformation::ConstraintChecked`]
+    ConstraintChecked,
+    /// [`Transformation::DefaultButHadUnparseableValue`]
+    DefaultButHadUnparseableValue,
+    /// [`Transformation::SubstringMatch`]
+    SubstringMatch,
+    /// [`Transformation::StrippedNonAlphaNumeric`]
+    StrippedNonAlphaNumeric,
+    /// [`Transformation::UnionMatch`]
+    UnionMatch,
+    /// [`Transformation::StringNormalized`]
+    StringNormalized,
+    /// [`Transformation::DateTimeParsed`]
+    DateTimeParsed,
+    /// [`Transformation::DurationParsed`]
+    DurationParsed,
+    /// [`Transformation::ApproximateValue`]
+    ApproximateValue,
+    /// [`Transformation::AmbiguousNumberFormat`]
+    AmbiguousNumberFormat,
+    /// [`Transformation::MagnitudeSuffix`]
+    MagnitudeSuffix,
+    /// [`Transformation::NumberWords`]
+    NumberWords,
+    /// [`Transformation::RangeResolved`]
+    RangeResolved,
+    /// [`Transformation::NullSentinel`]
+    NullSentinel,
+    /// [`Transformation::SplitToArray`]
+    SplitToArray,
+    /// [`Transformation::ArrayToMap`]
+    ArrayToMap,
+    /// [`Transformation::DuplicateKey`]
+    DuplicateKey,
+    /// [`Transformation::UndefinedRemoved`]
+    UndefinedRemoved,
+    /// [`Transformation::DateToString`]
+    DateToString,
+    /// [`Transformation::ExpressionDropped`]
+    ExpressionDropped,
+    /// [`Transformation::FirstMatch`]
+    FirstMatch,
+}
+
+impl TransformationKind {
+    /// Returns the snake_case name, as used for `"type"` in JSON output.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ExtractedFromMarkdown => "extracted_from_markdown",
+            Self::JsonRepaired => "json_repaired",
+            Self::StringToNumber => "string_to_number",
+            Self::FloatToInt => "float_to_int",
+            Self::SingleToArray => "single_to_array",
+            Self::FieldNameCaseChanged => "field_name_case_changed",
+            Self::DefaultValueInserted => "default_value_inserted",
+            Self::ExtraKey => "extra_key",
+            Self::ImpliedKey => "implied_key",
+            Self::ObjectFromMarkdown => "object_from_markdown",
+            Self::ArrayItemParseError => "array_item_parse_error",
+            Self::MapEntryParseError => "map_entry_parse_error",
+            Self::JsonToString => "json_to_string",
+            Self::ConstraintChecked => "constraint_checked",
+            Self::DefaultButHadUnparseableValue => "default_but_had_unparseable_value",
+            Self::SubstringMatch => "substring_match",
+            Self::StrippedNonAlphaNumeric => "stripped_non_alphanumeric",
+            Self::UnionMatch => "union_match",
+            Self::StringNormalized => "string_normalized",
+            Self::DateTimeParsed => "date_time_parsed",
+            Self::DurationParsed => "duration_parsed",
+            Self::ApproximateValue => "approximate_value",
+            Self::AmbiguousNumberFormat => "ambiguous_number_format",
+            Self::MagnitudeSuffix => "magnitude_suffix",
+            Self::NumberWords => "number_words",
+            Self::RangeResolved => "range_resolved",
+            Self::NullSentinel => "null_sentinel",
+            Self::SplitToArray => "split_to_array",
+            Self::ArrayToMap => "array_to_map",
+            Self::DuplicateKey => "duplicate_key",
+            Self::UndefinedRemoved => "undefined_removed",
+            Self::DateToString => "date_to_string",
+            Self::ExpressionDropped => "expression_dropped",
+            Self::FirstMatch => "first_match",
+        }
+    }
+}
+
+impl std::fmt::Display for TransformationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl Transformation {
+    /// Returns the kind of this transformation.
+    pub const fn kind(&self) -> TransformationKind {
+        match self {
+            Self::ExtractedFromMarkdown => TransformationKind::ExtractedFromMarkdown,
+            Self::JsonRepaired { .. } => TransformationKind::JsonRepaired,
+            Self::StringToNumber { .. } => TransformationKind::StringToNumber,
+            Self::FloatToInt { .. } => TransformationKind::FloatToInt,
+            Self::SingleToArray => TransformationKind::SingleToArray,
+            Self::FieldNameCaseChanged { .. } => TransformationKind::FieldNameCaseChanged,
+            Self::DefaultValueInserted { .. } => TransformationKind::DefaultValueInserted,
+            Self::ExtraKey { .. } => TransformationKind::ExtraKey,
+            Self::ImpliedKey { .. } => TransformationKind::ImpliedKey,
+            Self::ObjectFromMarkdown { .. } => TransformationKind::ObjectFromMarkdown,
+            Self::ArrayItemParseError { .. } => TransformationKind::ArrayItemParseError,
+            Self::MapEntryParseError { .. } => TransformationKind::MapEntryParseError,
+            Self::JsonToString { .. } => TransformationKind::JsonToString,
+            Self::ConstraintChecked { .. } => TransformationKind::ConstraintChecked,
+            Self::DefaultButHadUnparseableValue { .. } => {
+                TransformationKind::DefaultButHadUnparseableValue
+            }
+            Self::SubstringMatch { .. } => TransformationKind::SubstringMatch,
+            Self::StrippedNonAlphaNumeric { .. } => TransformationKind::StrippedNonAlphaNumeric,
+            Self::UnionMatch { .. } => TransformationKind::UnionMatch,
+            Self::StringNormalized { .. } => TransformationKind::StringNormalized,
+            Self::DateTimeParsed { .. } => TransformationKind::DateTimeParsed,
+            Self::DurationParsed { .. } => TransformationKind::DurationParsed,
+            Self::ApproximateValue { .. } => TransformationKind::ApproximateValue,
+            Self::AmbiguousNumberFormat { .. } => TransformationKind::AmbiguousNumberFormat,
+            Self::MagnitudeSuffix { .. } => TransformationKind::MagnitudeSuffix,
+            Self::NumberWords { .. } => TransformationKind::NumberWords,
+            Self::RangeResolved { .. } => TransformationKind::RangeResolved,
+            Self::NullSentinel { .. } => TransformationKind::NullSentinel,
+            Self::SplitToArray { .. } => TransformationKind::SplitToArray,
+            Self::ArrayToMap { .. } => TransformationKind::ArrayToMap,
+            Self::DuplicateKey { .. } => TransformationKind::DuplicateKey,
+            Self::UndefinedRemoved { .. } => TransformationKind::UndefinedRemoved,
+            Self::DateToString { .. } => TransformationKind::DateToString,
+            Self::ExpressionDropped { .. } => TransformationKind::ExpressionDropped,
+            Self::FirstMatch { .. } => TransformationKind::FirstMatch,
+        }
+    }
+
     /// Returns a penalty score for this transformation.
     ///
     /// Higher scores indicate less desirable transformations.
@@ -611,6 +1137,14 @@ impl Transformation {
                 // Penalty increases with index (deeper errors are worse)
                 1 + (*index as u32)
             }
+            Self::MapEntryParseError { kept_raw, .. } => {
+                // A dropped entry is lost data; a raw one is only untyped
+                if *kept_raw {
+                    2
+                } else {
+                    5
+                }
+            }
             Self::JsonToString { .. } => 2, // Moderate penalty for type conversion
             Self::ConstraintChecked {
                 passed, is_assert, ..
@@ -628,7 +1162,22 @@ impl Transformation {
             Self::SubstringMatch { .. } => 2,                // Fuzzy matching
             Self::StrippedNonAlphaNumeric { .. } => 3,       // More aggressive fuzzy matching
             Self::UnionMatch { .. } => 0,                    // Just tracking, not a penalty
-            Self::FirstMatch { .. } => 1,                    // Slight penalty for array-to-struct
+            Self::StringNormalized { .. } => 1,              // Formatting only, value unchanged
+            Self::DateTimeParsed { .. } => 2,                // Format had to be guessed
+            Self::DurationParsed { .. } => 2,                // Unit or format had to be guessed
+            Self::ApproximateValue { .. } => 2,              // Exact value is unknown
+            Self::MagnitudeSuffix { .. } => 1,               // Unambiguous expansion
+            Self::AmbiguousNumberFormat { .. } => 3,         // Could be off by 1000x
+            Self::NumberWords { .. } => 2,
+            Self::RangeResolved { .. } => 4, // Picked one value out of many
+            Self::NullSentinel { .. } => 1,  // The model said "no value" in words
+            Self::SplitToArray { .. } => 3,  // Guessed item boundaries in prose
+            Self::ArrayToMap { .. } => 2,    // Same entries, different container
+            Self::DuplicateKey { .. } => 5,  // Values were dropped or merged
+            Self::UndefinedRemoved { .. } => 1, // Same as what JavaScript would serialize
+            Self::DateToString { .. } => 1,  // Same instant, as text
+            Self::ExpressionDropped { .. } => 5, // A value was lost
+            Self::FirstMatch { .. } => 1,    // Slight penalty for array-to-struct
         }
     }
 }
@@ -659,6 +1208,25 @@ mod tests {
         assert_eq!(value.transformations().len(), 1);
     }
 
+    #[test]
+    fn test_flex_value_hash_ignores_key_order() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |text: &str| {
+            let value = FlexValue::new(serde_json::from_str(text).unwrap(), Source::Direct);
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // Equal values must hash equally, whatever order the keys were written in
+        assert_eq!(
+            hash(r#"{"b": [1, {"y": 2, "x": 1}], "a": null}"#),
+            hash(r#"{"a": null, "b": [1, {"x": 1, "y": 2}]}"#)
+        );
+        assert_ne!(hash(r#"{"a": 1}"#), hash(r#"{"a": "1"}"#));
+    }
+
     #[test]
     fn test_transformation_penalty() {
         assert_eq!(
@@ -546,3 +546,141 @@ fn test_struct_with_hashmap_and_other_fields() {
     );
     assert_eq!(data.metadata.get("author"), Some(&"Alice".to_string()));
 }
+
+// ============================================================================
+// Custom field coercion (#[llm(with)] / #[llm(deserialize_with)])
+// ============================================================================
+
+/// Parses ratings like "3 of 5 stars" into the leading number.
+#[cfg(feature = "derive")]
+fn parse_rating(value: &FlexValue, ctx: &mut CoercionContext) -> tryparse::error::Result<i64> {
+    if let Some(n) = value.value.as_i64() {
+        return Ok(n);
+    }
+
+    let text = value.value.as_str().unwrap_or_default();
+    let rating = text
+        .split_whitespace()
+        .next()
+        .and_then(|n| n.parse::<i64>().ok())
+        .ok_or_else(|| {
+            tryparse::error::ParseError::DeserializeFailed(
+                tryparse::error::DeserializeError::type_mismatch("rating", "string"),
+            )
+        })?;
+
+    ctx.add_transformation(tryparse::value::Transformation::StringToNumber {
+        original: text.to_string(),
+    });
+    Ok(rating)
+}
+
+#[cfg(feature = "derive")]
+mod phone {
+    use tryparse::{deserializer::CoercionContext, error::Result, value::FlexValue};
+
+    /// Keeps only the digits of a phone number.
+    pub fn deserialize(value: &FlexValue, _ctx: &mut CoercionContext) -> Result<String> {
+        let text = value.value.as_str().unwrap_or_default();
+        Ok(text.chars().filter(|c| c.is_ascii_digit()).collect())
+    }
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, Clone, LlmDeserialize, PartialEq)]
+struct Review {
+    title: String,
+    #[llm(deserialize_with = "parse_rating")]
+    rating: i64,
+    #[llm(with = "phone")]
+    contact: Option<String>,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_deserialize_with() {
+    let json = json!({
+        "title": "Great",
+        "rating": "3 of 5 stars",
+        "contact": "+1 (555) 010-2030"
+    });
+
+    let value = FlexValue::new(json, Source::Direct);
+    let mut ctx = CoercionContext::new();
+
+    let review = Review::deserialize(&value, &mut ctx).unwrap();
+
+    assert_eq!(review.title, "Great");
+    assert_eq!(review.rating, 3);
+    assert_eq!(review.contact, Some("15550102030".to_string()));
+
+    // Transformations recorded by the custom function reach the caller's context
+    assert!(ctx.transformations().iter().any(|t| matches!(
+        t,
+        tryparse::value::Transformation::StringToNumber { original } if original == "3 of 5 stars"
+    )));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_with_optional_missing() {
+    let json = json!({
+        "title": "Meh",
+        "rating": 2
+    });
+
+    let value = FlexValue::new(json, Source::Direct);
+    let mut ctx = CoercionContext::new();
+
+    let review = Review::deserialize(&value, &mut ctx).unwrap();
+
+    assert_eq!(review.rating, 2);
+    assert_eq!(review.contact, None);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_deserialize_with_error() {
+    let json = json!({
+        "title": "Bad",
+        "rating": "no stars"
+    });
+
+    let value = FlexValue::new(json, Source::Direct);
+    let mut ctx = CoercionContext::new();
+
+    assert!(Review::deserialize(&value, &mut ctx).is_err());
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, Clone, LlmDeserialize, PartialEq)]
+struct Rating {
+    #[llm(deserialize_with = "parse_rating")]
+    stars: i64,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_deserialize_with_implied_key_and_array() {
+    let records_rating = |ctx: &CoercionContext| {
+        ctx.transformations().iter().any(|t| matches!(
+            t,
+            tryparse::value::Transformation::StringToNumber { original } if original == "4 stars"
+        ))
+    };
+
+    // A bare value fills the only field
+    let value = FlexValue::new(json!("4 stars"), Source::Direct);
+    let mut ctx = CoercionContext::new();
+    assert_eq!(Rating::deserialize(&value, &mut ctx).unwrap().stars, 4);
+    assert!(records_rating(&ctx));
+
+    // Array elements fill fields in order
+    let value = FlexValue::new(json!(["Fine", "4 stars"]), Source::Direct);
+    let mut ctx = CoercionContext::new();
+    let review = Review::deserialize(&value, &mut ctx).unwrap();
+    assert_eq!(review.title, "Fine");
+    assert_eq!(review.rating, 4);
+    assert_eq!(review.contact, None);
+    assert!(records_rating(&ctx));
+}
@@ -35,6 +35,7 @@ struct NestedData {
     products: Vec<Product>,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 enum Status {
     Active,
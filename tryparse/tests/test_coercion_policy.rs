@@ -0,0 +1,171 @@
+//! Tests for `CoercionPolicy` on both the serde and `LlmDeserialize` paths.
+
+use serde::Deserialize;
+use tryparse::{
+    deserializer::{CoercionContext, CoercionPolicy},
+    error::{DeserializeError, ParseError},
+    options::ParseOptions,
+    parse_llm_with_context, parse_with,
+    value::TransformationKind,
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Order {
+    quantity: i64,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_default_policy_matches_parse() {
+    let response = r#"{"quantity": 2.6, "tags": "rush"}"#;
+    let order: Order = parse_with(response, &ParseOptions::new()).unwrap();
+
+    assert_eq!(order, tryparse::parse::<Order>(response).unwrap());
+}
+
+#[test]
+fn test_forbidden_float_to_int_fails() {
+    let options = ParseOptions::new()
+        .with_policy(CoercionPolicy::new().forbid(TransformationKind::FloatToInt));
+    let result = parse_with::<Order>(r#"{"quantity": 2.6, "tags": []}"#, &options);
+
+    match result {
+        Err(ParseError::DeserializeFailed(DeserializeError::ForbiddenTransformation {
+            kind,
+            ..
+        })) => assert_eq!(kind, TransformationKind::FloatToInt),
+        other => panic!("expected a policy error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lossless_rejects_single_to_array() {
+    let options = ParseOptions::new().with_policy(CoercionPolicy::lossless());
+
+    let order: Order = parse_with(r#"{"quantity": 2, "tags": ["rush"]}"#, &options).unwrap();
+    assert_eq!(order.tags, vec!["rush"]);
+
+    let err = parse_with::<Order>(r#"{"quantity": 2, "tags": "rush"}"#, &options)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("single_to_array"), "{}", err);
+}
+
+#[test]
+fn test_penalize_does_not_reject() {
+    let options = ParseOptions::new()
+        .with_policy(CoercionPolicy::new().penalize(TransformationKind::StringToNumber, 50));
+    let order: Order = parse_with(r#"{"quantity": "3", "tags": []}"#, &options).unwrap();
+
+    assert_eq!(order.quantity, 3);
+}
+
+#[test]
+fn test_llm_context_forbids_nested_coercion() {
+    let ctx = CoercionContext::new()
+        .with_policy(CoercionPolicy::new().forbid(TransformationKind::FloatToInt));
+
+    let ints: Vec<i64> = parse_llm_with_context("[1, 2, 3]", &ctx).unwrap();
+    assert_eq!(ints, vec![1, 2, 3]);
+
+    let result = parse_llm_with_context::<Vec<i64>>("[1, 2.5, 3]", &ctx);
+    assert!(matches!(
+        result,
+        Err(ParseError::DeserializeFailed(
+            DeserializeError::ForbiddenTransformation { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_context_score_uses_policy_penalties() {
+    let mut ctx = CoercionContext::new()
+        .with_policy(CoercionPolicy::new().penalize(TransformationKind::StringToNumber, 7));
+    ctx.add_transformation(tryparse::value::Transformation::StringToNumber {
+        original: "1".to_string(),
+    });
+
+    assert_eq!(ctx.score(), 7);
+    assert!(ctx.check_policy().is_ok());
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use tryparse::{
+        deserializer::{CoercionContext, CoercionPolicy},
+        parse_llm_with_context,
+        value::TransformationKind,
+    };
+    use tryparse_derive::LlmDeserialize;
+
+    #[derive(Debug, LlmDeserialize, PartialEq)]
+    #[llm(union)]
+    enum Count {
+        Exact(i64),
+        Labels(Vec<String>),
+    }
+
+    #[test]
+    fn test_union_skips_forbidden_variant() {
+        let default: Count = parse_llm_with_context("42.5", &CoercionContext::new()).unwrap();
+        assert_eq!(default, Count::Exact(43));
+
+        let ctx = CoercionContext::new()
+            .with_policy(CoercionPolicy::new().forbid(TransformationKind::FloatToInt));
+        let strict: Count = parse_llm_with_context("42.5", &ctx).unwrap();
+        assert_eq!(strict, Count::Labels(vec!["42.5".to_string()]));
+    }
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, Clone, tryparse_derive::LlmDeserialize)]
+struct Invoice {
+    id: i64,
+    note: Option<i64>,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_lossless_rejects_default_for_unparseable_value() {
+    let ctx = CoercionContext::new().with_policy(CoercionPolicy::lossless());
+
+    let invoice: Invoice = parse_llm_with_context(r#"{"id": 1}"#, &ctx).unwrap();
+    assert_eq!(invoice.id, 1);
+    assert_eq!(invoice.note, None);
+
+    let invoice: Invoice = tryparse::parse_llm(r#"{"id": 1, "note": "garbage"}"#).unwrap();
+    assert_eq!(invoice.note, None);
+
+    let err = parse_llm_with_context::<Invoice>(r#"{"id": 1, "note": "garbage"}"#, &ctx)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("default_but_had_unparseable_value"), "{}", err);
+}
+
+#[test]
+fn test_penalize_changes_the_winner() {
+    // The last fence ranks first, so the coerced quantity wins by default
+    let response = "```json\n{\"quantity\": 4, \"tags\": []}\n```\n\n\
+                    ```json\n{\"quantity\": \"3\", \"tags\": []}\n```";
+
+    let order: Order = parse_with(response, &ParseOptions::new()).unwrap();
+    assert_eq!(order.quantity, 3);
+
+    let options = ParseOptions::new()
+        .with_policy(CoercionPolicy::new().penalize(TransformationKind::StringToNumber, 1000));
+    let order: Order = parse_with(response, &options).unwrap();
+    assert_eq!(order.quantity, 4);
+}
+
+#[test]
+fn test_penalize_changes_the_winner_with_context() {
+    let response = "```json\n[3.5, 4.5]\n```\n\n```json\n[\"1\", \"2\"]\n```";
+
+    let ints: Vec<i64> = parse_llm_with_context(response, &CoercionContext::new()).unwrap();
+    assert_eq!(ints, vec![1, 2]);
+
+    let ctx = CoercionContext::new()
+        .with_policy(CoercionPolicy::new().penalize(TransformationKind::StringToNumber, 1000));
+    let ints: Vec<i64> = parse_llm_with_context(response, &ctx).unwrap();
+    assert_eq!(ints, vec![4, 5]);
+}
@@ -192,6 +192,7 @@ fn test_constraint_levels() {
 #[test]
 fn test_multiple_failing_asserts() {
     // Example with multiple assert constraints
+    #[allow(dead_code)]
     #[derive(Debug)]
     struct Product {
         name: String,
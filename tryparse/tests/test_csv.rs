@@ -0,0 +1,45 @@
+//! Tests for CSV and TSV answers.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{parse_llm, parse_llm_with_candidates, value::Source};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Contact {
+    full_name: String,
+    email: String,
+    score: f64,
+}
+
+#[test]
+fn test_fenced_csv_into_vec() {
+    let input = "\
+Extracted 3 contacts:
+
+```csv
+Full Name,Email,Score
+\"Doe, Jane\",jane@example.com,4.5
+John Smith,john@example.com,3
+\"Lee \"\"Ace\"\" Park\",ace@example.com,5
+```";
+
+    let (contacts, candidates) = parse_llm_with_candidates::<Vec<Contact>>(input).unwrap();
+    assert_eq!(contacts.len(), 3);
+    assert_eq!(contacts[0].full_name, "Doe, Jane");
+    assert_eq!(contacts[0].email, "jane@example.com");
+    assert_eq!(contacts[1].score, 3.0);
+    assert_eq!(contacts[2].full_name, "Lee \"Ace\" Park");
+    assert!(matches!(candidates[0].source, Source::Csv));
+}
+
+#[test]
+fn test_unfenced_semicolon_and_tab_data() {
+    let input = "full_name;email;score\nAna;ana@example.com;2,5\nBo;bo@example.com;1";
+    let contacts: Vec<Contact> = parse_llm(input).unwrap();
+    assert_eq!(contacts[1].full_name, "Bo");
+
+    let input = "full_name\temail\tscore\nAna\tana@example.com\t2.5";
+    let contacts: Vec<Contact> = parse_llm(input).unwrap();
+    assert_eq!(contacts[0].score, 2.5);
+}
@@ -0,0 +1,144 @@
+//! Tests for objects that repeat a key.
+
+use serde::Deserialize;
+use tryparse::{
+    error::ParseError,
+    parse_with_candidates, parse_with_parser,
+    parser::{DuplicateKeyPolicy, FlexibleParser},
+    value::Transformation,
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Post {
+    title: String,
+    tags: Vec<String>,
+}
+
+const INPUT: &str = r#"{"title": "Hi", "tags": ["rust"], "tags": ["serde", "json"]}"#;
+
+#[test]
+fn test_last_wins_by_default_and_is_recorded() {
+    let (post, candidates) = parse_with_candidates::<Post>(INPUT).unwrap();
+
+    assert_eq!(post.tags, vec!["serde", "json"]);
+    assert!(candidates[0].transformations().iter().any(|t| matches!(
+        t,
+        Transformation::DuplicateKey { path, policy } if path == "tags" && policy == "last_wins"
+    )));
+}
+
+#[test]
+fn test_first_wins_and_merge() {
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::FirstWins);
+    let post: Post = parse_with_parser(INPUT, &parser).unwrap();
+    assert_eq!(post.tags, vec!["rust"]);
+
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::MergeIntoArray);
+    let post: Post = parse_with_parser(INPUT, &parser).unwrap();
+    assert_eq!(post.tags, vec!["rust", "serde", "json"]);
+}
+
+#[test]
+fn test_repaired_json_is_checked_too() {
+    // Single quotes and a trailing comma need the fixer
+    let input = "{'title': 'Hi', 'tags': ['a'], 'tags': ['b'],}";
+
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::FirstWins);
+    let post: Post = parse_with_parser(input, &parser).unwrap();
+    assert_eq!(post.tags, vec!["a"]);
+
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::Error);
+    match parser.parse(input) {
+        Err(ParseError::DuplicateKey { path }) => assert_eq!(path, "tags"),
+        other => panic!("expected a duplicate key error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_extracted_json_is_checked_too() {
+    let inputs = [
+        // Markdown fence
+        format!("Here you go:\n```json\n{}\n```", INPUT),
+        // JSON in prose
+        format!("Sure! The post is {} hope this helps.", INPUT),
+        // Several objects
+        format!("{} {}", r#"{"title": "A", "tags": []}"#, INPUT),
+    ];
+
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::FirstWins);
+    for input in &inputs[..2] {
+        let post: Post = parse_with_parser(input, &parser).unwrap();
+        assert_eq!(post.tags, vec!["rust"], "{}", input);
+    }
+
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::Error);
+    for input in &inputs[..2] {
+        assert!(
+            matches!(parser.parse(input), Err(ParseError::DuplicateKey { .. })),
+            "{}",
+            input
+        );
+    }
+
+    // The object without a repeated key is still a candidate
+    let post: Post = parse_with_parser(&inputs[2], &parser).unwrap();
+    assert_eq!(post.title, "A");
+}
+
+#[test]
+fn test_rejected_block_keeps_the_others() {
+    let input = format!(
+        "```json\n{}\n```\n\nOr, shorter:\n```json\n{}\n```",
+        r#"{"title": "Hi", "tags": []}"#, INPUT
+    );
+
+    let parser = FlexibleParser::with_duplicate_key_policy(DuplicateKeyPolicy::Error);
+    let post: Post = parse_with_parser(&input, &parser).unwrap();
+    assert_eq!(post.tags, Vec::<String>::new());
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use tryparse::{
+        deserializer::{CoercionContext, CoercionPolicy},
+        error::ParseError,
+        parse_llm_with_context,
+        parser::DuplicateKeyPolicy,
+    };
+    use tryparse_derive::LlmDeserialize;
+
+    use super::INPUT;
+
+    #[derive(Debug, LlmDeserialize)]
+    struct Post {
+        #[allow(dead_code)]
+        title: String,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_context_policy() {
+        let ctx = CoercionContext::new().with_duplicate_key_policy(DuplicateKeyPolicy::FirstWins);
+        let post: Post = parse_llm_with_context(INPUT, &ctx).unwrap();
+        assert_eq!(post.tags, vec!["rust"]);
+
+        let ctx = CoercionContext::new().with_duplicate_key_policy(DuplicateKeyPolicy::Error);
+        assert!(matches!(
+            parse_llm_with_context::<Post>(INPUT, &ctx),
+            Err(ParseError::DuplicateKey { .. })
+        ));
+
+        let ctx = CoercionContext::new().with_policy(CoercionPolicy::lossless());
+        assert!(parse_llm_with_context::<Post>(INPUT, &ctx).is_err());
+    }
+
+    #[test]
+    fn test_context_policy_applies_to_fenced_json() {
+        let input = format!("```json\n{}\n```", INPUT);
+        let ctx = CoercionContext::new().with_duplicate_key_policy(DuplicateKeyPolicy::Error);
+        assert!(matches!(
+            parse_llm_with_context::<Post>(&input, &ctx),
+            Err(ParseError::DuplicateKey { .. })
+        ));
+    }
+}
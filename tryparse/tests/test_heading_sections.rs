@@ -0,0 +1,60 @@
+//! Tests for long-form answers organized under markdown headings.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{parse_llm, parse_llm_with_candidates, value::Source};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Report {
+    summary: String,
+    risks: Vec<String>,
+    next_steps: Option<Vec<String>>,
+}
+
+#[test]
+fn test_sections_into_struct() {
+    let input = "\
+Here's the review you asked for.
+
+## Summary
+The migration is on track.
+Rollout starts Monday.
+
+## Risks
+- Database lock contention
+- Cache warmup takes
+  longer than planned
+
+## Next Steps
+1. Load test
+2. Notify support";
+
+    let (report, candidates) = parse_llm_with_candidates::<Report>(input).unwrap();
+    assert_eq!(
+        report.summary,
+        "The migration is on track.\nRollout starts Monday."
+    );
+    assert_eq!(
+        report.risks,
+        vec![
+            "Database lock contention",
+            "Cache warmup takes longer than planned"
+        ]
+    );
+    assert_eq!(
+        report.next_steps,
+        Some(vec!["Load test".to_string(), "Notify support".to_string()])
+    );
+    assert!(matches!(candidates[0].source, Source::Sections));
+}
+
+#[test]
+fn test_title_heading_is_unwrapped() {
+    let input = "# Weekly Report\n## Summary:\nQuiet week.\n## **Risks**\n- none";
+
+    let report: Report = parse_llm(input).unwrap();
+    assert_eq!(report.summary, "Quiet week.");
+    assert_eq!(report.risks, vec!["none"]);
+    assert_eq!(report.next_steps, None);
+}
@@ -107,6 +107,7 @@ fn test_implied_key_with_llm_deserialize() {
 
 #[test]
 fn test_multi_field_struct_rejects_non_object() {
+    #[allow(dead_code)]
     #[derive(Deserialize, Debug)]
     struct TwoFields {
         name: String,
@@ -0,0 +1,85 @@
+//! Tests for JavaScript object literal answers.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{
+    deserializer::{CoercionContext, CoercionPolicy},
+    parse_llm, parse_llm_with_candidates, parse_llm_with_context,
+    value::Transformation,
+};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Event {
+    title: String,
+    starts_at: String,
+    attendees: Vec<String>,
+    location: Option<String>,
+}
+
+#[test]
+fn test_const_wrapper_with_date_and_undefined() {
+    let input =
+        "const event = {\n  title: 'Launch',\n  starts_at: new Date(\"2024-03-01T09:00:00Z\"),\n  \
+                 attendees: ['ana', 'bo'],\n  location: undefined,\n};";
+
+    let (event, candidates) = parse_llm_with_candidates::<Event>(input).unwrap();
+    assert_eq!(event.title, "Launch");
+    assert_eq!(event.starts_at, "2024-03-01T09:00:00Z");
+    assert_eq!(event.attendees, vec!["ana", "bo"]);
+    assert_eq!(event.location, None);
+
+    let transformations: Vec<_> = candidates
+        .iter()
+        .flat_map(|c| c.transformations().iter())
+        .collect();
+    assert!(transformations.iter().any(|t| matches!(
+        t,
+        Transformation::DateToString { path, .. } if path == "starts_at"
+    )));
+    assert!(transformations.iter().any(|t| matches!(
+        t,
+        Transformation::UndefinedRemoved { path } if path == "location"
+    )));
+}
+
+#[test]
+fn test_fenced_typescript_drops_expressions() {
+    let input = "Here is the event:\n\n```ts\nexport default {\n  title: `Standup`,\n  \
+                 starts_at: new Date(2024, 0, 8, 9, 30),\n  attendees: [...team, 'cy'],\n  \
+                 location: room.name,\n  notify: () => send(),\n} satisfies Event;\n```";
+
+    let event: Event = parse_llm(input).unwrap();
+    assert_eq!(event.title, "Standup");
+    assert_eq!(event.starts_at, "2024-01-08T09:30:00.000Z");
+    assert_eq!(event.attendees, vec!["cy"]);
+    assert_eq!(event.location, None);
+}
+
+#[test]
+fn test_lossless_policy_rejects_dropped_expressions() {
+    let ctx = CoercionContext::new().with_policy(CoercionPolicy::lossless());
+
+    let input =
+        "{title: 'Sync', starts_at: '2024-05-02', attendees: [], location: `${city}, ${country}`}";
+    assert!(parse_llm::<Event>(input).is_ok());
+    assert!(parse_llm_with_context::<Event>(input, &ctx).is_err());
+
+    let input = "{title: 'Sync', starts_at: new Date(0), attendees: [], location: undefined}";
+    let event: Event = parse_llm_with_context(input, &ctx).unwrap();
+    assert_eq!(event.title, "Sync");
+    assert_eq!(event.starts_at, "1970-01-01T00:00:00.000Z");
+    assert!(event.attendees.is_empty());
+    assert_eq!(event.location, None);
+}
+
+#[test]
+fn test_objects_in_prose_keep_their_lists() {
+    let input = "Result: {name: 'x', tags: ['a', 'b'], ok: true, when: new Date(\"2024-01-02\")}";
+
+    let value: serde_json::Value = tryparse::parse(input).unwrap();
+    assert_eq!(value["name"], "x");
+    assert_eq!(value["tags"], serde_json::json!(["a", "b"]));
+    assert_eq!(value["ok"], true);
+    assert_eq!(value["when"], "2024-01-02");
+}
@@ -0,0 +1,109 @@
+//! Tests for JSON5 answers.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{
+    deserializer::CoercionContext,
+    error::ParseError,
+    parse_llm, parse_llm_with_candidates, parse_llm_with_context, parse_with_parser,
+    parser::{
+        strategies::{Json5Strategy, NonFinitePolicy},
+        DuplicateKeyPolicy, FlexibleParser,
+    },
+    value::{JsonFix, Source},
+};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize, serde::Deserialize)]
+struct Reading {
+    sensor: String,
+    value: Option<f64>,
+    offset: f64,
+    note: String,
+}
+
+#[test]
+fn test_json5_numbers_and_strings() {
+    let input = "{
+  // first reading
+  sensor: 'temp-1',
+  value: .5,
+  offset: +2.,
+  note: 'calibrated \\
+yesterday',
+}";
+
+    let (reading, candidates) = parse_llm_with_candidates::<Reading>(input).unwrap();
+    assert_eq!(reading.sensor, "temp-1");
+    assert_eq!(reading.value, Some(0.5));
+    assert_eq!(reading.offset, 2.0);
+    assert_eq!(reading.note, "calibrated yesterday");
+
+    let Source::Fixed { fixes } = &candidates[0].source else {
+        panic!("expected a fixed candidate, got {:?}", candidates[0].source);
+    };
+    for fix in [
+        JsonFix::Comments,
+        JsonFix::BareDecimalPoints,
+        JsonFix::PlusSigns,
+        JsonFix::LineContinuations,
+        JsonFix::TrailingCommas,
+    ] {
+        assert!(fixes.contains(&fix), "missing {:?}", fix);
+    }
+}
+
+#[test]
+fn test_non_finite_numbers_in_prose() {
+    let input = "The sensor saturated: {sensor: 'x', value: Infinity, offset: -.25, note: 'max'}";
+    let reading: Reading = parse_llm(input).unwrap();
+    assert_eq!(reading.value, None);
+    assert_eq!(reading.offset, -0.25);
+}
+
+#[test]
+fn test_non_finite_policy_on_custom_parser() {
+    let input = "{sensor: 'x', value: NaN, offset: 0, note: ''}";
+
+    let strict = FlexibleParser::with_strategies(vec![Box::new(
+        Json5Strategy::new().with_non_finite_policy(NonFinitePolicy::Error),
+    )]);
+    assert!(parse_with_parser::<Reading>(input, &strict).is_err());
+
+    let nulls = FlexibleParser::with_strategies(vec![Box::new(Json5Strategy::new())]);
+    let reading: Reading = parse_with_parser(input, &nulls).unwrap();
+    assert_eq!(reading.value, None);
+}
+
+#[test]
+fn test_non_finite_policy_on_default_parser() {
+    let input = "{sensor: 'x', value: NaN, offset: 0, note: ''}";
+
+    let strict =
+        FlexibleParser::with_policies(DuplicateKeyPolicy::default(), NonFinitePolicy::Error);
+    assert!(matches!(
+        strict.parse(input),
+        Err(ParseError::NonFiniteNumber { literal }) if literal == "NaN"
+    ));
+    assert!(strict.clone().parse(input).is_err());
+
+    let strings =
+        FlexibleParser::with_policies(DuplicateKeyPolicy::default(), NonFinitePolicy::String);
+    let candidates = strings.parse(input).unwrap();
+    assert_eq!(candidates[0].value["value"], "NaN");
+}
+
+#[test]
+fn test_non_finite_policy_on_context() {
+    let input = "{sensor: 'x', value: Infinity, offset: 0, note: ''}";
+
+    let ctx = CoercionContext::new().with_non_finite_policy(NonFinitePolicy::Error);
+    assert!(matches!(
+        parse_llm_with_context::<Reading>(input, &ctx),
+        Err(ParseError::NonFiniteNumber { .. })
+    ));
+
+    let ctx = CoercionContext::new().with_non_finite_policy(NonFinitePolicy::String);
+    let reading: Reading = parse_llm_with_context(input, &ctx).unwrap();
+    assert_eq!(reading.value, Some(f64::INFINITY));
+}
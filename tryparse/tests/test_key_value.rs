@@ -0,0 +1,55 @@
+//! Tests for answers written as `key: value` lines instead of JSON.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{parse_llm, parse_llm_with_candidates, value::Source};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Address {
+    city: String,
+    zip_code: String,
+}
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Person {
+    name: String,
+    age: i64,
+    address: Address,
+    languages: Vec<String>,
+}
+
+#[test]
+fn test_markdown_bullets_into_struct() {
+    let input = "\
+Here's the profile you asked for:
+
+- **Name**: Alice Martin
+- **Age:** 34
+- **Address**:
+  - City: Lyon
+  - Zip code: 69001
+- **Languages**:
+  - French
+  - English
+
+Let me know if anything is missing!";
+
+    let (person, candidates) = parse_llm_with_candidates::<Person>(input).unwrap();
+    assert_eq!(person.name, "Alice Martin");
+    assert_eq!(person.age, 34);
+    assert_eq!(person.address.city, "Lyon");
+    assert_eq!(person.address.zip_code, "69001");
+    assert_eq!(person.languages, vec!["French", "English"]);
+    assert!(matches!(candidates[0].source, Source::KeyValue));
+}
+
+#[test]
+fn test_repeated_keys_become_a_list() {
+    let input = "Name = Bob\nAge = 41\nAddress:\n  City = Oslo\n  Zip code = 0150\n\
+                 Languages = Norwegian\nLanguages = English";
+
+    let person: Person = parse_llm(input).unwrap();
+    assert_eq!(person.address.zip_code, "0150");
+    assert_eq!(person.languages, vec!["Norwegian", "English"]);
+}
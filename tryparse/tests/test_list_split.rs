@@ -0,0 +1,92 @@
+//! Tests for splitting delimited text and markdown lists into `Vec<T>`.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{
+    deserializer::{CoercionContext, CoercionPolicy},
+    parse_llm, parse_llm_with_candidates, parse_llm_with_context,
+    value::{Transformation, TransformationKind},
+};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, PartialEq, LlmDeserialize)]
+struct Profile {
+    name: String,
+    skills: Vec<String>,
+    scores: Vec<i64>,
+}
+
+#[test]
+fn test_comma_and_conjunction() {
+    let profile: Profile = parse_llm(
+        r#"{"name": "Ada", "skills": "math, poetry, and engines", "scores": "90; 85; 70"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(profile.skills, vec!["math", "poetry", "engines"]);
+    assert_eq!(profile.scores, vec![90, 85, 70]);
+}
+
+#[test]
+fn test_single_values_with_commas_are_not_split() {
+    for skill in [
+        "Paris, France",
+        "Smith, John",
+        "Hello, world!",
+        "Tom and Jerry",
+    ] {
+        let input = format!(r#"{{"name": "Ada", "skills": "{}", "scores": []}}"#, skill);
+        let profile: Profile = parse_llm(&input).unwrap();
+        assert_eq!(profile.skills, vec![skill]);
+    }
+}
+
+#[test]
+fn test_markdown_list_in_string() {
+    let profile: Profile =
+        parse_llm(r#"{"name": "Ada", "skills": "- math\n- poetry", "scores": "1. 90\n2. 85"}"#)
+            .unwrap();
+
+    assert_eq!(profile.skills, vec!["math", "poetry"]);
+    assert_eq!(profile.scores, vec![90, 85]);
+}
+
+#[test]
+fn test_single_item_is_still_wrapped() {
+    let profile: Profile = parse_llm(r#"{"name": "Ada", "skills": "math", "scores": 90}"#).unwrap();
+
+    assert_eq!(profile.skills, vec!["math"]);
+    assert_eq!(profile.scores, vec![90]);
+}
+
+#[test]
+fn test_split_is_recorded_and_scored() {
+    let (_profile, candidates) = parse_llm_with_candidates::<Profile>(
+        r#"{"name": "Ada", "skills": "math, poetry or chess", "scores": []}"#,
+    )
+    .unwrap();
+
+    let split = candidates
+        .iter()
+        .flat_map(|c| c.transformations())
+        .find(|t| matches!(t, Transformation::SplitToArray { .. }))
+        .expect("split recorded");
+    assert!(split.penalty() > 0);
+}
+
+#[test]
+fn test_policy_can_forbid_splitting() {
+    let ctx = CoercionContext::new()
+        .with_policy(CoercionPolicy::new().forbid(TransformationKind::SplitToArray));
+
+    let result = parse_llm_with_context::<Profile>(
+        r#"{"name": "Ada", "skills": "math, poetry, chess", "scores": []}"#,
+        &ctx,
+    );
+    assert!(result.is_err());
+
+    let profile: Profile =
+        parse_llm_with_context(r#"{"name": "Ada", "skills": ["math"], "scores": []}"#, &ctx)
+            .unwrap();
+    assert_eq!(profile.skills, vec!["math"]);
+}
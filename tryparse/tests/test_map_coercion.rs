@@ -0,0 +1,124 @@
+//! Tests for reading maps from arrays of entries.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use tryparse::{
+    deserializer::CoercionPolicy,
+    error::{DeserializeError, ParseError},
+    options::ParseOptions,
+    parse, parse_with,
+    value::TransformationKind,
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Inventory {
+    counts: BTreeMap<String, u32>,
+}
+
+#[test]
+fn test_serde_pairs() {
+    let inventory: Inventory = parse(r#"{"counts": [["apples", 3], ["pears", 5]]}"#).unwrap();
+
+    assert_eq!(inventory.counts["apples"], 3);
+    assert_eq!(inventory.counts["pears"], 5);
+}
+
+#[test]
+fn test_serde_key_value_objects() {
+    let counts: HashMap<String, u32> =
+        parse(r#"[{"name": "apples", "value": "3"}, {"name": "pears", "value": 5}]"#).unwrap();
+
+    assert_eq!(counts["apples"], 3);
+    assert_eq!(counts["pears"], 5);
+}
+
+#[test]
+fn test_serde_single_entry_objects() {
+    let inventory: Inventory = parse(r#"{"counts": [{"apples": 3}, {"pears": 5}]}"#).unwrap();
+
+    assert_eq!(inventory.counts.len(), 2);
+}
+
+#[test]
+fn test_serde_struct_still_needs_an_object() {
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct Fruit {
+        apples: u32,
+    }
+
+    assert!(parse::<Fruit>(r#"[["apples", 3]]"#).is_err());
+}
+
+#[test]
+fn test_duplicate_keys_keep_last_unless_forbidden() {
+    let input = r#"{"counts": [["apples", 3], ["apples", 4]]}"#;
+
+    let inventory: Inventory = parse(input).unwrap();
+    assert_eq!(inventory.counts["apples"], 4);
+
+    let options = ParseOptions::new()
+        .with_policy(CoercionPolicy::new().forbid(TransformationKind::DuplicateKey));
+    match parse_with::<Inventory>(input, &options) {
+        Err(ParseError::DeserializeFailed(DeserializeError::ForbiddenTransformation {
+            kind,
+            ..
+        })) => assert_eq!(kind, TransformationKind::DuplicateKey),
+        other => panic!("expected a policy error, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use std::collections::HashMap;
+
+    use tryparse::{
+        deserializer::{CoercionContext, CoercionPolicy, MapEntryPolicy},
+        parse_llm, parse_llm_with_context,
+        value::TransformationKind,
+    };
+    use tryparse_derive::LlmDeserialize;
+
+    #[derive(Debug, Clone, PartialEq, LlmDeserialize)]
+    struct Scores {
+        by_player: HashMap<String, i64>,
+    }
+
+    #[test]
+    fn test_llm_entry_arrays() {
+        let scores: Scores =
+            parse_llm(r#"{"by_player": [{"key": "ann", "value": 3}, {"key": "bo", "value": 5}]}"#)
+                .unwrap();
+        assert_eq!(scores.by_player["ann"], 3);
+
+        let scores: Scores = parse_llm(r#"{"by_player": [["ann", 3], ["bo", 5]]}"#).unwrap();
+        assert_eq!(scores.by_player["bo"], 5);
+    }
+
+    #[test]
+    fn test_llm_duplicate_keys() {
+        let input = r#"{"by_player": [{"ann": 3}, {"ann": 4}]}"#;
+
+        let scores: Scores = parse_llm(input).unwrap();
+        assert_eq!(scores.by_player["ann"], 4);
+
+        let ctx = CoercionContext::new()
+            .with_policy(CoercionPolicy::new().forbid(TransformationKind::DuplicateKey));
+        assert!(parse_llm_with_context::<Scores>(input, &ctx).is_err());
+    }
+
+    #[test]
+    fn test_llm_bad_entries_follow_policy() {
+        let input = r#"{"by_player": {"ann": 3, "bo": "absent"}}"#;
+
+        let scores: Scores = parse_llm(input).unwrap();
+        assert_eq!(scores.by_player.len(), 1);
+
+        let ctx = CoercionContext::new().with_map_entry_policy(MapEntryPolicy::Fail);
+        assert!(parse_llm_with_context::<Scores>(input, &ctx).is_err());
+
+        let ctx = CoercionContext::new().with_policy(CoercionPolicy::lossless());
+        assert!(parse_llm_with_context::<Scores>(input, &ctx).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+//! Tests for records answered as markdown tables.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{parse_llm, parse_llm_with_candidates, value::Source};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Product {
+    name: String,
+    unit_price: f64,
+    in_stock: bool,
+    notes: Option<String>,
+}
+
+#[test]
+fn test_table_into_vec_of_structs() {
+    let input = r#"Sure! Here's the inventory:
+
+| Name | Unit Price | In Stock | Notes |
+|:-----|-----------:|:--------:|-------|
+| Hammer | $12.50 | true | steel \| wood |
+| **Saw** | 20 | false |
+| Drill | 89.99 | true | |
+
+Let me know if you need more."#;
+
+    let (products, candidates) = parse_llm_with_candidates::<Vec<Product>>(input).unwrap();
+
+    assert_eq!(products.len(), 3);
+    assert_eq!(products[0].unit_price, 12.5);
+    assert_eq!(products[0].notes.as_deref(), Some("steel | wood"));
+    assert_eq!(products[1].name, "Saw");
+    assert!(!products[1].in_stock);
+    assert_eq!(products[1].notes, None);
+    assert_eq!(products[2].notes, None);
+    assert!(matches!(candidates[0].source, Source::MarkdownTable));
+}
+
+#[test]
+fn test_json_still_wins_over_tables() {
+    let input = r#"[{"name": "Saw", "unit_price": 20, "in_stock": false}]"#;
+    let products: Vec<Product> = parse_llm(input).unwrap();
+    assert_eq!(products[0].name, "Saw");
+}
@@ -0,0 +1,101 @@
+//! Tests for natural-language number coercion through serde.
+//!
+//! Suffixes ("1.2k"), number words ("twenty-three"), approximate markers
+//! ("~40"), ranges ("10 to 20", resolved to the midpoint by default) and
+//! non-English number formats ("1.234,56", "١٢٣").
+
+use serde::Deserialize;
+use tryparse::parse;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Company {
+    employees: u32,
+    revenue: f64,
+}
+
+#[test]
+fn test_magnitude_suffixes() {
+    let response = r#"{"employees": "1.2k", "revenue": "$2.5B"}"#;
+    let company: Company = parse(response).unwrap();
+
+    assert_eq!(company.employees, 1200);
+    assert_eq!(company.revenue, 2_500_000_000.0);
+}
+
+#[test]
+fn test_number_words() {
+    let response = r#"{"employees": "twenty-three", "revenue": "three million"}"#;
+    let company: Company = parse(response).unwrap();
+
+    assert_eq!(company.employees, 23);
+    assert_eq!(company.revenue, 3_000_000.0);
+}
+
+#[test]
+fn test_approximate_markers() {
+    let response = r#"{"employees": "40+", "revenue": "~1.5M"}"#;
+    let company: Company = parse(response).unwrap();
+
+    assert_eq!(company.employees, 40);
+    assert_eq!(company.revenue, 1_500_000.0);
+}
+
+#[test]
+fn test_ranges_use_midpoint() {
+    let response = r#"{"employees": "between 10 and 20", "revenue": "1M – 2M"}"#;
+    let company: Company = parse(response).unwrap();
+
+    assert_eq!(company.employees, 15);
+    assert_eq!(company.revenue, 1_500_000.0);
+}
+
+#[test]
+fn test_phone_like_numbers_are_not_ranges() {
+    let response = r#"{"employees": "555-1234", "revenue": 1.0}"#;
+    assert!(parse::<Company>(response).is_err());
+
+    let response = r#"{"employees": "1-2", "revenue": 1.0}"#;
+    assert!(parse::<Company>(response).is_err());
+}
+
+#[test]
+fn test_plain_text_still_fails() {
+    let response = r#"{"employees": "a few", "revenue": 1.0}"#;
+    assert!(parse::<Company>(response).is_err());
+}
+
+#[test]
+fn test_locale_formats() {
+    let response = r#"{"employees": "١٢٣", "revenue": "1.234.567,89 €"}"#;
+    let company: Company = parse(response).unwrap();
+
+    assert_eq!(company.employees, 123);
+    assert_eq!(company.revenue, 1_234_567.89);
+
+    let response = r#"{"employees": "１２", "revenue": "1 234,5"}"#;
+    let company: Company = parse(response).unwrap();
+
+    assert_eq!(company.employees, 12);
+    assert_eq!(company.revenue, 1_234.5);
+}
+
+#[test]
+fn test_options_set_range_policy_and_locale() {
+    use tryparse::{
+        deserializer::{NumberLocale, RangePolicy},
+        options::ParseOptions,
+        parse_with,
+    };
+
+    let response = r#"{"employees": "10 to 20", "revenue": "1.234"}"#;
+    let company: Company = parse(response).unwrap();
+    assert_eq!(company.employees, 15);
+    assert_eq!(company.revenue, 1.234);
+
+    let options = ParseOptions::new()
+        .with_range_policy(RangePolicy::High)
+        .with_number_locale(NumberLocale::DecimalComma);
+    let company: Company = parse_with(response, &options).unwrap();
+    assert_eq!(company.employees, 20);
+    assert_eq!(company.revenue, 1234.0);
+}
@@ -0,0 +1,230 @@
+//! Tests for opt-in null sentinels ("N/A", "none", "-") and `Maybe<T>`.
+
+use serde::Deserialize;
+use tryparse::{
+    deserializer::{Maybe, NullSentinels},
+    options::ParseOptions,
+    parse, parse_with,
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Listing {
+    title: String,
+    price: Option<f64>,
+    sqft: Option<u32>,
+}
+
+#[test]
+fn test_serde_sentinels_become_none() {
+    let listing: Listing = parse_with(
+        r#"{"title": "Loft", "price": "N/A", "sqft": "unknown"}"#,
+        &ParseOptions::new().with_null_sentinels(NullSentinels::common()),
+    )
+    .unwrap();
+
+    assert_eq!(listing.price, None);
+    assert_eq!(listing.sqft, None);
+}
+
+#[test]
+fn test_serde_sentinels_only_apply_to_options() {
+    let listing: Listing = parse_with(
+        r#"{"title": "none", "price": 10, "sqft": null}"#,
+        &ParseOptions::new().with_null_sentinels(NullSentinels::common()),
+    )
+    .unwrap();
+
+    assert_eq!(listing.title, "none");
+    assert_eq!(listing.price, Some(10.0));
+}
+
+#[test]
+fn test_serde_custom_sentinels() {
+    let response = r#"{"title": "Loft", "price": "N/A", "sqft": "TBD"}"#;
+
+    let options = ParseOptions::new().with_null_sentinels(NullSentinels::none().with("tbd"));
+    assert!(parse_with::<Listing>(response, &options).is_err());
+
+    let options = ParseOptions::new().with_null_sentinels(NullSentinels::common().with("tbd"));
+    let listing: Listing = parse_with(response, &options).unwrap();
+    assert_eq!(listing.price, None);
+    assert_eq!(listing.sqft, None);
+}
+
+#[test]
+fn test_serde_sentinels_combine_with_a_policy() {
+    use tryparse::{deserializer::CoercionPolicy, value::TransformationKind};
+
+    let options = ParseOptions::new()
+        .with_null_sentinels(NullSentinels::common())
+        .with_policy(CoercionPolicy::new().forbid(TransformationKind::StringToNumber));
+
+    let listing: Listing =
+        parse_with(r#"{"title": "Loft", "price": "N/A", "sqft": 80}"#, &options).unwrap();
+    assert_eq!(listing.price, None);
+    assert_eq!(listing.sqft, Some(80));
+
+    assert!(parse_with::<Listing>(
+        r#"{"title": "Loft", "price": "N/A", "sqft": "80"}"#,
+        &options
+    )
+    .is_err());
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Owner {
+    owner: Option<String>,
+}
+
+#[test]
+fn test_serde_sentinels_are_opt_in() {
+    for word in ["Unknown", "NA", "none"] {
+        let owner: Owner = parse(&format!(r#"{{"owner": "{}"}}"#, word)).unwrap();
+        assert_eq!(owner.owner.as_deref(), Some(word));
+    }
+
+    let owner: Owner = parse_with(r#"{"owner": "Unknown"}"#, &ParseOptions::new()).unwrap();
+    assert_eq!(owner.owner.as_deref(), Some("Unknown"));
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+enum Status {
+    Active,
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Account {
+    status: Option<Status>,
+}
+
+#[test]
+fn test_serde_enum_variant_named_like_a_sentinel() {
+    let account: Account = parse(r#"{"status": "Unknown"}"#).unwrap();
+    assert_eq!(account.status, Some(Status::Unknown));
+}
+
+#[derive(Deserialize, Debug)]
+struct ListingPatch {
+    #[serde(default)]
+    price: Maybe<f64>,
+    #[serde(default)]
+    sqft: Maybe<u32>,
+}
+
+#[test]
+fn test_serde_maybe() {
+    let patch: ListingPatch = parse_with(
+        r#"{"price": "n/a"}"#,
+        &ParseOptions::new().with_null_sentinels(NullSentinels::common()),
+    )
+    .unwrap();
+
+    assert_eq!(patch.price, Maybe::Null);
+    assert_eq!(patch.sqft, Maybe::Absent);
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use tryparse::{
+        deserializer::{CoercionContext, Maybe, NullSentinels},
+        parse_llm, parse_llm_with_context,
+    };
+    use tryparse_derive::LlmDeserialize;
+
+    #[derive(Debug, Clone, PartialEq, LlmDeserialize)]
+    struct Contact {
+        name: String,
+        phone: Option<String>,
+        age: Option<i64>,
+    }
+
+    #[test]
+    fn test_option_fields_read_sentinels() {
+        let ctx = CoercionContext::new().with_null_sentinels(NullSentinels::common());
+        let contact: Contact =
+            parse_llm_with_context(r#"{"name": "Ada", "phone": "N/A", "age": "-"}"#, &ctx).unwrap();
+
+        assert_eq!(contact.phone, None);
+        assert_eq!(contact.age, None);
+    }
+
+    #[test]
+    fn test_option_fields_keep_sentinels_by_default() {
+        let contact: Contact = parse_llm(r#"{"name": "Ada", "phone": "Unknown"}"#).unwrap();
+
+        assert_eq!(contact.phone.as_deref(), Some("Unknown"));
+    }
+
+    #[test]
+    fn test_null_is_none_not_the_string_null() {
+        let contact: Contact = parse_llm(r#"{"name": "Ada", "phone": null}"#).unwrap();
+
+        assert_eq!(contact.phone, None);
+    }
+
+    #[test]
+    fn test_custom_sentinels() {
+        let ctx = CoercionContext::new().with_null_sentinels(NullSentinels::none().with("tbd"));
+
+        let contact: Contact =
+            parse_llm_with_context(r#"{"name": "Ada", "phone": "N/A", "age": "TBD"}"#, &ctx)
+                .unwrap();
+
+        assert_eq!(contact.phone.as_deref(), Some("N/A"));
+        assert_eq!(contact.age, None);
+    }
+
+    #[derive(Debug, Clone, PartialEq, LlmDeserialize)]
+    struct ContactPatch {
+        #[llm(maybe)]
+        phone: Maybe<String>,
+        #[llm(maybe)]
+        age: Maybe<i64>,
+        email: tryparse::deserializer::Maybe<String>,
+    }
+
+    #[test]
+    fn test_maybe_fields() {
+        let patch: ContactPatch = parse_llm(r#"{"phone": null, "age": "42"}"#).unwrap();
+
+        assert_eq!(patch.phone, Maybe::Null);
+        assert_eq!(patch.age, Maybe::Value(42));
+        assert_eq!(patch.email, Maybe::Absent);
+    }
+
+    #[test]
+    fn test_maybe_sentinel_is_null() {
+        let ctx = CoercionContext::new().with_null_sentinels(NullSentinels::common());
+        let patch: ContactPatch = parse_llm_with_context(r#"{"phone": "none"}"#, &ctx).unwrap();
+
+        assert_eq!(patch.phone, Maybe::Null);
+        assert_eq!(patch.age, Maybe::Absent);
+    }
+
+    #[test]
+    fn test_unparseable_maybe_is_not_absent() {
+        assert!(parse_llm::<ContactPatch>(r#"{"phone": null, "age": "lots"}"#).is_err());
+    }
+
+    /// Not `tryparse::deserializer::Maybe`, so an ordinary required field.
+    mod other {
+        #[derive(Debug, Clone, PartialEq, tryparse_derive::LlmDeserialize)]
+        pub struct Maybe<T: tryparse::deserializer::LlmDeserialize + Clone + 'static> {
+            pub value: T,
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, LlmDeserialize)]
+    struct Answer {
+        guess: other::Maybe<i64>,
+    }
+
+    #[test]
+    fn test_other_types_named_maybe_are_required() {
+        let answer: Answer = parse_llm(r#"{"guess": {"value": 3}}"#).unwrap();
+        assert_eq!(answer.guess, other::Maybe { value: 3 });
+
+        assert!(parse_llm::<Answer>(r#"{}"#).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+//! Tests for checked numeric conversions and the `arbitrary_precision` feature.
+
+use serde::Deserialize;
+use tryparse::{
+    deserializer::{CoercionContext, LlmDeserialize},
+    error::{DeserializeError, ParseError},
+    parse, parse_llm,
+    value::{FlexValue, Source},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Counter {
+    count: i64,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Quota {
+    limit: u32,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Account {
+    id: u128,
+}
+
+fn assert_out_of_range<T: std::fmt::Debug>(result: tryparse::error::Result<T>) {
+    match result {
+        Err(ParseError::DeserializeFailed(DeserializeError::NumberOutOfRange { .. })) => {}
+        other => panic!("expected an out-of-range error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_serde_float_overflow_is_an_error() {
+    assert_out_of_range(parse::<Counter>(r#"{"count": 1e30}"#));
+    assert_out_of_range(parse::<Counter>(r#"{"count": "1e30"}"#));
+}
+
+#[test]
+fn test_serde_out_of_range_names_the_target() {
+    for (input, expected) in [
+        (r#"{"limit": 5000000000}"#, "5000000000"),
+        (r#"{"limit": -1}"#, "-1"),
+        (r#"{"limit": 1e30}"#, "1e30"),
+        (r#"{"limit": "12 billion"}"#, "12 billion"),
+    ] {
+        match parse::<Quota>(input) {
+            Err(ParseError::DeserializeFailed(DeserializeError::NumberOutOfRange {
+                value,
+                target,
+            })) => {
+                assert_eq!(target, "u32", "{}", input);
+                assert_eq!(value, expected, "{}", input);
+            }
+            other => panic!(
+                "expected an out-of-range error for {}, got {:?}",
+                input, other
+            ),
+        }
+    }
+    assert_eq!(
+        parse::<Quota>(r#"{"limit": 4294967295}"#).unwrap().limit,
+        u32::MAX
+    );
+}
+
+#[test]
+fn test_serde_u128_from_string() {
+    let account: Account = parse(r#"{"id": "123456789012345678901234567890"}"#).unwrap();
+    assert_eq!(account.id, 123_456_789_012_345_678_901_234_567_890);
+}
+
+#[test]
+fn test_llm_i64_overflow_is_an_error() {
+    let deserialize = |value: serde_json::Value| {
+        <i64 as LlmDeserialize>::deserialize(
+            &FlexValue::new(value, Source::Direct),
+            &mut CoercionContext::new(),
+        )
+    };
+    assert_out_of_range(deserialize(serde_json::json!(18446744073709551615u64)));
+    assert_out_of_range(deserialize(serde_json::json!(1e300)));
+
+    // No candidate survives, so parsing reports that instead
+    assert!(matches!(
+        parse_llm::<i64>("18446744073709551615"),
+        Err(ParseError::NoCandidates)
+    ));
+}
+
+#[cfg(feature = "arbitrary_precision")]
+mod arbitrary_precision {
+    use serde::Deserialize;
+    use tryparse::{parse, parse_llm};
+
+    const ID: &str = "123456789012345678901234567890";
+
+    #[derive(Deserialize, Debug)]
+    struct Record {
+        id: String,
+        big: u128,
+        raw: serde_json::Value,
+    }
+
+    #[test]
+    fn test_serde_keeps_all_digits() {
+        let input = format!(r#"{{"id": {ID}, "big": {ID}, "raw": {ID}}}"#);
+        let record: Record = parse(&input).unwrap();
+
+        assert_eq!(record.id, ID);
+        assert_eq!(record.big.to_string(), ID);
+        assert_eq!(record.raw.to_string(), ID);
+    }
+
+    #[test]
+    fn test_llm_keeps_all_digits() {
+        assert_eq!(parse_llm::<String>(ID).unwrap(), ID);
+        assert_eq!(parse_llm::<u128>(ID).unwrap().to_string(), ID);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_keeps_all_digits() {
+        let decimal: rust_decimal::Decimal = parse_llm("1234567890123456.7890123456").unwrap();
+        assert_eq!(decimal.to_string(), "1234567890123456.7890123456");
+    }
+}
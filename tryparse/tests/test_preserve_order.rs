@@ -0,0 +1,51 @@
+//! Tests for keeping object keys in the order the model wrote them.
+
+#![cfg(feature = "preserve_order")]
+
+use serde_json::Value;
+use tryparse::{parse, parse_with_candidates};
+
+fn keys(value: &Value) -> Vec<&str> {
+    value
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect()
+}
+
+#[test]
+fn test_direct_json_keeps_key_order() {
+    let (_, candidates) =
+        parse_with_candidates::<Value>(r#"{"zebra": 1, "apple": 2, "mango": 3}"#).unwrap();
+
+    assert_eq!(keys(&candidates[0].value), vec!["zebra", "apple", "mango"]);
+}
+
+#[test]
+fn test_repaired_json_keeps_key_order() {
+    let value: Value = parse("{zebra: 1, apple: 2, mango: 3,}").unwrap();
+
+    assert_eq!(keys(&value), vec!["zebra", "apple", "mango"]);
+}
+
+#[test]
+fn test_nested_and_extracted_json_keep_key_order() {
+    let input = "Here you go:\n```json\n{\"b\": {\"z\": 1, \"y\": 2}, \"a\": 0}\n```";
+    let value: Value = parse(input).unwrap();
+
+    assert_eq!(keys(&value), vec!["b", "a"]);
+    assert_eq!(keys(&value["b"]), vec!["z", "y"]);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_yaml_keeps_key_order() {
+    use tryparse::parser::strategies::{ParsingStrategy, YamlStrategy};
+
+    let candidates = YamlStrategy
+        .parse("zebra: 1\napple: 2\nmango: 3\n")
+        .unwrap();
+
+    assert_eq!(keys(&candidates[0].value), vec!["zebra", "apple", "mango"]);
+}
@@ -0,0 +1,90 @@
+//! Tests for Python literal answers.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{
+    deserializer::CoercionContext, error::ParseError, parse_llm, parse_llm_with_candidates,
+    parse_llm_with_context, parser::NonFinitePolicy, value::Source,
+};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct User {
+    name: String,
+    active: bool,
+    tags: Vec<String>,
+    score: Option<f64>,
+}
+
+#[test]
+fn test_dict_repr() {
+    let input = "{'name': 'Alice', 'active': True, 'tags': ('a', 'b'), 'score': None}";
+
+    let (user, candidates) = parse_llm_with_candidates::<User>(input).unwrap();
+    assert_eq!(user.name, "Alice");
+    assert!(user.active);
+    assert_eq!(user.tags, vec!["a", "b"]);
+    assert_eq!(user.score, None);
+    assert!(matches!(candidates[0].source, Source::Python));
+}
+
+#[test]
+fn test_printed_dataclasses() {
+    let input = "Running the script prints:\n\n\
+                 [User(name='Alice', active=True, tags=['admin'], score=Decimal('9.5')), \
+                 User(name=\"Bob\", active=False, tags=[], score=None)]";
+
+    let users: Vec<User> = parse_llm(input).unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].score, Some(9.5));
+    assert_eq!(users[1].name, "Bob");
+    assert!(users[1].tags.is_empty());
+}
+
+#[test]
+fn test_fenced_python_with_strings() {
+    let input = "```python\n{\n    'name': r'C:\\Users\\alice',\n    'active': True,\n    \
+                 'tags': {'x'},  # a set\n    'score': float('inf'),\n}\n```";
+
+    let user: User = parse_llm(input).unwrap();
+    assert_eq!(user.name, "C:\\Users\\alice");
+    assert_eq!(user.tags, vec!["x"]);
+    assert_eq!(user.score, None);
+}
+
+#[test]
+fn test_non_finite_scores_follow_the_context() {
+    let input = "{'name': 'Bo', 'active': False, 'tags': [], 'score': float('nan')}";
+
+    let user: User = parse_llm(input).unwrap();
+    assert_eq!(user.score, None);
+
+    let ctx = CoercionContext::new().with_non_finite_policy(NonFinitePolicy::Error);
+    assert!(matches!(
+        parse_llm_with_context::<User>(input, &ctx),
+        Err(ParseError::NonFiniteNumber { literal }) if literal == "NaN"
+    ));
+
+    let input = "{'name': 'Bo', 'active': False, 'tags': [], 'score': inf}";
+    let ctx = CoercionContext::new().with_non_finite_policy(NonFinitePolicy::String);
+    let user: User = parse_llm_with_context(input, &ctx).unwrap();
+    assert_eq!(user.score, Some(f64::INFINITY));
+}
+
+#[test]
+fn test_lists_inside_other_objects_are_not_read_alone() {
+    let input = "{'name': 'x', 'tags': ['a', 'b'], 'ok': true}";
+    let value: serde_json::Value = tryparse::parse(input).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({"name": "x", "tags": ["a", "b"], "ok": true})
+    );
+    assert!(tryparse::parse::<Vec<String>>(input).is_err());
+
+    let value: serde_json::Value =
+        tryparse::parse("{unquoted: 'single', hex: 0xFF, arr: [1,2,],}").unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({"unquoted": "single", "hex": 255, "arr": [1, 2]})
+    );
+}
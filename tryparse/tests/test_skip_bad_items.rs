@@ -0,0 +1,49 @@
+//! Tests for dropping list items that don't coerce.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{
+    deserializer::{CoercionContext, CoercionPolicy},
+    parse_llm, parse_llm_with_context,
+};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, LlmDeserialize)]
+struct Order {
+    #[llm(skip_bad_items)]
+    quantities: Vec<i64>,
+    prices: Vec<f64>,
+}
+
+#[test]
+fn test_field_attribute_skips_bad_items() {
+    let input = r#"{"quantities": [1, "lots", 3], "prices": [1.5, 2]}"#;
+
+    let order: Order = parse_llm(input).unwrap();
+    assert_eq!(order.quantities, vec![1, 3]);
+    assert_eq!(order.prices, vec![1.5, 2.0]);
+}
+
+#[test]
+fn test_attribute_is_scoped_to_its_field() {
+    let input = r#"{"quantities": [1], "prices": [1.5, "pricey"]}"#;
+
+    assert!(parse_llm::<Order>(input).is_err());
+}
+
+#[test]
+fn test_context_skips_bad_items_everywhere() {
+    let input = r#"{"quantities": [1], "prices": [1.5, "pricey", 3]}"#;
+
+    let ctx = CoercionContext::new().with_skip_bad_items(true);
+    let order: Order = parse_llm_with_context(input, &ctx).unwrap();
+    assert_eq!(order.prices, vec![1.5, 3.0]);
+}
+
+#[test]
+fn test_lossless_policy_forbids_skipping() {
+    let input = r#"{"quantities": [1, "lots"], "prices": []}"#;
+
+    let ctx = CoercionContext::new().with_policy(CoercionPolicy::lossless());
+    assert!(parse_llm_with_context::<Order>(input, &ctx).is_err());
+}
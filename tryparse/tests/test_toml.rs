@@ -0,0 +1,60 @@
+//! Tests for TOML answers (requires the `toml` feature).
+
+#![cfg(feature = "toml")]
+
+use serde::Deserialize;
+use tryparse::{parse, parse_with_candidates, value::Source};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Config {
+    name: String,
+    debug: bool,
+    servers: Vec<Server>,
+}
+
+const CONFIG: &str = r#"name = "api"
+debug = false
+
+[[servers]]
+host = "10.0.0.1"
+port = 8080
+
+[[servers]]
+host = "10.0.0.2"
+port = 8081
+"#;
+
+#[test]
+fn test_plain_toml() {
+    let (config, candidates) = parse_with_candidates::<Config>(CONFIG).unwrap();
+
+    assert_eq!(config.name, "api");
+    assert_eq!(config.servers.len(), 2);
+    assert_eq!(config.servers[1].port, 8081);
+    assert!(matches!(candidates[0].source, Source::Toml));
+}
+
+#[test]
+fn test_fenced_toml_in_prose() {
+    let input = format!(
+        "Here's the generated config:\n\n```toml\n{}```\n\nLet me know!",
+        CONFIG
+    );
+
+    let config: Config = parse(&input).unwrap();
+    assert_eq!(config.servers[0].host, "10.0.0.1");
+}
+
+#[test]
+fn test_json_is_not_toml() {
+    let (_, candidates) =
+        parse_with_candidates::<Config>(r#"{"name": "api", "debug": true, "servers": []}"#)
+            .unwrap();
+    assert!(matches!(candidates[0].source, Source::Direct));
+}
@@ -0,0 +1,67 @@
+//! Tests for answers written as XML-ish markup.
+
+#![cfg(feature = "derive")]
+
+use tryparse::{parse_llm, parse_llm_with_candidates, value::Source};
+use tryparse_derive::LlmDeserialize;
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct User {
+    name: String,
+    age: i64,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, LlmDeserialize)]
+struct Reply {
+    user: User,
+}
+
+#[test]
+fn test_root_element_matches_struct() {
+    let input = "<user><name>Alice</name><age>30</age><tags>admin</tags><tags>ops</tags></user>";
+
+    let (user, candidates) = parse_llm_with_candidates::<User>(input).unwrap();
+    assert_eq!(user.name, "Alice");
+    assert_eq!(user.age, 30);
+    assert_eq!(user.tags, vec!["admin", "ops"]);
+    assert!(matches!(candidates[0].source, Source::Xml));
+}
+
+#[test]
+fn test_root_element_matches_field() {
+    let input = "<user><name>Bob</name><age>41</age><tags>x</tags></user>";
+
+    let reply: Reply = parse_llm(input).unwrap();
+    assert_eq!(reply.user.name, "Bob");
+    assert_eq!(reply.user.tags, vec!["x"]);
+}
+
+#[test]
+fn test_sloppy_markup() {
+    let input = r#"<?xml version="1.0"?>
+<user id=7 verified>
+  <name>Smith & Sons &amp; Co</name>
+  <age>30
+  <tags>a</tags><tags>b
+</user>"#;
+
+    let user: User = parse_llm(input).unwrap();
+    assert_eq!(user.name, "Smith & Sons & Co");
+    assert_eq!(user.age, 30);
+    assert_eq!(user.tags, vec!["a", "b"]);
+}
+
+#[test]
+fn test_top_level_fields_without_root() {
+    let input = "<name>Carol</name>\n<age>25</age>\n<tags>x</tags>";
+
+    let user: User = parse_llm(input).unwrap();
+    assert_eq!(user.name, "Carol");
+}
+
+#[test]
+fn test_markup_in_prose_is_not_xml() {
+    let text: String = parse_llm("<b>bold</b> text").unwrap();
+    assert_eq!(text, "<b>bold</b> text");
+}
@@ -0,0 +1,70 @@
+//! Tests for answers wrapped in XML-style tags.
+
+use serde::Deserialize;
+use tryparse::{
+    parse, parse_with_candidates, parse_with_parser,
+    parser::{
+        strategies::{ParsingStrategy, XmlTagExtractor, XmlTagStrategy},
+        FlexibleParser,
+    },
+    value::Source,
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Answer {
+    city: String,
+    population: u64,
+}
+
+#[test]
+fn test_tag_beats_json_in_reasoning() {
+    let input = r#"<thinking>
+First guess: {"city": "Lyon", "population": 500000}. Hmm, that's not the biggest.
+</thinking>
+<answer>{"city": "Paris", "population": 2100000}</answer>"#;
+
+    let (answer, candidates) = parse_with_candidates::<Answer>(input).unwrap();
+    assert_eq!(answer.city, "Paris");
+    assert!(matches!(&candidates[0].source, Source::Tagged { tag, .. } if tag == "answer"));
+}
+
+#[test]
+fn test_last_tag_wins() {
+    let input = r#"<json>{"city": "Lyon", "population": 1}</json>
+Actually, let me correct that.
+<json>{"city": "Paris", "population": 2100000}</json>"#;
+
+    let answer: Answer = parse(input).unwrap();
+    assert_eq!(answer.city, "Paris");
+}
+
+#[test]
+fn test_tag_content_with_code_braces_is_repaired() {
+    // Code in a string and a trailing comma; no code fence needed
+    let input = "<answer>\n{\"city\": \"fn main() { }\", \"population\": 3,}\n</answer>";
+
+    let answer: Answer = parse(input).unwrap();
+    assert_eq!(answer.city, "fn main() { }");
+    assert_eq!(answer.population, 3);
+}
+
+#[test]
+fn test_unclosed_answer_tag() {
+    let answer: Answer = parse(r#"<answer>{"city": "Oslo", "population": 700000}"#).unwrap();
+    assert_eq!(answer.city, "Oslo");
+}
+
+#[test]
+fn test_custom_tags_with_preference() {
+    let input = r#"<final>{"city": "Rome", "population": 2800000}</final>
+<draft>{"city": "Milan", "population": 1400000}</draft>"#;
+
+    let strategy =
+        XmlTagStrategy::new(XmlTagExtractor::new(["draft", "final"]).prefer_tag("final"));
+    let values = strategy.parse(input).unwrap();
+    assert_eq!(values[0].value["city"], "Rome");
+
+    let parser = FlexibleParser::with_strategies(vec![Box::new(strategy)]);
+    let answer: Answer = parse_with_parser(input, &parser).unwrap();
+    assert_eq!(answer.city, "Rome");
+}